@@ -11,7 +11,7 @@ fn main() {
     let u = vec![rank; size as usize];
     let mut v = vec![0; size as usize];
 
-    world.all_to_all_into(&u[..], &mut v[..]);
+    world.all_to_all_into(&u[..], &mut v[..]).unwrap();
 
     println!("u: {:?}", u);
     println!("v: {:?}", v);
@@ -10,15 +10,15 @@ fn main() {
     if world.rank() == receiver_rank {
         let n = (world.size() - 1) as usize;
         let mut buf = vec![0u64; 2 * n];
-        for x in buf[0..n].iter_mut() { world.receive_into(x); }
-        world.barrier();
-        for x in buf[n..2 * n].iter_mut() { world.receive_into(x); }
+        for x in buf[0..n].iter_mut() { world.receive_into(x).unwrap(); }
+        world.barrier().unwrap();
+        for x in buf[n..2 * n].iter_mut() { world.receive_into(x).unwrap(); }
         println!("{:?}", buf);
         assert!(buf[0..n].iter().all(|&x| { x == 1 }));
         assert!(buf[n..2 * n].iter().all(|&x| { x == 2 }));
     } else {
-        world.process_at_rank(0).send(&1u64);
-        world.barrier();
-        world.process_at_rank(0).send(&2u64);
+        world.process_at_rank(0).send(&1u64).unwrap();
+        world.barrier().unwrap();
+        world.process_at_rank(0).send(&2u64).unwrap();
     }
 }
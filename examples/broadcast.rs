@@ -15,7 +15,7 @@ fn main() {
     } else {
         x = 0_u64;
     }
-    root_process.broadcast_into(&mut x);
+    root_process.broadcast_into(&mut x).unwrap();
     println!("Rank {} received value: {}.", world.rank(), x);
     assert_eq!(x, 1024);
     println!("");
@@ -28,7 +28,7 @@ fn main() {
     } else {
         a = std::iter::repeat(0_u64).take(n).collect::<Vec<_>>();
     }
-    root_process.broadcast_into(&mut a[..]);
+    root_process.broadcast_into(&mut a[..]).unwrap();
     println!("Rank {} received value: {:?}.", world.rank(), &a[..]);
     assert_eq!(&a[..], &[2, 4, 8, 16]);
 }
@@ -0,0 +1,41 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::topology::Rank;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let dims = mpi::topology::dims_create(world.size(), 2).unwrap();
+    assert_eq!(world.size(), dims.iter().product());
+
+    let comm = world.create_cartesian_communicator(&dims[..], &[true, false], false)
+        .unwrap().unwrap();
+    assert_eq!(2, comm.num_dimensions());
+
+    let layout = comm.layout();
+    println!("Rank {} has coordinates {:?} in grid {:?}.", comm.rank(), layout.coords, layout.dims);
+    assert_eq!(dims, layout.dims);
+    assert_eq!(vec![true, false], layout.periods);
+    assert_eq!(layout.coords, comm.coordinates());
+    assert_eq!(layout.coords, comm.coordinates_of(comm.rank()).unwrap());
+    assert_eq!(comm.rank(), comm.rank_at_coordinates(&layout.coords[..]).unwrap());
+
+    // The first dimension is periodic, so there is always a neighbor.
+    let (source, destination) = comm.shift(0, 1).unwrap();
+    let (msg, _) = comm.send_receive::<_, Rank>(&comm.rank(), destination.rank(), source.rank())
+        .unwrap();
+    let c = &layout.coords;
+    let expected = comm.rank_at_coordinates(&[(c[0] + dims[0] - 1) % dims[0], c[1]]).unwrap();
+    assert_eq!(Some(expected), msg);
+
+    // The second dimension is not periodic, so processes on the boundary have no neighbor.
+    let (source, destination) = comm.shift(1, 1).unwrap();
+    assert_eq!(c[1] == 0, source.rank() == comm.null_process().rank());
+    assert_eq!(c[1] == dims[1] - 1, destination.rank() == comm.null_process().rank());
+
+    let row = comm.sub(&[false, true]).unwrap();
+    assert_eq!(dims[1], row.size());
+    assert_eq!(vec![c[1]], row.coordinates());
+}
@@ -0,0 +1,25 @@
+extern crate mpi;
+extern crate num_complex;
+
+use num_complex::Complex;
+
+use mpi::traits::*;
+use mpi::collective::SystemOperation;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let z = Complex::new(rank as f64, 1.0);
+    let mut sum = Complex::new(0.0f64, 0.0);
+    world.all_reduce_into(&z, &mut sum, SystemOperation::sum()).unwrap();
+    assert_eq!(Complex::new((size * (size - 1) / 2) as f64, size as f64), sum);
+
+    let w = [Complex::new(0.0f32, 1.0); 2];
+    let mut product = [Complex::new(0.0f32, 0.0); 2];
+    world.all_reduce_into(&w[..], &mut product[..], SystemOperation::product()).unwrap();
+    let expected = (0..size).fold(Complex::new(1.0f32, 0.0), |p, _| p * Complex::new(0.0, 1.0));
+    assert_eq!([expected; 2], product);
+}
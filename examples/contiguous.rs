@@ -16,18 +16,18 @@ fn main() {
     let b1 = (1..).map(|x| rank * x).take(3).collect::<Vec<_>>();
     let mut b2 = std::iter::repeat(-1).take(3).collect::<Vec<_>>();
     println!("Rank {} sending message: {:?}.", rank, b1);
-    world.barrier();
+    world.barrier().unwrap();
 
-    let t = UserDatatype::contiguous(3, Rank::equivalent_datatype());
+    let t = UserDatatype::contiguous(3, Rank::equivalent_datatype()).unwrap();
     let status;
     {
         let v1 = unsafe { View::with_count_and_datatype(&b1[..], 1, &t) };
         let mut v2 = unsafe { MutView::with_count_and_datatype(&mut b2[..], 1, &t) };
-        status = world.send_receive_into(&v1, next_rank, &mut v2, previous_rank);
+        status = world.send_receive_into(&v1, next_rank, &mut v2, previous_rank).unwrap();
     }
 
     println!("Rank {} received message: {:?}, status: {:?}.", rank, b2, status);
-    world.barrier();
+    world.barrier().unwrap();
 
     let b3 = (1..).map(|x| previous_rank * x).take(3).collect::<Vec<_>>();
     assert_eq!(b3, b2);
@@ -0,0 +1,40 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::datatype::{UserDatatype, View, Distribution, Order};
+use mpi::topology::Rank;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // A global array of 4 elements per process, dealt out in blocks of 2 elements
+    let global = (0..4 * size).collect::<Vec<Rank>>();
+    let local_part = UserDatatype::distributed_array(size, rank, &[4 * size],
+        &[Distribution::CyclicOf(2)], &[size], Order::C, Rank::equivalent_datatype()).unwrap();
+
+    let mut local = [-1; 4];
+    {
+        let v = unsafe { View::with_count_and_datatype(&global[..], 1, &local_part) };
+        world.send_receive_into(&v, rank, &mut local[..], rank).unwrap();
+    }
+
+    let expected = global.iter().cloned().filter(|&i| (i / 2) % size == rank).collect::<Vec<_>>();
+    assert_eq!(&expected[..], &local[..]);
+
+    // A block distribution of the rows of a matrix with 3 columns
+    let rows = 2 * size;
+    let matrix = (0..rows * 3).collect::<Vec<Rank>>();
+    let local_rows = UserDatatype::distributed_array(size, rank, &[rows, 3],
+        &[Distribution::Block, Distribution::Undistributed], &[size, 1], Order::C,
+        Rank::equivalent_datatype()).unwrap();
+
+    let mut local = [-1; 6];
+    {
+        let v = unsafe { View::with_count_and_datatype(&matrix[..], 1, &local_rows) };
+        world.send_receive_into(&v, rank, &mut local[..], rank).unwrap();
+    }
+    assert_eq!(&matrix[(6 * rank) as usize..(6 * rank + 6) as usize], &local[..]);
+}
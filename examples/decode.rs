@@ -0,0 +1,60 @@
+extern crate mpi;
+
+use std::mem;
+
+use mpi::traits::*;
+use mpi::datatype::{DecodedDatatype, Order, UserDatatype};
+use mpi::Address;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let f64_size = mem::size_of::<f64>() as Address;
+    let row = UserDatatype::vector(2, 1, 3, f64::equivalent_datatype()).unwrap();
+    let block = UserDatatype::subarray(&[4, 4], &[2, 2], &[1, 1], Order::C,
+        i32::equivalent_datatype()).unwrap();
+    let pair = UserDatatype::structured(&[1, 1], &[0, 6 * f64_size], &[&row, &block]).unwrap();
+    let resized = UserDatatype::resized(&pair, 0, 16 * f64_size).unwrap();
+
+    let decoded = resized.decode().unwrap();
+    if world.rank() == 0 {
+        println!("{:#?}", decoded);
+    }
+
+    match decoded {
+        DecodedDatatype::Resized { lower_bound: 0, extent, oldtype } => {
+            assert_eq!(16 * f64_size, extent);
+            match *oldtype {
+                DecodedDatatype::Structured { blocklengths, displacements, types } => {
+                    assert_eq!(vec![1, 1], blocklengths);
+                    assert_eq!(vec![0, 6 * f64_size], displacements);
+                    match types[0] {
+                        DecodedDatatype::Vector { count: 2, blocklength: 1, stride: 3, ref oldtype } =>
+                            match **oldtype {
+                                DecodedDatatype::Named(_) => { }
+                                ref other => panic!("Expected a named datatype, got {:?}", other)
+                            },
+                        ref other => panic!("Expected a vector datatype, got {:?}", other)
+                    }
+                    match types[1] {
+                        DecodedDatatype::Subarray { ref sizes, ref subsizes, ref starts, order, .. } => {
+                            assert_eq!(&[4, 4], &sizes[..]);
+                            assert_eq!(&[2, 2], &subsizes[..]);
+                            assert_eq!(&[1, 1], &starts[..]);
+                            assert_eq!(Order::C, order);
+                        }
+                        ref other => panic!("Expected a subarray datatype, got {:?}", other)
+                    }
+                }
+                other => panic!("Expected a structured datatype, got {:?}", other)
+            }
+        }
+        other => panic!("Expected a resized datatype, got {:?}", other)
+    }
+
+    match f64::equivalent_datatype().decode().unwrap() {
+        DecodedDatatype::Named(_) => { }
+        other => panic!("Expected a named datatype, got {:?}", other)
+    }
+}
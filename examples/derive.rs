@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::topology::Rank;
+
+#[derive(Equivalence, Copy, Clone, Debug, PartialEq)]
+struct Particle {
+    position: [f64; 3],
+    charge: i32
+}
+
+#[derive(Equivalence, Copy, Clone, Debug, PartialEq)]
+struct Tagged(Rank, Particle);
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let previous_rank = if rank - 1 >= 0 { rank - 1 } else { size - 1 };
+
+    let p = Particle { position: [rank as f64, 0.5, -1.0], charge: rank };
+    let mut q = Particle { position: [0.0; 3], charge: -1 };
+    world.send_receive_into(&p, next_rank, &mut q, previous_rank).unwrap();
+    assert_eq!(Particle { position: [previous_rank as f64, 0.5, -1.0], charge: previous_rank }, q);
+
+    let mut t = if rank == root_rank {
+        (0..3).map(|i| Tagged(i, Particle { position: [i as f64; 3], charge: -i })).collect()
+    } else {
+        vec![Tagged(0, Particle { position: [0.0; 3], charge: 0 }); 3]
+    };
+    root_process.broadcast_into(&mut t[..]).unwrap();
+    assert!(t.iter().zip(0..).all(|(t, i)| {
+        *t == Tagged(i, Particle { position: [i as f64; 3], charge: -i })
+    }));
+
+    if rank > 0 {
+        root_process.send(&t[..]).unwrap();
+    } else {
+        for _ in 1..size {
+            let (msg, _) = world.receive_vec::<Tagged>().unwrap();
+            assert_eq!(Some(t.clone()), msg);
+        }
+    }
+}
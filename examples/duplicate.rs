@@ -6,10 +6,10 @@ use mpi::topology::{CommunicatorRelation};
 fn main() {
     let universe = mpi::initialize().unwrap();
     let world = universe.world();
-    let moon = world.duplicate();
+    let moon = world.duplicate().unwrap();
 
-    world.barrier();
-    moon.barrier();
+    world.barrier().unwrap();
+    moon.barrier().unwrap();
 
     assert_eq!(CommunicatorRelation::Congruent, world.compare(&moon));
 }
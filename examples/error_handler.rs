@@ -0,0 +1,46 @@
+extern crate mpi;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use mpi::traits::*;
+use mpi::error::ErrorHandler;
+use mpi::topology::Color;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let x = 0u64;
+
+    // Tags must not be negative, so this send fails.
+    let error = world.this_process().send_with_tag(&x, -5).unwrap_err();
+    println!("Rank {} caught error: {}", world.rank(), error);
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let handler = {
+        let calls = calls.clone();
+        ErrorHandler::new(move |comm, error| {
+            println!("Rank {} handled error: {}", comm.rank(), error);
+            calls.fetch_add(1, Ordering::SeqCst);
+        }).unwrap()
+    };
+    world.set_error_handler(&handler).unwrap();
+    assert!(world.this_process().send_with_tag(&x, -5).is_err());
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+
+    world.call_error_handler(error).unwrap();
+    assert_eq!(2, calls.load(Ordering::SeqCst));
+
+    // Communicators inherit the error handler, even after it has been dropped.
+    drop(handler);
+    let moon = world.duplicate().unwrap();
+    assert!(moon.this_process().send_with_tag(&x, -5).is_err());
+    assert_eq!(3, calls.load(Ordering::SeqCst));
+    let half = world.split_by_color(Color::with_value(world.rank() % 2)).unwrap().unwrap();
+    assert!(half.this_process().send_with_tag(&x, -5).is_err());
+    assert_eq!(4, calls.load(Ordering::SeqCst));
+
+    world.set_error_handler(&ErrorHandler::errors_return()).unwrap();
+    assert!(world.this_process().send_with_tag(&x, -5).is_err());
+    assert_eq!(4, calls.load(Ordering::SeqCst));
+}
@@ -15,11 +15,11 @@ fn main () {
 
     if world.rank() == root_rank {
         let mut a = vec![0u64; count];
-        root_process.gather_into_root(&i, &mut a[..]);
+        root_process.gather_into_root(&i, &mut a[..]).unwrap();
         println!("Root gathered sequence: {:?}.", a);
         assert!(a.iter().enumerate().all(|(a, &b)| b == 2u64.pow(a as u32 + 1)));
     } else {
-        root_process.gather_into(&i);
+        root_process.gather_into(&i).unwrap();
     }
 
     let factor = world.rank() as u64 + 1;
@@ -27,17 +27,17 @@ fn main () {
 
     if world.rank() == root_rank {
         let mut t = vec![0u64; count * count];
-        root_process.gather_into_root(&a[..], &mut t[..]);
+        root_process.gather_into_root(&a[..], &mut t[..]).unwrap();
         println!("Root gathered table:");
         for r in t.chunks(count) {
             println!("{:?}", r);
         }
         assert!((0_u64..).zip(t.iter()).all(|(a, &b)| b == (a / count as u64 + 1) * (a % count as u64 + 1)));
     } else {
-        root_process.gather_into(&a[..]);
+        root_process.gather_into(&a[..]).unwrap();
     }
 
-    let d = UserDatatype::contiguous(count as Count, u64::equivalent_datatype());
+    let d = UserDatatype::contiguous(count as Count, u64::equivalent_datatype()).unwrap();
     let sv = unsafe { View::with_count_and_datatype(&a[..], 1, &d) };
 
     if world.rank() == root_rank {
@@ -45,7 +45,7 @@ fn main () {
 
         {
             let mut rv = unsafe { MutView::with_count_and_datatype(&mut t[..], count as Count, &d) };
-            root_process.gather_into_root(&sv, &mut rv);
+            root_process.gather_into_root(&sv, &mut rv).unwrap();
         }
 
         println!("Root gathered table:");
@@ -54,6 +54,6 @@ fn main () {
         }
         assert!((0_u64..).zip(t.iter()).all(|(a, &b)| b == (a / count as u64 + 1) * (a % count as u64 + 1)));
     } else {
-        root_process.gather_into(&sv);
+        root_process.gather_into(&sv).unwrap();
     }
 }
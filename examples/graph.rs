@@ -0,0 +1,48 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::topology::Rank;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let previous_rank = if rank - 1 >= 0 { rank - 1 } else { size - 1 };
+
+    // A ring where every node is connected to its predecessor and successor.
+    let index = (1..size + 1).map(|i| 2 * i).collect::<Vec<_>>();
+    let edges = (0..size)
+        .flat_map(|i| vec![(i + size - 1) % size, (i + 1) % size].into_iter())
+        .collect::<Vec<Rank>>();
+    let ring = world.create_graph_communicator(&index[..], &edges[..], false).unwrap().unwrap();
+    assert_eq!(vec![previous_rank, next_rank], ring.neighbors());
+    assert_eq!(vec![rank, (next_rank + 1) % size], ring.neighbors_of(next_rank).unwrap());
+
+    // A directed ring where every process only knows its own edges.
+    let weight = rank + 1;
+    let previous_weight = previous_rank + 1;
+    let ring = world.create_dist_graph_communicator_adjacent(
+        &[previous_rank], Some(&[previous_weight]), &[next_rank], Some(&[weight]), false).unwrap();
+    let sources = ring.sources().unwrap();
+    assert_eq!(vec![previous_rank], sources.ranks);
+    assert_eq!(Some(vec![previous_weight]), sources.weights);
+    let destinations = ring.destinations().unwrap();
+    assert_eq!(vec![next_rank], destinations.ranks);
+    assert_eq!(Some(vec![weight]), destinations.weights);
+
+    // The same directed ring, but unweighted and described entirely by the root process.
+    let (sources, degrees, destinations) = if rank == 0 {
+        ((0..size).collect::<Vec<_>>(), vec![1; size as usize],
+         (0..size).map(|i| (i + 1) % size).collect::<Vec<_>>())
+    } else {
+        (vec![], vec![], vec![])
+    };
+    let ring = world.create_dist_graph_communicator(
+        &sources[..], &degrees[..], &destinations[..], None, false).unwrap();
+    assert_eq!(vec![previous_rank], ring.sources().unwrap().ranks);
+    assert_eq!(None, ring.sources().unwrap().weights);
+    assert_eq!(vec![next_rank], ring.destinations().unwrap().ranks);
+}
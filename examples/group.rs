@@ -7,7 +7,7 @@ fn main() {
     let universe = mpi::initialize().unwrap();
     let world = universe.world();
 
-    let g = world.group();
+    let g = world.group().unwrap();
     // Group accessors and Communicator accessors agree
     assert_eq!(world.size(), g.size());
     assert_eq!(world.rank(), g.rank().unwrap());
@@ -15,50 +15,50 @@ fn main() {
     // g == g
     assert_eq!(GroupRelation::Identical, g.compare(&g));
 
-    let h = world.group();
+    let h = world.group().unwrap();
     // h == g
     assert_eq!(GroupRelation::Identical, g.compare(&h));
 
-    let i = g.union(&h);
+    let i = g.union(&h).unwrap();
     // g union h == g union g == g
     assert_eq!(GroupRelation::Identical, g.compare(&i));
 
-    let empty = g.difference(&h);
+    let empty = g.difference(&h).unwrap();
     // g difference h == g difference g = empty Group
     assert_eq!(GroupRelation::Identical, SystemGroup::empty().compare(&empty));
     assert_eq!(0, empty.size());
 
     // g intersection empty == empty Group
-    assert_eq!(0, g.intersection(&empty).size());
+    assert_eq!(0, g.intersection(&empty).unwrap().size());
 
     let first_half: Vec<Rank> = (0..g.size() / 2).collect();
 
     // f and s are first and second half of g
-    let f = g.include(&first_half[..]);
-    let s = g.exclude(&first_half[..]);
+    let f = g.include(&first_half[..]).unwrap();
+    let s = g.exclude(&first_half[..]).unwrap();
     // f != s
     assert_eq!(GroupRelation::Unequal, f.compare(&s));
 
     // g intersection f == f
-    let f_ = g.intersection(&f);
+    let f_ = g.intersection(&f).unwrap();
     assert_eq!(GroupRelation::Identical, f.compare(&f_));
     // g intersection s == s
-    let s_ = g.intersection(&s);
+    let s_ = g.intersection(&s).unwrap();
     assert_eq!(GroupRelation::Identical, s.compare(&s_));
 
     // g difference s == f
-    let f__ = g.difference(&s);
+    let f__ = g.difference(&s).unwrap();
     assert_eq!(GroupRelation::Identical, f.compare(&f__));
     // g difference f == s
-    let s__ = g.difference(&f);
+    let s__ = g.difference(&f).unwrap();
     assert_eq!(GroupRelation::Identical, s.compare(&s__));
 
     // f union s == g
-    let fs = f.union(&s);
+    let fs = f.union(&s).unwrap();
     assert_eq!(GroupRelation::Identical, g.compare(&fs));
 
     // f intersection s == empty Group
-    let fs = f.intersection(&s);
+    let fs = f.intersection(&s).unwrap();
     assert_eq!(GroupRelation::Identical, empty.compare(&fs));
 
     // rank is either in f or in s
@@ -67,6 +67,6 @@ fn main() {
 
     // inverting rank mappings
     let rev: Vec<Rank> = (0..g.size()).rev().collect();
-    let r = g.include(&rev[..]);
-    assert_eq!(Some(rev[g.rank().unwrap() as usize]), r.translate_rank(g.rank().unwrap(), &g));
+    let r = g.include(&rev[..]).unwrap();
+    assert_eq!(Some(rev[g.rank().unwrap() as usize]), r.translate_rank(g.rank().unwrap(), &g).unwrap());
 }
@@ -10,52 +10,53 @@ fn main() {
     let x = 3.1415f32;
     let mut y: f32 = 0.0;
 
-    {
-        let mut sreq = world.this_process().immediate_send(&x);
-        let rreq = world.immediate_receive_into(&mut y);
-        rreq.wait();
+    mpi::request::scope(|scope| {
+        let mut sreq = world.this_process().immediate_send(scope, &x).unwrap();
+        let rreq = world.immediate_receive_into(scope, &mut y).unwrap();
+        rreq.wait().unwrap();
         loop {
-            match sreq.test() {
+            match sreq.test().unwrap() {
                 Ok(_) => { break; }
                 Err(req) => { sreq = req; }
             }
         }
-    }
+    });
     assert_eq!(x, y);
 
     y = 0.0;
-    {
-        let _rreq = WaitGuard::from(world.immediate_receive_into(&mut y));
-        let _sreq = WaitGuard::from(world.this_process().immediate_ready_send(&x));
-    }
+    mpi::request::scope(|scope| {
+        let _rreq = WaitGuard::from(world.immediate_receive_into(scope, &mut y).unwrap());
+        let _sreq = WaitGuard::from(world.this_process().immediate_ready_send(scope, &x).unwrap());
+    });
     assert_eq!(x, y);
 
-    assert!(world.immediate_probe().is_none());
-    assert!(world.immediate_matched_probe().is_none());
+    assert!(world.immediate_probe().unwrap().is_none());
+    assert!(world.immediate_matched_probe().unwrap().is_none());
 
     y = 0.0;
-    {
-        let _sreq: WaitGuard<_> = world.this_process().immediate_synchronous_send(&x).into();
-        let preq = world.immediate_matched_probe();
+    mpi::request::scope(|scope| {
+        let _sreq: WaitGuard<_> = world.this_process().immediate_synchronous_send(scope, &x)
+            .unwrap().into();
+        let preq = world.immediate_matched_probe().unwrap();
         assert!(preq.is_some());
         let (msg, _) = preq.unwrap();
-        let _rreq: WaitGuard<_> = msg.immediate_matched_receive_into(&mut y).into();
-    }
+        let _rreq: WaitGuard<_> = msg.immediate_matched_receive_into(scope, &mut y).unwrap().into();
+    });
     assert_eq!(x, y);
 
-    let future = world.immediate_receive();
-    world.this_process().send(&x);
-    let (msg, _) = future.get();
+    let future = world.immediate_receive().unwrap();
+    world.this_process().send(&x).unwrap();
+    let (msg, _) = future.get().unwrap();
     assert!(msg.is_some());
     assert_eq!(x, msg.unwrap());
 
-    let future = world.immediate_receive();
-    let res = future.try();
+    let future = world.immediate_receive().unwrap();
+    let res = future.try().unwrap();
     assert!(res.is_err());
     let mut future = res.err().unwrap();
-    world.this_process().send(&x);
+    world.this_process().send(&x).unwrap();
     loop {
-        match future.try() {
+        match future.try().unwrap() {
             Ok((msg, _)) => {
                 assert!(msg.is_some());
                 assert_eq!(x, msg.unwrap());
@@ -65,8 +66,11 @@ fn main() {
         }
     }
 
-    let sreq = world.this_process().immediate_send(&x);
-    sreq.cancel();
+    mpi::request::scope(|scope| {
+        let sreq = world.this_process().immediate_send(scope, &x).unwrap();
+        sreq.cancel().unwrap();
 
-    let _sreq = CancelGuard::from(world.this_process().immediate_receive_into(&mut y));
+        let _rreq = CancelGuard::from(world.this_process().immediate_receive_into(scope, &mut y)
+            .unwrap());
+    });
 }
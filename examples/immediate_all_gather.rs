@@ -13,7 +13,9 @@ fn main () {
     let i = 2_u64.pow(world.rank() as u32 + 1);
     let mut a = vec![0u64; count];
 
-    world.immediate_all_gather_into(&i, &mut a[..]).wait();
+    mpi::request::scope(|scope| {
+        world.immediate_all_gather_into(scope, &i, &mut a[..]).unwrap().wait().unwrap();
+    });
 
     if world.rank() == root_rank { println!("Root gathered sequence: {:?}.", a); }
     assert!(a.iter().enumerate().all(|(a, &b)| b == 2u64.pow(a as u32 + 1)));
@@ -22,7 +24,9 @@ fn main () {
     let a = (1_u64..).take(count).map(|x| x * factor).collect::<Vec<_>>();
     let mut t = vec![0u64; count * count];
 
-    world.immediate_all_gather_into(&a[..], &mut t[..]).wait();
+    mpi::request::scope(|scope| {
+        world.immediate_all_gather_into(scope, &a[..], &mut t[..]).unwrap().wait().unwrap();
+    });
 
     if world.rank() == root_rank {
         println!("Root gathered table:");
@@ -32,14 +36,16 @@ fn main () {
     }
     assert!((0_u64..).zip(t.iter()).all(|(a, &b)| b == (a / count as u64 + 1) * (a % count as u64 + 1)));
 
-    let d = UserDatatype::contiguous(count as Count, u64::equivalent_datatype());
+    let d = UserDatatype::contiguous(count as Count, u64::equivalent_datatype()).unwrap();
     t = vec![0u64; count * count];
 
     {
         let sv = unsafe { View::with_count_and_datatype(&a[..], 1, &d) };
         let mut rv = unsafe { MutView::with_count_and_datatype(&mut t[..], count as Count, &d) };
 
-        world.immediate_all_gather_into(&sv, &mut rv).wait();
+        mpi::request::scope(|scope| {
+            world.immediate_all_gather_into(scope, &sv, &mut rv).unwrap().wait().unwrap();
+        });
     }
 
     if world.rank() == root_rank {
@@ -0,0 +1,31 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::datatype::PartitionMut;
+use mpi::Count;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let rank = world.rank();
+    let size = world.size();
+
+    // Rank `r` contributes `r` copies of `r`.
+    let msg = vec![rank; rank as usize];
+
+    let counts = (0..size).collect::<Vec<Count>>();
+    let displs = counts.iter().scan(0, |acc, &x| { let tmp = *acc; *acc += x; Some(tmp) })
+        .collect::<Vec<Count>>();
+    let mut buf = vec![0; (size * (size - 1) / 2) as usize];
+    {
+        let mut partition = PartitionMut::new(&mut buf[..], &counts[..], &displs[..]);
+        mpi::request::scope(|scope| {
+            let req = world.immediate_all_gather_varcount_into(scope, &msg[..], &mut partition)
+                .unwrap();
+            req.wait().unwrap();
+        });
+    }
+
+    assert!(buf.iter().zip((0..size).flat_map(|r| vec![r; r as usize])).all(|(&a, b)| a == b));
+}
@@ -11,7 +11,9 @@ fn main() {
     let u = vec![rank; size as usize];
     let mut v = vec![0; size as usize];
 
-    world.immediate_all_to_all_into(&u[..], &mut v[..]).wait();
+    mpi::request::scope(|scope| {
+        world.immediate_all_to_all_into(scope, &u[..], &mut v[..]).unwrap().wait().unwrap();
+    });
 
     println!("u: {:?}", u);
     println!("v: {:?}", v);
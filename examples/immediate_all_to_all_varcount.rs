@@ -0,0 +1,36 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::datatype::{Partition, PartitionMut};
+use mpi::Count;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let rank = world.rank();
+    let size = world.size();
+
+    // Rank `r` sends `i + 1` copies of `r` to rank `i`, so it receives `r + 1` elements from
+    // every rank.
+    let send_counts = (1..size + 1).collect::<Vec<Count>>();
+    let send_displs = send_counts.iter()
+        .scan(0, |acc, &x| { let tmp = *acc; *acc += x; Some(tmp) })
+        .collect::<Vec<Count>>();
+    let msg = vec![rank; (size * (size + 1) / 2) as usize];
+
+    let recv_counts = vec![rank + 1; size as usize];
+    let recv_displs = (0..size).map(|i| i * (rank + 1)).collect::<Vec<Count>>();
+    let mut buf = vec![0; (size * (rank + 1)) as usize];
+    {
+        let partition = Partition::new(&msg[..], &send_counts[..], &send_displs[..]);
+        let mut partition_mut = PartitionMut::new(&mut buf[..], &recv_counts[..], &recv_displs[..]);
+        mpi::request::scope(|scope| {
+            let req = world.immediate_all_to_all_varcount_into(scope, &partition,
+                &mut partition_mut).unwrap();
+            req.wait().unwrap();
+        });
+    }
+
+    assert!(buf.chunks((rank + 1) as usize).zip(0..).all(|(c, i)| c.iter().all(|&x| x == i)));
+}
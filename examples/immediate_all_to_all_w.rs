@@ -0,0 +1,44 @@
+extern crate mpi;
+
+use std::mem;
+
+use mpi::traits::*;
+use mpi::datatype::{UserDatatype, DatatypePartition, DatatypePartitionMut};
+use mpi::topology::Rank;
+use mpi::{Address, Count};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let rank = world.rank();
+    let size = world.size();
+    let extent = mem::size_of::<Rank>() as Address;
+
+    // `types[i]` is a block of `i + 1` contiguous `Rank`s.
+    let types = (1..size + 1)
+        .map(|i| UserDatatype::contiguous(i as Count, Rank::equivalent_datatype()).unwrap())
+        .collect::<Vec<_>>();
+
+    // Send a single block of `types[i]` to rank `i`, receive `rank + 1` instances of `types[0]`
+    // from every rank.
+    let v = vec![rank; (size * (size + 1) / 2) as usize];
+    let send_parts = (0..size)
+        .map(|i| (1, (i * (i + 1) / 2) as Address * extent, &types[i as usize]))
+        .collect::<Vec<_>>();
+
+    let mut a = vec![0; (size * (rank + 1)) as usize];
+    let recv_parts = (0..size)
+        .map(|i| (rank + 1, (i * (rank + 1)) as Address * extent, &types[0]))
+        .collect::<Vec<_>>();
+
+    {
+        let send = unsafe { DatatypePartition::new(&v[..], &send_parts[..]) };
+        let mut recv = unsafe { DatatypePartitionMut::new(&mut a[..], &recv_parts[..]) };
+        mpi::request::scope(|scope| {
+            world.immediate_all_to_all_w_into(scope, &send, &mut recv).unwrap().wait().unwrap();
+        });
+    }
+
+    assert!(a.chunks((rank + 1) as usize).zip(0..).all(|(c, i)| c.iter().all(|&x| x == i)));
+}
@@ -12,11 +12,11 @@ fn main() {
         let n = (size - 1) as usize;
         let mut buf = vec![0u64; 3 * n];
         // receive first 2 * n messages
-        for x in buf[0..2 * n].iter_mut() { world.receive_into(x); }
+        for x in buf[0..2 * n].iter_mut() { world.receive_into(x).unwrap(); }
         // signal the waiting senders that 2 * n messages have been received
-        let breq = world.immediate_barrier();
+        let breq = world.immediate_barrier().unwrap();
         // receive remaining n messages
-        for x in buf[2 * n..3 * n].iter_mut() { world.receive_into(x); }
+        for x in buf[2 * n..3 * n].iter_mut() { world.receive_into(x).unwrap(); }
         println!("{:?}", buf);
         // messages "1" and "2" may be interleaved, but all have to be contained within the first
         // 2 * n slots of the buffer
@@ -25,17 +25,17 @@ fn main() {
         // the last n slots in the buffer may only contain message "3"
         assert!(buf[2 * n..3 * n].iter().all(|&x| { x == 3 }));
         // clean up the barrier request
-        breq.wait();
+        breq.wait().unwrap();
     } else { // sender processes
         // send message "1"
-        world.process_at_rank(0).send(&1u64);
+        world.process_at_rank(0).send(&1u64).unwrap();
         // join barrier, but do not block
-        let breq = world.immediate_barrier();
+        let breq = world.immediate_barrier().unwrap();
         // send message "2"
-        world.process_at_rank(0).send(&2u64);
+        world.process_at_rank(0).send(&2u64).unwrap();
         // wait for receiver process to receive the first 2 * n messages
-        breq.wait();
+        breq.wait().unwrap();
         // send message "3"
-        world.process_at_rank(0).send(&3u64);
+        world.process_at_rank(0).send(&3u64).unwrap();
     }
 }
@@ -15,11 +15,15 @@ fn main () {
 
     if world.rank() == root_rank {
         let mut a = vec![0u64; count];
-        root_process.immediate_gather_into_root(&i, &mut a[..]).wait();
+        mpi::request::scope(|scope| {
+            root_process.immediate_gather_into_root(scope, &i, &mut a[..]).unwrap().wait().unwrap();
+        });
         println!("Root gathered sequence: {:?}.", a);
         assert!(a.iter().enumerate().all(|(a, &b)| b == 2u64.pow(a as u32 + 1)));
     } else {
-        root_process.immediate_gather_into(&i).wait();
+        mpi::request::scope(|scope| {
+            root_process.immediate_gather_into(scope, &i).unwrap().wait().unwrap();
+        });
     }
 
     let factor = world.rank() as u64 + 1;
@@ -27,17 +31,22 @@ fn main () {
 
     if world.rank() == root_rank {
         let mut t = vec![0u64; count * count];
-        root_process.immediate_gather_into_root(&a[..], &mut t[..]).wait();
+        mpi::request::scope(|scope| {
+            root_process.immediate_gather_into_root(scope, &a[..], &mut t[..])
+                .unwrap().wait().unwrap();
+        });
         println!("Root gathered table:");
         for r in t.chunks(count) {
             println!("{:?}", r);
         }
         assert!((0_u64..).zip(t.iter()).all(|(a, &b)| b == (a / count as u64 + 1) * (a % count as u64 + 1)));
     } else {
-        root_process.immediate_gather_into(&a[..]).wait();
+        mpi::request::scope(|scope| {
+            root_process.immediate_gather_into(scope, &a[..]).unwrap().wait().unwrap();
+        });
     }
 
-    let d = UserDatatype::contiguous(count as Count, u64::equivalent_datatype());
+    let d = UserDatatype::contiguous(count as Count, u64::equivalent_datatype()).unwrap();
     let sv = unsafe { View::with_count_and_datatype(&a[..], 1, &d) };
 
     if world.rank() == root_rank {
@@ -45,7 +54,10 @@ fn main () {
 
         {
             let mut rv = unsafe { MutView::with_count_and_datatype(&mut t[..], count as Count, &d) };
-            root_process.immediate_gather_into_root(&sv, &mut rv).wait();
+            mpi::request::scope(|scope| {
+                root_process.immediate_gather_into_root(scope, &sv, &mut rv)
+                    .unwrap().wait().unwrap();
+            });
         }
 
         println!("Root gathered table:");
@@ -54,6 +66,8 @@ fn main () {
         }
         assert!((0_u64..).zip(t.iter()).all(|(a, &b)| b == (a / count as u64 + 1) * (a % count as u64 + 1)));
     } else {
-        root_process.immediate_gather_into(&sv).wait();
+        mpi::request::scope(|scope| {
+            root_process.immediate_gather_into(scope, &sv).unwrap().wait().unwrap();
+        });
     }
 }
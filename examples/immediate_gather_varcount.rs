@@ -0,0 +1,40 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::datatype::PartitionMut;
+use mpi::Count;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let rank = world.rank();
+    let size = world.size();
+
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    // Rank `r` contributes `r` copies of `r`.
+    let msg = vec![rank; rank as usize];
+
+    if rank == root_rank {
+        let counts = (0..size).collect::<Vec<Count>>();
+        let displs = counts.iter().scan(0, |acc, &x| { let tmp = *acc; *acc += x; Some(tmp) })
+            .collect::<Vec<Count>>();
+        let mut buf = vec![0; (size * (size - 1) / 2) as usize];
+        {
+            let mut partition = PartitionMut::new(&mut buf[..], &counts[..], &displs[..]);
+            mpi::request::scope(|scope| {
+                let req = root_process.immediate_gather_varcount_into_root(scope, &msg[..],
+                    &mut partition).unwrap();
+                req.wait().unwrap();
+            });
+        }
+        assert!(buf.iter().zip((0..size).flat_map(|r| vec![r; r as usize])).all(|(&a, b)| a == b));
+    } else {
+        mpi::request::scope(|scope| {
+            let req = root_process.immediate_gather_varcount_into(scope, &msg[..]).unwrap();
+            req.wait().unwrap();
+        });
+    }
+}
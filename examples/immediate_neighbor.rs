@@ -0,0 +1,77 @@
+extern crate mpi;
+
+use std::mem;
+
+use mpi::traits::*;
+use mpi::datatype::{DatatypePartition, DatatypePartitionMut, Partition, PartitionMut};
+use mpi::topology::Rank;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    // A periodic ring, every process has its predecessor and successor as neighbors.
+    let ring = world.create_cartesian_communicator(&[world.size()], &[true], false)
+        .unwrap().unwrap();
+    let rank = ring.rank();
+    let (previous, next) = ring.shift(0, 1).unwrap();
+    let (p, n) = (previous.rank(), next.rank());
+
+    let mut a = [0; 2];
+    mpi::request::scope(|scope| {
+        ring.immediate_neighbor_all_gather_into(scope, &rank, &mut a[..]).unwrap().wait().unwrap();
+    });
+    assert_eq!([p, n], a);
+
+    let mut a = [0; 2];
+    let v = [rank, rank + 100];
+    mpi::request::scope(|scope| {
+        ring.immediate_neighbor_all_to_all_into(scope, &v[..], &mut a[..]).unwrap().wait().unwrap();
+    });
+    assert_eq!([p + 100, n], a);
+
+    let v = vec![rank; rank as usize + 1];
+    let mut a = vec![0; (p + n + 2) as usize];
+    {
+        let counts = [p + 1, n + 1];
+        let displs = [0, p + 1];
+        let mut partition = PartitionMut::new(&mut a[..], &counts[..], &displs[..]);
+        mpi::request::scope(|scope| {
+            ring.immediate_neighbor_all_gather_varcount_into(scope, &v[..], &mut partition)
+                .unwrap().wait().unwrap();
+        });
+    }
+    assert!(a[..(p + 1) as usize].iter().all(|&x| x == p));
+    assert!(a[(p + 1) as usize..].iter().all(|&x| x == n));
+
+    // Send one element to the predecessor and two to the successor.
+    let v = [rank; 3];
+    let mut a = [0; 3];
+    {
+        let (send_counts, send_displs) = ([1, 2], [0, 1]);
+        let (recv_counts, recv_displs) = ([2, 1], [0, 2]);
+        let send = Partition::new(&v[..], &send_counts[..], &send_displs[..]);
+        let mut recv = PartitionMut::new(&mut a[..], &recv_counts[..], &recv_displs[..]);
+        mpi::request::scope(|scope| {
+            ring.immediate_neighbor_all_to_all_varcount_into(scope, &send, &mut recv)
+                .unwrap().wait().unwrap();
+        });
+    }
+    assert_eq!([p, p, n], a);
+
+    // The same, but with displacements in bytes and a datatype for every part.
+    let mut a = [0; 3];
+    {
+        let t = Rank::equivalent_datatype();
+        let size = mem::size_of::<Rank>() as mpi::Address;
+        let send_parts = [(1, 0, t), (2, size, t)];
+        let recv_parts = [(2, 0, t), (1, 2 * size, t)];
+        let send = unsafe { DatatypePartition::new(&v[..], &send_parts[..]) };
+        let mut recv = unsafe { DatatypePartitionMut::new(&mut a[..], &recv_parts[..]) };
+        mpi::request::scope(|scope| {
+            ring.immediate_neighbor_all_to_all_w_into(scope, &send, &mut recv)
+                .unwrap().wait().unwrap();
+        });
+    }
+    assert_eq!([p, p, n], a);
+}
@@ -0,0 +1,39 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::topology::Rank;
+use mpi::collective::SystemOperation;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+    // Operations are borrowed by non-blocking reductions, so they have to outlive the scope.
+    let sum_op = SystemOperation::sum();
+    let max_op = SystemOperation::max();
+
+    if rank == root_rank {
+        let mut sum: Rank = 0;
+        mpi::request::scope(|scope| {
+            root_process.immediate_reduce_into_root(scope, &rank, &mut sum, &sum_op)
+                .unwrap().wait().unwrap();
+        });
+        assert_eq!(sum, size * (size - 1) / 2);
+    } else {
+        mpi::request::scope(|scope| {
+            root_process.immediate_reduce_into(scope, &rank, &sum_op)
+                .unwrap().wait().unwrap();
+        });
+    }
+
+    let mut max: Rank = -1;
+
+    mpi::request::scope(|scope| {
+        world.immediate_all_reduce_into(scope, &rank, &mut max, &max_op)
+            .unwrap().wait().unwrap();
+    });
+    assert_eq!(max, size - 1);
+}
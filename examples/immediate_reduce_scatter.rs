@@ -0,0 +1,34 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::topology::Rank;
+use mpi::collective::SystemOperation;
+use mpi::Count;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // Every rank contributes `rank * size + i` to block `i`.
+    let v = (0..size).map(|i| rank * size + i).collect::<Vec<Rank>>();
+    let mut x: Rank = 0;
+    let sum = SystemOperation::sum();
+    mpi::request::scope(|scope| {
+        world.immediate_reduce_scatter_block_into(scope, &v[..], &mut x, &sum)
+            .unwrap().wait().unwrap();
+    });
+    assert_eq!(x, size * size * (size - 1) / 2 + size * rank);
+
+    // Block `i` contains `i + 1` elements.
+    let counts = (1..size + 1).collect::<Vec<Count>>();
+    let v = vec![rank; (size * (size + 1) / 2) as usize];
+    let mut y = vec![0; rank as usize + 1];
+    let max = SystemOperation::max();
+    mpi::request::scope(|scope| {
+        world.immediate_reduce_scatter_into(scope, &v[..], &mut y[..], &counts[..], &max)
+            .unwrap().wait().unwrap();
+    });
+    assert!(y.iter().all(|&y| y == size - 1));
+}
@@ -0,0 +1,34 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::topology::Rank;
+use mpi::collective::SystemOperation;
+
+fn fac(n: Rank) -> Rank {
+    (1..n + 1).fold(1, |x, y| x * y)
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+
+    let sum = SystemOperation::sum();
+    let mut x = 0;
+    mpi::request::scope(|scope| {
+        world.immediate_scan_into(scope, &rank, &mut x, &sum)
+            .unwrap().wait().unwrap();
+    });
+    assert_eq!(x, (rank * (rank + 1)) / 2);
+
+    let y = rank + 1;
+    let mut z = 0;
+    let product = SystemOperation::product();
+    mpi::request::scope(|scope| {
+        world.immediate_exclusive_scan_into(scope, &y, &mut z, &product)
+            .unwrap().wait().unwrap();
+    });
+    if rank > 0 {
+        assert_eq!(z, fac(y - 1));
+    }
+}
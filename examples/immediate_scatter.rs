@@ -12,13 +12,15 @@ fn main() {
     let root_process = world.process_at_rank(root_rank);
 
     let mut x = 0 as Rank;
-    if rank == root_rank {
-        let v = (0..size).collect::<Vec<_>>();
-        let req = root_process.immediate_scatter_into_root(&v[..], &mut x);
-        req.wait();
-    } else {
-        let req = root_process.immediate_scatter_into(&mut x);
-        req.wait();
-    }
+    let v = (0..size).collect::<Vec<_>>();
+    mpi::request::scope(|scope| {
+        if rank == root_rank {
+            let req = root_process.immediate_scatter_into_root(scope, &v[..], &mut x).unwrap();
+            req.wait().unwrap();
+        } else {
+            let req = root_process.immediate_scatter_into(scope, &mut x).unwrap();
+            req.wait().unwrap();
+        }
+    });
     assert_eq!(x, rank);
 }
@@ -0,0 +1,39 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::datatype::Partition;
+use mpi::Count;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let rank = world.rank();
+    let size = world.size();
+
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    // Rank `r` receives `r` copies of `r`.
+    let mut buf = vec![0; rank as usize];
+
+    if rank == root_rank {
+        let msg = (0..size).flat_map(|r| vec![r; r as usize]).collect::<Vec<_>>();
+        let counts = (0..size).collect::<Vec<Count>>();
+        let displs = counts.iter().scan(0, |acc, &x| { let tmp = *acc; *acc += x; Some(tmp) })
+            .collect::<Vec<Count>>();
+        let partition = Partition::new(&msg[..], &counts[..], &displs[..]);
+        mpi::request::scope(|scope| {
+            let req = root_process.immediate_scatter_varcount_into_root(scope, &partition,
+                &mut buf[..]).unwrap();
+            req.wait().unwrap();
+        });
+    } else {
+        mpi::request::scope(|scope| {
+            let req = root_process.immediate_scatter_varcount_into(scope, &mut buf[..]).unwrap();
+            req.wait().unwrap();
+        });
+    }
+
+    assert!(buf.iter().all(|&x| x == rank));
+}
@@ -0,0 +1,61 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::topology::{Color, Rank};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let odd = rank % 2 != 0;
+    let local = world.split_by_color(Color::with_value(rank % 2)).unwrap().unwrap();
+    let remote_leader = if odd { 0 } else { 1 };
+    let inter = local.create_intercommunicator(0, &world, remote_leader).unwrap();
+
+    assert!(inter.is_intercommunicator());
+    assert!(!local.is_intercommunicator());
+    let num_even = (size + 1) / 2;
+    let num_odd = size / 2;
+    assert_eq!(if odd { num_even } else { num_odd }, inter.remote_size());
+    assert_eq!(inter.remote_size(), inter.remote_group().unwrap().size());
+
+    if inter.rank() < inter.remote_size() {
+        let (msg, _) = inter.send_receive::<_, Rank>(&rank, inter.rank(), inter.rank()).unwrap();
+        let expected = if odd { rank - 1 } else { rank + 1 };
+        assert_eq!(Some(expected), msg);
+    }
+
+    // The root of the even group broadcasts to the odd group.
+    let mut x = if odd { 0 } else { rank + 42 };
+    if odd {
+        inter.process_at_rank(0).broadcast_into(&mut x).unwrap();
+        assert_eq!(42, x);
+    } else if inter.rank() == 0 {
+        inter.root_process().broadcast_into(&mut x).unwrap();
+    } else {
+        inter.null_process().broadcast_into(&mut x).unwrap();
+    }
+
+    // The root of the even group gathers the ranks of all odd processes.
+    if odd {
+        inter.process_at_rank(0).gather_into(&rank).unwrap();
+    } else if inter.rank() == 0 {
+        let mut a = vec![0; num_odd as usize];
+        inter.root_process().gather_into_root(&rank, &mut a[..]).unwrap();
+        assert!(a.iter().enumerate().all(|(i, &r)| r == 2 * i as Rank + 1));
+    } else {
+        inter.null_process().gather_into(&rank).unwrap();
+    }
+
+    let twin = inter.duplicate().unwrap();
+    assert!(twin.is_intercommunicator());
+    assert_eq!(inter.remote_size(), twin.remote_size());
+
+    let merged = inter.merge(odd).unwrap();
+    assert!(!merged.is_intercommunicator());
+    assert_eq!(size, merged.size());
+    let expected = if odd { num_even + rank / 2 } else { rank / 2 };
+    assert_eq!(expected, merged.rank());
+}
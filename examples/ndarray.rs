@@ -0,0 +1,53 @@
+extern crate mpi;
+#[macro_use]
+extern crate ndarray;
+
+use ndarray::{Array, Array2, Axis};
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let previous_rank = if rank - 1 >= 0 { rank - 1 } else { size - 1 };
+
+    let entry = |r, i, j| (r * 100 + i as i32 * 10 + j as i32) as f64;
+    let matrix = Array::from_shape_fn((3, 4), |(i, j)| entry(rank, i, j));
+    let expected = Array::from_shape_fn((3, 4), |(i, j)| entry(previous_rank, i, j));
+
+    // A whole matrix in standard layout
+    let mut received = Array2::<f64>::zeros((3, 4));
+    world.send_receive_into(&matrix, next_rank, &mut received, previous_rank).unwrap();
+    assert_eq!(expected, received);
+
+    // A column, i.e. a strided view, received into a contiguous vector
+    let mut column = Array::zeros(3);
+    world.send_receive_into(&matrix.column(2), next_rank, &mut column, previous_rank).unwrap();
+    assert_eq!(expected.column(2), column);
+
+    // Rows gathered from all processes into a matrix in standard layout
+    let mut rows = Array2::<f64>::zeros((size as usize, 4));
+    world.all_gather_into(&matrix.row(0), &mut rows).unwrap();
+    for (r, row) in rows.axis_iter(Axis(0)).enumerate() {
+        assert_eq!(Array::from_shape_fn(4, |j| entry(r as i32, 0, j)), row);
+    }
+
+    // A transposed view received into a strided part of a larger array
+    let mut target = Array2::<f64>::zeros((8, 3));
+    {
+        let mut every_other_row = target.slice_mut(s![..;2, ..]);
+        world.send_receive_into(&matrix.t(), next_rank, &mut every_other_row, previous_rank)
+            .unwrap();
+    }
+    for (i, row) in target.axis_iter(Axis(0)).enumerate() {
+        if i % 2 == 0 {
+            assert_eq!(expected.column(i / 2), row);
+        } else {
+            assert!(row.iter().all(|&x| x == 0.0));
+        }
+    }
+}
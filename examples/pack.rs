@@ -0,0 +1,41 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::datatype::PackedBuffer;
+use mpi::Count;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let root_process = world.process_at_rank(0);
+
+    let mut packed_size: Count = 0;
+    let mut packed = if rank == 0 {
+        let values = [1.0f64, 2.5, -3.0];
+        let mut packed = PackedBuffer::new();
+        world.pack_into(&(values.len() as i32), &mut packed).unwrap();
+        world.pack_into(&values[..], &mut packed).unwrap();
+        world.pack_into(&42u64, &mut packed).unwrap();
+        assert!(packed.count() <= world.pack_size(&values[..]).unwrap() +
+            world.pack_size(&0i32).unwrap() + world.pack_size(&0u64).unwrap());
+        packed_size = packed.count();
+        root_process.broadcast_into(&mut packed_size).unwrap();
+        packed
+    } else {
+        root_process.broadcast_into(&mut packed_size).unwrap();
+        PackedBuffer::from_bytes(vec![0; packed_size as usize])
+    };
+    root_process.broadcast_into(&mut packed).unwrap();
+    assert_eq!(packed_size as usize, packed.len());
+
+    let mut len = 0i32;
+    world.unpack_from(&mut packed, &mut len).unwrap();
+    let mut values = vec![0.0f64; len as usize];
+    world.unpack_from(&mut packed, &mut values[..]).unwrap();
+    let mut answer = 0u64;
+    world.unpack_from(&mut packed, &mut answer).unwrap();
+
+    assert_eq!(vec![1.0, 2.5, -3.0], values);
+    assert_eq!(42, answer);
+}
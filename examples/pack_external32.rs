@@ -0,0 +1,35 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::datatype::{self, PackedBuffer};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let previous_rank = if rank - 1 >= 0 { rank - 1 } else { size - 1 };
+
+    let values = [rank as u16, 0x0102, 0xfffe];
+    let mut packed = PackedBuffer::external32();
+    datatype::pack_external32_into(&values[..], &mut packed).unwrap();
+    datatype::pack_external32_into(&(rank as f64), &mut packed).unwrap();
+
+    // external32 is big-endian with fixed sizes, regardless of the platform.
+    let values_size = datatype::external32_pack_size(&values[..]).unwrap();
+    assert_eq!(values_size + 8, packed.len() as mpi::Address);
+    assert_eq!(&[0x01, 0x02, 0xff, 0xfe], &packed.as_bytes()[2..6]);
+
+    // The bytes can be stored away and read back in later, or sent to another process.
+    let mut received = PackedBuffer::external32_from_bytes(vec![0; packed.len()]);
+    world.send_receive_into(&packed, next_rank, &mut received, previous_rank).unwrap();
+
+    let mut values = [0u16; 3];
+    datatype::unpack_external32_from(&mut received, &mut values[..]).unwrap();
+    let mut x = 0.0f64;
+    datatype::unpack_external32_from(&mut received, &mut x).unwrap();
+    assert_eq!([previous_rank as u16, 0x0102, 0xfffe], values);
+    assert_eq!(previous_rank as f64, x);
+}
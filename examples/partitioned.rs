@@ -0,0 +1,146 @@
+extern crate crossbeam;
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::request;
+use mpi::request::RequestCollection;
+use mpi::topology::Rank;
+use mpi::Threading;
+
+const PARTITIONS: usize = 4;
+const PARTITION_SIZE: usize = 8;
+
+fn main() {
+    let (universe, threading) = mpi::initialize_with_threading(Threading::Multiple).unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // Processes are paired up, the even rank sends to the odd rank.
+    let partner_rank = if rank % 2 == 0 { rank + 1 } else { rank - 1 };
+    if partner_rank >= size {
+        return;
+    }
+    let partner_process = world.process_at_rank(partner_rank);
+
+    // Partitioned communication is only available from MPI 4.0 onwards.
+    if mpi::get_version().0 < 4 {
+        let mut buf = vec![0 as Rank; PARTITIONS * PARTITION_SIZE];
+        request::scope(|scope| {
+            assert!(partner_process.partitioned_send(scope, &mut buf[..], PARTITIONS).is_err());
+        });
+        return;
+    }
+
+    let mut buf = vec![0 as Rank; PARTITIONS * PARTITION_SIZE];
+    request::scope(|scope| {
+        if rank % 2 == 0 {
+            let mut sreq = partner_process.partitioned_send(scope, &mut buf[..], PARTITIONS)
+                .unwrap();
+            for step in 0..3 {
+                if threading == Threading::Multiple {
+                    let mut active = sreq.start().unwrap();
+                    {
+                        // Every partition is filled and marked as ready by its own thread.
+                        let (ready, partitions) = active.partitions().unwrap();
+                        let ready = &ready;
+                        crossbeam::scope(|threads| {
+                            for partition in partitions {
+                                threads.spawn(move || fill_and_ready(ready, partition, rank, step));
+                            }
+                        });
+                    }
+                    sreq = active.wait().unwrap().0;
+                } else {
+                    // Partitions are only handed out to threads under `Threading::Multiple`,
+                    // otherwise the buffer is filled before the operation is started.
+                    for (i, partition) in sreq.buffer_mut().chunks_mut(PARTITION_SIZE).enumerate() {
+                        for x in partition {
+                            *x = value(rank, i, step);
+                        }
+                    }
+                    let mut active = sreq.start().unwrap();
+                    assert!(active.partitions().is_err());
+                    sreq = active.wait().unwrap().0;
+                }
+            }
+        } else {
+            let mut rreq = partner_process.partitioned_receive_into(scope, &mut buf[..],
+                PARTITIONS).unwrap();
+            for step in 0..3 {
+                let active = rreq.start().unwrap();
+                // Partitions can be read as soon as they have arrived, in any order.
+                let mut done = vec![false; PARTITIONS];
+                while done.iter().any(|&d| !d) {
+                    for i in 0..PARTITIONS {
+                        if done[i] {
+                            continue;
+                        }
+                        if let Some(partition) = active.partition(i).unwrap() {
+                            assert!(partition.iter().all(|&x| x == value(partner_rank, i, step)));
+                            done[i] = true;
+                        }
+                    }
+                }
+                let (r, status) = active.wait().unwrap();
+                rreq = r;
+                assert_eq!(partner_rank, status.source_rank());
+            }
+        }
+    });
+
+    if rank % 2 != 0 {
+        for (i, partition) in buf.chunks(PARTITION_SIZE).enumerate() {
+            assert!(partition.iter().all(|&x| x == value(partner_rank, i, 2)));
+        }
+    }
+
+    // Partitions that are not handed out or are dropped are marked as ready implicitly.
+    let mut x = vec![rank; PARTITIONS];
+    let mut y = vec![-1; PARTITIONS];
+    request::scope(|scope| {
+        if rank % 2 == 0 {
+            let sreq = partner_process.partitioned_send(scope, &mut x[..], PARTITIONS).unwrap();
+            let sreq = sreq.start().unwrap().wait().unwrap().0;
+            let mut active = sreq.start().unwrap();
+            if threading == Threading::Multiple {
+                drop(active.partitions().unwrap());
+            }
+            let (sreq, _) = active.wait().unwrap();
+            assert_eq!(PARTITIONS, sreq.num_partitions());
+
+            drop(sreq.start().unwrap().wait().unwrap());
+        } else {
+            let rreq = partner_process.partitioned_receive_into(scope, &mut y[..], PARTITIONS)
+                .unwrap();
+            let (mut rreq, status) = rreq.start().unwrap().wait().unwrap();
+            assert_eq!(partner_rank, status.source_rank());
+            assert!(rreq.buffer().iter().all(|&v| v == partner_rank));
+            rreq.buffer_mut()[0] = -1;
+            let (mut rreq, _) = rreq.start().unwrap().wait().unwrap();
+            assert!(rreq.buffer().iter().all(|&v| v == partner_rank));
+            rreq.buffer_mut()[0] = -1;
+            let mut requests = RequestCollection::new();
+            requests.push(rreq.start().unwrap());
+            assert_eq!(1, requests.incomplete());
+            requests.wait_all().unwrap();
+            assert_eq!(0, requests.incomplete());
+        }
+    });
+    if rank % 2 != 0 {
+        assert_eq!(vec![partner_rank; PARTITIONS], y);
+    }
+}
+
+fn value(rank: Rank, partition: usize, step: usize) -> Rank {
+    rank * 100 + (partition * 10 + step) as Rank
+}
+
+fn fill_and_ready(ready: &request::PartitionReady, mut partition: request::SendPartition<Rank>,
+                  rank: Rank, step: usize) {
+    let v = value(rank, partition.index(), step);
+    for x in partition.buffer_mut() {
+        *x = v;
+    }
+    ready.ready(partition).unwrap();
+}
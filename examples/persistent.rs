@@ -0,0 +1,71 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::request;
+use mpi::request::RequestCollection;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let previous_rank = if rank - 1 >= 0 { rank - 1 } else { size - 1 };
+    let next_process = world.process_at_rank(next_rank);
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // Pass a value around the ring, the requests are created once and started in every step.
+    let mut x = rank;
+    let mut y = -1;
+    request::scope(|scope| {
+        let mut sreq = next_process.persistent_send(scope, &mut x).unwrap();
+        let mut rreq = previous_process.persistent_receive_into(scope, &mut y).unwrap();
+        for step in 0..size {
+            let active_rreq = rreq.start().unwrap();
+            let active_sreq = sreq.start().unwrap();
+            let (r, status) = active_rreq.wait().unwrap();
+            rreq = r;
+            assert_eq!(previous_rank, status.source_rank());
+            sreq = active_sreq.wait().unwrap().0;
+
+            let expected = (rank - step - 1 + 2 * size) % size;
+            assert_eq!(expected, *rreq.buffer());
+            *sreq.buffer_mut() = *rreq.buffer();
+        }
+    });
+    assert_eq!(rank, y);
+
+    // Exchange halos with both neighbors in every step, starting all requests at once. Requests
+    // with different types of buffers can be started together in a `RequestCollection`.
+    let mut left_boundary = [rank as f64; 2];
+    let mut right_boundary = [rank as f64; 2];
+    let mut left_halo = [-1.0f64; 2];
+    let mut right_halo = [-1.0f64; 2];
+    let mut own_rank = rank;
+    let mut previous_rank_received = -1;
+    request::scope(|scope| {
+        let mut requests = RequestCollection::new();
+        requests.push(previous_process.persistent_receive_into_with_tag(scope,
+            &mut left_halo[..], 0).unwrap());
+        requests.push(next_process.persistent_receive_into_with_tag(scope,
+            &mut right_halo[..], 1).unwrap());
+        requests.push(previous_process.persistent_receive_into_with_tag(scope,
+            &mut previous_rank_received, 2).unwrap());
+        requests.push(next_process.persistent_send_with_tag(scope,
+            &mut right_boundary[..], 0).unwrap());
+        requests.push(previous_process.persistent_send_with_tag(scope,
+            &mut left_boundary[..], 1).unwrap());
+        requests.push(next_process.persistent_send_with_tag(scope, &mut own_rank, 2).unwrap());
+        assert_eq!(0, requests.incomplete());
+        for _ in 0..3 {
+            requests.start_all().unwrap();
+            assert_eq!(6, requests.incomplete());
+            requests.wait_all().unwrap();
+            assert_eq!(0, requests.incomplete());
+        }
+    });
+    assert_eq!([previous_rank as f64; 2], left_halo);
+    assert_eq!([next_rank as f64; 2], right_halo);
+    assert_eq!(previous_rank, previous_rank_received);
+}
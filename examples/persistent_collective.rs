@@ -0,0 +1,165 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::request;
+use mpi::request::RequestCollection;
+use mpi::collective::SystemOperation;
+use mpi::topology::Rank;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    // Persistent collectives are only available from MPI 4.0 onwards.
+    if mpi::get_version().0 < 4 {
+        request::scope(|scope| {
+            assert!(world.persistent_barrier(scope).is_err());
+        });
+        return;
+    }
+
+    // Operations are borrowed by persistent reductions, so they have to outlive the scope.
+    let sum_op = SystemOperation::sum();
+    let max_op = SystemOperation::max();
+
+    // Repeated global sums, e.g. of a residual in an iterative solver
+    let mut local: Rank = 0;
+    let mut global: Rank = 0;
+    request::scope(|scope| {
+        let mut sum = world.persistent_all_reduce_into(scope, &mut local, &mut global, &sum_op)
+            .unwrap();
+        for step in 0..4 {
+            *sum.send_buffer_mut() = rank + step;
+            sum = sum.start().unwrap().wait().unwrap();
+            assert_eq!(size * (size - 1) / 2 + size * step, *sum.receive_buffer());
+        }
+    });
+
+    let mut x = [0u64; 2];
+    let mut max = [0 as Rank];
+    let mut v = vec![0 as Rank; size as usize];
+    let mut w = vec![0 as Rank; size as usize];
+    let mut t = vec![0 as Rank; size as usize];
+    request::scope(|scope| {
+        let mut barrier = world.persistent_barrier(scope).unwrap();
+        let mut broadcast = root_process.persistent_broadcast_into(scope, &mut x[..]).unwrap();
+        let mut gather = world.persistent_all_gather_into(scope, &mut max[..], &mut v[..])
+            .unwrap();
+        let mut exchange = world.persistent_all_to_all_into(scope, &mut w[..], &mut t[..])
+            .unwrap();
+
+        for step in 0..3u64 {
+            if rank == root_rank {
+                broadcast.receive_buffer_mut().copy_from_slice(&[step, 2 * step]);
+            }
+            broadcast = broadcast.start().unwrap().wait().unwrap();
+            assert_eq!(&[step, 2 * step], broadcast.receive_buffer());
+
+            gather.send_buffer_mut()[0] = rank * step as Rank;
+            gather = gather.start().unwrap().wait().unwrap();
+            assert!(gather.receive_buffer().iter().zip(0..).all(|(&r, i)| r == i * step as Rank));
+
+            for (i, x) in exchange.send_buffer_mut().iter_mut().enumerate() {
+                *x = rank * size + i as Rank;
+            }
+            let mut active = exchange.start().unwrap();
+            exchange = loop {
+                match active.test().unwrap() {
+                    Ok(exchange) => { break exchange; }
+                    Err(a) => { active = a; }
+                }
+            };
+            assert!(exchange.receive_buffer().iter().zip(0..).all(|(&r, i)| r == i * size + rank));
+
+            barrier = barrier.start().unwrap().wait().unwrap();
+        }
+    });
+
+    // Rooted operations: reduce and gather to the root, scatter from the root
+    let mut value = rank;
+    let mut result: Rank = -1;
+    let mut all = vec![-1 as Rank; size as usize];
+    let mut part: Rank = -1;
+    request::scope(|scope| {
+        if rank == root_rank {
+            let mut reduce = root_process.persistent_reduce_into_root(scope, &mut value,
+                &mut result, &max_op).unwrap();
+            reduce = reduce.start().unwrap().wait().unwrap();
+            assert_eq!(size - 1, *reduce.receive_buffer());
+        } else {
+            let reduce = root_process.persistent_reduce_into(scope, &mut value, &max_op).unwrap();
+            drop(reduce.start().unwrap().wait().unwrap());
+        }
+    });
+    request::scope(|scope| {
+        if rank == root_rank {
+            let mut gather = root_process.persistent_gather_into_root(scope, &mut value,
+                &mut all[..]).unwrap();
+            gather = gather.start().unwrap().wait().unwrap();
+            assert!(gather.receive_buffer().iter().zip(0..).all(|(&r, i)| r == i));
+        } else {
+            let gather = root_process.persistent_gather_into(scope, &mut value).unwrap();
+            drop(gather.start().unwrap().wait().unwrap());
+        }
+    });
+    request::scope(|scope| {
+        if rank == root_rank {
+            let mut scatter = root_process.persistent_scatter_into_root(scope, &mut all[..],
+                &mut part).unwrap();
+            for step in 0..2 {
+                for x in scatter.send_buffer_mut() {
+                    *x += step;
+                }
+                scatter = scatter.start().unwrap().wait().unwrap();
+                assert_eq!(root_rank + step, *scatter.receive_buffer());
+            }
+        } else {
+            let mut scatter = root_process.persistent_scatter_into(scope, &mut part).unwrap();
+            for step in 0..2 {
+                scatter = scatter.start().unwrap().wait().unwrap();
+                assert_eq!(rank + step, *scatter.receive_buffer());
+            }
+        }
+    });
+
+    // Prefix sums and a reduce-scatter with one block per process
+    let mut prefix: Rank = 0;
+    let mut blocks = (0..size).map(|i| rank * size + i).collect::<Vec<Rank>>();
+    let mut block: Rank = 0;
+    request::scope(|scope| {
+        let mut scan = world.persistent_scan_into(scope, &mut value, &mut prefix, &sum_op)
+            .unwrap();
+        scan = scan.start().unwrap().wait().unwrap();
+        assert_eq!(rank * (rank + 1) / 2, *scan.receive_buffer());
+
+        let mut reduce_scatter = world.persistent_reduce_scatter_block_into(scope,
+            &mut blocks[..], &mut block, &sum_op).unwrap();
+        reduce_scatter = reduce_scatter.start().unwrap().wait().unwrap();
+        assert_eq!(size * size * (size - 1) / 2 + size * rank, *reduce_scatter.receive_buffer());
+    });
+
+    // Persistent collectives of different types can be started together in a
+    // `RequestCollection`.
+    let mut contribution = rank;
+    let mut total: Rank = 0;
+    let mut ranks = vec![-1 as Rank; size as usize];
+    request::scope(|scope| {
+        let mut requests = RequestCollection::new();
+        requests.push(world.persistent_all_reduce_into(scope, &mut value, &mut total, &sum_op)
+            .unwrap());
+        requests.push(world.persistent_all_gather_into(scope, &mut contribution, &mut ranks[..])
+            .unwrap());
+        for _ in 0..2 {
+            requests.start_all().unwrap();
+            assert_eq!(2, requests.incomplete());
+            requests.wait_all().unwrap();
+            assert_eq!(0, requests.incomplete());
+        }
+    });
+    assert_eq!(size * (size - 1) / 2, total);
+    assert!(ranks.iter().zip(0..).all(|(&r, i)| r == i));
+}
@@ -0,0 +1,37 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::collective::SystemOperation;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size() as usize;
+
+    let mut flags = vec![false; size];
+    world.all_gather_into(&(rank % 2 == 0), &mut flags[..]).unwrap();
+    assert!(flags.iter().enumerate().all(|(i, &f)| f == (i % 2 == 0)));
+
+    // `char`s are sent as `u32`s and have to be validated on receipt.
+    let mut letters = vec![0u32; size];
+    let letter = ['α', 'b', '€', '𝄞'][rank as usize % 4];
+    world.all_gather_into(&letter, &mut letters[..]).unwrap();
+    assert!(letters.iter().enumerate()
+        .all(|(i, &c)| ::std::char::from_u32(c) == Some(['α', 'b', '€', '𝄞'][i % 4])));
+
+    let mut total = 0usize;
+    world.all_reduce_into(&(rank as usize), &mut total, SystemOperation::sum()).unwrap();
+    assert_eq!(size * (size - 1) / 2, total);
+
+    let mut offset = 0isize;
+    world.all_reduce_into(&-(rank as isize), &mut offset, SystemOperation::min()).unwrap();
+    assert_eq!(1 - size as isize, offset);
+
+    let point = [rank as f64, 2.0 * rank as f64, -1.0];
+    let mut points = vec![[0.0f64; 3]; size];
+    world.all_gather_into(&point, &mut points[..]).unwrap();
+    for (i, p) in points.iter().enumerate() {
+        assert_eq!([i as f64, 2.0 * i as f64, -1.0], *p);
+    }
+}
@@ -13,9 +13,11 @@ fn main() {
     let previous_rank = if rank - 1 >= 0 { rank - 1 } else { size - 1 };
 
     let msg = vec![rank , 2 * rank, 4 * rank];
-    let _sreq = WaitGuard::from(world.process_at_rank(next_rank).immediate_send(&msg[..]));
-
-    let (msg, status) = world.receive_vec();
+    let (msg, status) = mpi::request::scope(|scope| {
+        let _sreq = WaitGuard::from(world.process_at_rank(next_rank).immediate_send(scope, &msg[..])
+            .unwrap());
+        world.receive_vec().unwrap()
+    });
     let msg = msg.unwrap();
 
     println!("Process {} got message {:?}.\nStatus is: {:?}", rank, msg, status);
@@ -33,7 +35,7 @@ fn main() {
     } else {
         a = vec![0; 4];
     }
-    root_process.broadcast_into(&mut a[..]);
+    root_process.broadcast_into(&mut a[..]).unwrap();
     println!("Rank {} received value: {:?}.", world.rank(), &a[..]);
     assert_eq!(&a[..], &[2, 4, 8, 16]);
 }
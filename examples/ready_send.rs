@@ -11,19 +11,19 @@ fn main() {
 
     if rank > 0 {
         let msg = rank as u8;
-        world.barrier();
-        world.process_at_rank(0).ready_send(&msg);
+        world.barrier().unwrap();
+        world.process_at_rank(0).ready_send(&msg).unwrap();
     } else {
         let mut v = vec![0u8; (size - 1) as usize];
-        {
-            let reqs = v.iter_mut().zip((1..)).map(
-                |(x, i)| { world.process_at_rank(i as Rank).immediate_receive_into(x) }
-            ).collect::<Vec<_>>();
-            world.barrier();
+        mpi::request::scope(|scope| {
+            let reqs = v.iter_mut().zip((1..)).map(|(x, i)| {
+                world.process_at_rank(i as Rank).immediate_receive_into(scope, x).unwrap()
+            }).collect::<Vec<_>>();
+            world.barrier().unwrap();
             for req in reqs {
-                req.wait();
+                req.wait().unwrap();
             }
-        }
+        });
         println!("Got message: {:?}", v);
         assert!(v.iter().zip((1..)).all(|(x, i)| { i == *x as usize }));
     }
@@ -13,29 +13,29 @@ fn main() {
 
     if rank == root_rank {
         let mut sum: Rank = 0;
-        world.process_at_rank(root_rank).reduce_into_root(&rank, &mut sum, SystemOperation::sum());
+        world.process_at_rank(root_rank).reduce_into_root(&rank, &mut sum, SystemOperation::sum()).unwrap();
         assert_eq!(sum, size * (size - 1) / 2);
     } else {
-        world.process_at_rank(root_rank).reduce_into(&rank, SystemOperation::sum());
+        world.process_at_rank(root_rank).reduce_into(&rank, SystemOperation::sum()).unwrap();
     }
 
     let mut max: Rank = -1;
 
-    world.all_reduce_into(&rank, &mut max, SystemOperation::max());
+    world.all_reduce_into(&rank, &mut max, SystemOperation::max()).unwrap();
     assert_eq!(max, size - 1);
 
     let a: u64 = 0b0000111111110000;
     let b: u64 = 0b0011110000111100;
 
     let mut c = b;
-    collective::reduce_local_into(&a, &mut c, SystemOperation::bitwise_and());
+    collective::reduce_local_into(&a, &mut c, SystemOperation::bitwise_and()).unwrap();
     assert_eq!(c, 0b0000110000110000);
 
     let mut d = b;
-    collective::reduce_local_into(&a, &mut d, SystemOperation::bitwise_or());
+    collective::reduce_local_into(&a, &mut d, SystemOperation::bitwise_or()).unwrap();
     assert_eq!(d, 0b0011111111111100);
 
     let mut e = b;
-    collective::reduce_local_into(&a, &mut e, SystemOperation::bitwise_xor());
+    collective::reduce_local_into(&a, &mut e, SystemOperation::bitwise_xor()).unwrap();
     assert_eq!(e, 0b0011001111001100);
 }
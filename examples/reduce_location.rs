@@ -0,0 +1,24 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::collective::SystemOperation;
+use mpi::datatype::ValueIndex;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // The residual is smallest on the middle rank.
+    let residual = ((rank - size / 2) as f64).abs() + 0.5;
+    let mut min = ValueIndex::new(0.0, -1);
+    world.all_reduce_into(&ValueIndex::new(residual, rank), &mut min,
+        SystemOperation::min_location()).unwrap();
+    assert_eq!(ValueIndex::new(0.5, size / 2), min);
+
+    let v = [ValueIndex::new(rank, rank), ValueIndex::new(-rank, rank)];
+    let mut max = [ValueIndex::new(0, -1); 2];
+    world.all_reduce_into(&v[..], &mut max[..], SystemOperation::max_location()).unwrap();
+    assert_eq!([ValueIndex::new(size - 1, size - 1), ValueIndex::new(0, 0)], max);
+}
@@ -0,0 +1,44 @@
+extern crate mpi;
+
+use std::mem;
+
+use mpi::traits::*;
+use mpi::datatype::{UserDatatype, View};
+use mpi::{Address, Count};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    let f64_size = mem::size_of::<f64>() as Address;
+    assert_eq!(f64_size, f64::equivalent_datatype().size().unwrap());
+    assert_eq!((0, f64_size), f64::equivalent_datatype().extent().unwrap());
+
+    // A row-major matrix with 3 rows and one column per process
+    let (rows, columns) = (3, size);
+    let column = UserDatatype::vector(rows, 1, columns, f64::equivalent_datatype()).unwrap();
+    assert_eq!(rows as Address * f64_size, column.size().unwrap());
+    assert_eq!((0, ((rows - 1) * columns + 1) as Address * f64_size), column.extent().unwrap());
+
+    // Consecutive columns start one element apart.
+    let column = UserDatatype::resized(&column, 0, f64_size).unwrap();
+    assert_eq!((0, f64_size), column.extent().unwrap());
+    assert_eq!((0, ((rows - 1) * columns + 1) as Address * f64_size),
+        column.true_extent().unwrap());
+
+    let mut c = [0.0; 3];
+    if rank == root_rank {
+        let matrix = (0..rows * columns).map(|i| i as f64).collect::<Vec<_>>();
+        let v = unsafe { View::with_count_and_datatype(&matrix[..], columns as Count, &column) };
+        root_process.scatter_into_root(&v, &mut c[..]).unwrap();
+    } else {
+        root_process.scatter_into(&mut c[..]).unwrap();
+    }
+
+    let expected = (0..rows).map(|r| (r * columns + rank) as f64).collect::<Vec<_>>();
+    assert_eq!(&expected[..], &c[..]);
+}
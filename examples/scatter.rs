@@ -14,9 +14,9 @@ fn main() {
     let mut x = 0 as Rank;
     if rank == root_rank { 
         let v = (0..size).collect::<Vec<_>>();
-        root_process.scatter_into_root(&v[..], &mut x);
+        root_process.scatter_into_root(&v[..], &mut x).unwrap();
     } else {
-        root_process.scatter_into(&mut x);
+        root_process.scatter_into(&mut x).unwrap();
     }
     assert_eq!(x, rank);
 }
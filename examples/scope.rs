@@ -0,0 +1,49 @@
+extern crate mpi;
+
+use std::mem;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let previous_rank = if rank - 1 >= 0 { rank - 1 } else { size - 1 };
+
+    // Requests that are dropped without having been completed are waited on when the scope ends.
+    let x = rank;
+    let mut y = -1;
+    mpi::request::scope(|scope| {
+        let _rreq = world.process_at_rank(previous_rank).immediate_receive_into(scope, &mut y)
+            .unwrap();
+        let _sreq = world.process_at_rank(next_rank).immediate_send(scope, &x).unwrap();
+    });
+    assert_eq!(previous_rank, y);
+
+    // The same holds for requests that are leaked.
+    let v = vec![rank; 4];
+    let mut w = vec![-1; 4];
+    mpi::request::scope(|scope| {
+        let rreq = world.process_at_rank(previous_rank).immediate_receive_into(scope, &mut w[..])
+            .unwrap();
+        mem::forget(rreq);
+        let sreq = world.process_at_rank(next_rank).immediate_send(scope, &v[..]).unwrap();
+        mem::forget(sreq);
+    });
+    assert_eq!(vec![previous_rank; 4], w);
+
+    // A scope returns the value of its closure.
+    let mut z = -1;
+    let status = mpi::request::scope(|scope| {
+        let sreq = world.process_at_rank(next_rank).immediate_synchronous_send(scope, &x)
+            .unwrap();
+        let status = world.process_at_rank(previous_rank).receive_into(&mut z).unwrap();
+        sreq.wait().unwrap();
+        status
+    });
+    assert_eq!(previous_rank, status.source_rank());
+    assert_eq!(previous_rank, z);
+}
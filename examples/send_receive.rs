@@ -12,19 +12,19 @@ fn main() {
     let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
     let previous_rank = if rank - 1 >= 0 { rank - 1 } else { size - 1 };
 
-    let (msg, status) = world.send_receive::<_, mpi::topology::Rank>(&rank, previous_rank, next_rank);
+    let (msg, status) = world.send_receive::<_, mpi::topology::Rank>(&rank, previous_rank, next_rank).unwrap();
     assert!(msg.is_some());
     let msg = msg.unwrap();
     println!("Process {} got message {}.\nStatus is: {:?}", rank, msg, status);
-    world.barrier();
+    world.barrier().unwrap();
     assert_eq!(msg, next_rank);
 
     if rank > 0 {
         let msg = vec![rank, rank + 1, rank - 1];
-        world.process_at_rank(0).send(&msg[..]);
+        world.process_at_rank(0).send(&msg[..]).unwrap();
     } else {
         for _ in 1..size {
-            let (msg, status) = world.receive_vec::<Rank>();
+            let (msg, status) = world.receive_vec::<Rank>().unwrap();
             assert!(msg.is_some());
             let msg = msg.unwrap();
             println!("Process {} got long message {:?}.\nStatus is: {:?}", rank, msg, status);
@@ -34,9 +34,9 @@ fn main() {
             assert_eq!(v, msg);
         }
     }
-    world.barrier();
+    world.barrier().unwrap();
 
     let mut x = rank;
-    world.send_receive_replace_into(&mut x, next_rank, previous_rank);
+    world.send_receive_replace_into(&mut x, next_rank, previous_rank).unwrap();
     assert_eq!(x, previous_rank);
 }
@@ -0,0 +1,38 @@
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    if rank > 0 {
+        let greeting = format!("Grüße from process {} of {}", rank, size);
+        let numbers = (0..rank).collect::<Vec<_>>();
+        let root_process = world.process_at_rank(0);
+        root_process.send(&greeting).unwrap();
+        root_process.send("🦀").unwrap();
+        root_process.send(&[0xf0u8, 0x9f][..]).unwrap();
+        root_process.send(&numbers).unwrap();
+        root_process.send(&numbers.into_boxed_slice()).unwrap();
+    } else {
+        for source in 1..size {
+            let process = world.process_at_rank(source);
+            let (greeting, _) = process.receive_string().unwrap();
+            assert_eq!(Some(Ok(format!("Grüße from process {} of {}", source, size))), greeting);
+            let (crab, _) = process.receive_string().unwrap();
+            assert_eq!(Some(Ok("🦀".to_string())), crab);
+            let (truncated, _) = process.receive_string().unwrap();
+            assert_eq!(vec![0xf0u8, 0x9f], truncated.unwrap().unwrap_err().into_bytes());
+
+            let mut numbers = vec![-1; source as usize];
+            process.receive_into(&mut numbers).unwrap();
+            assert_eq!((0..source).collect::<Vec<_>>(), numbers);
+            let mut numbers = vec![-1; source as usize].into_boxed_slice();
+            process.receive_into(&mut numbers).unwrap();
+            assert_eq!((0..source).collect::<Vec<_>>().into_boxed_slice(), numbers);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+extern crate mpi;
+#[macro_use]
+extern crate serde_derive;
+
+use std::collections::HashMap;
+
+use mpi::traits::*;
+use mpi::topology::Rank;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum Shape {
+    Circle { radius: f64 },
+    Polygon(Vec<(f64, f64)>),
+    Empty
+}
+
+fn shape_of(rank: Rank) -> Shape {
+    match rank % 3 {
+        0 => Shape::Circle { radius: rank as f64 },
+        1 => Shape::Polygon((0..rank).map(|i| (i as f64, -i as f64)).collect()),
+        _ => Shape::Empty
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let census_tag = 1;
+    let shape_tag = 2;
+
+    if rank > 0 {
+        let mut census = HashMap::new();
+        census.insert(format!("process {}", rank), rank);
+        census.insert("total".to_string(), size);
+
+        let root_process = world.process_at_rank(0);
+        root_process.send_serialized_with_tag(&census, census_tag).unwrap();
+        root_process.send_serialized_with_tag(&shape_of(rank), shape_tag).unwrap();
+        root_process.send_serialized_with_tag(&0u8, shape_tag).unwrap();
+    } else {
+        for source in 1..size {
+            let process = world.process_at_rank(source);
+            let (census, status) = process
+                .receive_deserialized_with_tag::<HashMap<String, Rank>>(census_tag).unwrap();
+            let census = census.unwrap().unwrap();
+            assert_eq!(census_tag, status.tag());
+            assert_eq!(2, census.len());
+            assert_eq!(Some(&source), census.get(&format!("process {}", source)));
+            assert_eq!(Some(&size), census.get("total"));
+
+            let (shape, _) = process.receive_deserialized_with_tag::<Shape>(shape_tag).unwrap();
+            assert_eq!(shape_of(source), shape.unwrap().unwrap());
+
+            // A single byte is not a valid encoding of a `Shape`.
+            let (garbage, _) = process.receive_deserialized_with_tag::<Shape>(shape_tag).unwrap();
+            assert!(garbage.unwrap().is_err());
+        }
+    }
+
+    let (nothing, _) = world.null_process().receive_deserialized::<Shape>().unwrap();
+    assert!(nothing.is_none());
+}
@@ -0,0 +1,38 @@
+extern crate mpi;
+
+use std::mem;
+
+use mpi::traits::*;
+use mpi::datatype::UserDatatype;
+use mpi::Address;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Particle {
+    position: [f64; 3],
+    charge: i32
+}
+
+impl EquivalentDatatype for Particle {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        let charge_offset = 3 * mem::size_of::<f64>() as Address;
+        UserDatatype::structured(&[3, 1], &[0, charge_offset],
+            &[&f64::equivalent_datatype(), &i32::equivalent_datatype()]).unwrap()
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let previous_rank = if rank - 1 >= 0 { rank - 1 } else { size - 1 };
+
+    let p = Particle { position: [rank as f64, 0.5, -1.0], charge: rank };
+    let mut q = Particle { position: [0.0; 3], charge: -1 };
+    world.send_receive_into(&p, next_rank, &mut q, previous_rank).unwrap();
+    assert_eq!(Particle { position: [previous_rank as f64, 0.5, -1.0], charge: previous_rank }, q);
+}
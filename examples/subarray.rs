@@ -0,0 +1,43 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::datatype::{UserDatatype, View, MutView, Order};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let previous_rank = if rank - 1 >= 0 { rank - 1 } else { size - 1 };
+
+    // A 2 x 2 x 2 block of cells surrounded by a layer of ghost cells, in row-major order
+    let n = 4;
+    let index = |x: usize, y: usize, z: usize| (x * n + y) * n + z;
+    let a = (0..n * n * n).map(|i| (rank * 1000) as f64 + i as f64).collect::<Vec<_>>();
+    let mut b = vec![-1.0; n * n * n];
+
+    // Send the last interior face in x direction, receive into the first ghost face.
+    let interior_face = UserDatatype::subarray(&[4, 4, 4], &[1, 2, 2], &[2, 1, 1], Order::C,
+        f64::equivalent_datatype()).unwrap();
+    let ghost_face = UserDatatype::subarray(&[4, 4, 4], &[1, 2, 2], &[0, 1, 1], Order::C,
+        f64::equivalent_datatype()).unwrap();
+    {
+        let v = unsafe { View::with_count_and_datatype(&a[..], 1, &interior_face) };
+        let mut w = unsafe { MutView::with_count_and_datatype(&mut b[..], 1, &ghost_face) };
+        world.send_receive_into(&v, next_rank, &mut w, previous_rank).unwrap();
+    }
+
+    for y in 0..n {
+        for z in 0..n {
+            let expected = if y >= 1 && y <= 2 && z >= 1 && z <= 2 {
+                (previous_rank * 1000) as f64 + index(2, y, z) as f64
+            } else {
+                -1.0
+            };
+            assert_eq!(expected, b[index(0, y, z)]);
+        }
+    }
+    assert!(b[index(1, 0, 0)..].iter().all(|&x| x == -1.0));
+}
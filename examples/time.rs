@@ -7,7 +7,7 @@ fn main() {
     let world = universe.world();
 
     let t_start = universe.get_time();
-    world.barrier();
+    world.barrier().unwrap();
     let t_end = universe.get_time();
 
     println!("barrier took: {} s", t_end - t_start);
@@ -0,0 +1,47 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::topology::Rank;
+use mpi::collective::UserOperation;
+
+fn first(x: &[Rank], acc: &mut [Rank]) {
+    acc.copy_from_slice(x);
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // A closure capturing its environment
+    let modulus = 7;
+    let op = UserOperation::commutative(move |x: &[Rank], acc: &mut [Rank]| {
+        for (a, &x) in acc.iter_mut().zip(x) {
+            *a = (*a + x) % modulus;
+        }
+    }).unwrap();
+    assert!(op.is_commutative());
+
+    let mut sum = 0;
+    world.all_reduce_into(&rank, &mut sum, &op).unwrap();
+    assert_eq!(sum, (size * (size - 1) / 2) % modulus);
+
+    // A plain function, applied in ascending order of the ranks
+    let op = UserOperation::non_commutative(first).unwrap();
+    assert!(!op.is_commutative());
+
+    let v = [rank, rank + 1];
+    let mut w = [-1, -1];
+    world.all_reduce_into(&v[..], &mut w[..], &op).unwrap();
+    assert_eq!([0, 1], w);
+
+    let root_process = world.process_at_rank(0);
+    if rank == 0 {
+        let mut x = -1;
+        root_process.reduce_into_root(&rank, &mut x, &op).unwrap();
+        assert_eq!(0, x);
+    } else {
+        root_process.reduce_into(&rank, &op).unwrap();
+    }
+}
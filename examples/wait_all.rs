@@ -0,0 +1,60 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::request::RequestCollection;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let previous_rank = if rank - 1 >= 0 { rank - 1 } else { size - 1 };
+
+    // A one dimensional halo exchange on a ring of processes
+    let interior = [rank as f64; 4];
+    let mut left_halo = [-1.0f64; 2];
+    let mut right_halo = [-1.0f64; 2];
+    mpi::request::scope(|scope| {
+        let mut requests = RequestCollection::with_capacity(4);
+        let left = world.process_at_rank(previous_rank);
+        let right = world.process_at_rank(next_rank);
+        requests.push(left.immediate_receive_into_with_tag(scope, &mut left_halo[..], 0).unwrap());
+        requests.push(right.immediate_receive_into_with_tag(scope, &mut right_halo[..], 1)
+            .unwrap());
+        requests.push(right.immediate_send_with_tag(scope, &interior[2..], 0).unwrap());
+        requests.push(left.immediate_send_with_tag(scope, &interior[..2], 1).unwrap());
+        assert_eq!(4, requests.len());
+
+        let statuses = requests.wait_all().unwrap();
+        assert_eq!(0, requests.incomplete());
+        assert_eq!(previous_rank, statuses[0].source_rank());
+        assert_eq!(next_rank, statuses[1].source_rank());
+        assert_eq!(1, statuses[1].tag());
+    });
+    assert_eq!([previous_rank as f64; 2], left_halo);
+    assert_eq!([next_rank as f64; 2], right_halo);
+
+    // The same exchange, polling for completion
+    let mut halos = [-1.0f64; 2];
+    {
+        let (left_halo, right_halo) = halos.split_at_mut(1);
+        mpi::request::scope(|scope| {
+            let mut requests = RequestCollection::new();
+            let left = world.process_at_rank(previous_rank);
+            let right = world.process_at_rank(next_rank);
+            requests.push(left.immediate_receive_into_with_tag(scope, left_halo, 0).unwrap());
+            requests.push(right.immediate_receive_into_with_tag(scope, right_halo, 1).unwrap());
+            requests.push(right.immediate_send_with_tag(scope, &interior[3], 0).unwrap());
+            requests.push(left.immediate_send_with_tag(scope, &interior[0], 1).unwrap());
+            loop {
+                if let Some(statuses) = requests.test_all().unwrap() {
+                    assert_eq!(4, statuses.len());
+                    break;
+                }
+            }
+        });
+    }
+    assert_eq!([previous_rank as f64, next_rank as f64], halos);
+}
@@ -0,0 +1,61 @@
+extern crate mpi;
+
+use mpi::traits::*;
+use mpi::request::RequestCollection;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+    let root_rank = 0;
+
+    if rank != root_rank {
+        world.process_at_rank(root_rank).send(&rank).unwrap();
+        world.process_at_rank(root_rank).send_with_tag(&(2 * rank), 1).unwrap();
+        return;
+    }
+
+    // Receive one message from each other process, in whatever order they arrive.
+    let mut ranks = vec![-1; size as usize - 1];
+    mpi::request::scope(|scope| {
+        let mut requests = RequestCollection::new();
+        for (i, r) in ranks.iter_mut().enumerate() {
+            let index = requests.push(world.process_at_rank(i as i32 + 1)
+                .immediate_receive_into(scope, r).unwrap());
+            assert_eq!(i, index);
+        }
+
+        let mut completed = vec![false; size as usize - 1];
+        while let Some((index, status)) = requests.wait_any().unwrap() {
+            assert!(!completed[index]);
+            completed[index] = true;
+            assert_eq!(index as i32 + 1, status.source_rank());
+        }
+        assert!(completed.iter().all(|&c| c));
+        assert!(requests.test_any().unwrap().is_none());
+    });
+    assert_eq!((1..size).collect::<Vec<_>>(), ranks);
+
+    // Receive the second round of messages in batches.
+    let mut doubled = vec![-1; size as usize - 1];
+    mpi::request::scope(|scope| {
+        let mut requests = RequestCollection::new();
+        for (i, r) in doubled.iter_mut().enumerate() {
+            requests.push(world.process_at_rank(i as i32 + 1)
+                .immediate_receive_into_with_tag(scope, r, 1).unwrap());
+        }
+
+        let mut num_completed = 0;
+        while let Some(completed) = requests.wait_some().unwrap() {
+            assert!(!completed.is_empty());
+            for (index, status) in completed {
+                assert_eq!(index as i32 + 1, status.source_rank());
+            }
+            num_completed = size as usize - 1 - requests.incomplete();
+        }
+        assert_eq!(size as usize - 1, num_completed);
+        assert!(requests.test_some().unwrap().is_empty());
+    });
+    assert_eq!((1..size).map(|r| 2 * r).collect::<Vec<_>>(), doubled);
+}
@@ -0,0 +1,118 @@
+//! Custom derive for the `EquivalentDatatype` trait of the `mpi` crate
+//!
+//! Enabled through the `derive` feature of the `mpi` crate, which re-exports the `Equivalence`
+//! derive:
+//!
+//! ```ignore
+//! #[macro_use]
+//! extern crate mpi;
+//!
+//! #[derive(Equivalence)]
+//! struct Particle {
+//!     position: [f64; 3],
+//!     charge: i32
+//! }
+//! ```
+//!
+//! Every field has to be of a type that implements `EquivalentDatatype` or an array thereof. The
+//! MPI datatype is built via `MPI_Type_create_struct()` from the offsets and types of the fields
+//! and resized to the size of the Rust type the first time it is requested. It is then cached for
+//! the remainder of the program.
+#![deny(missing_docs)]
+#![recursion_limit = "256"]
+
+extern crate proc_macro;
+extern crate syn;
+#[macro_use]
+extern crate quote;
+
+use proc_macro::TokenStream;
+
+use syn::{Body, DeriveInput, Ident, Ty, VariantData};
+use quote::Tokens;
+
+/// Implements `EquivalentDatatype` for a struct with fields that implement `EquivalentDatatype`
+#[proc_macro_derive(Equivalence)]
+pub fn derive_equivalence(input: TokenStream) -> TokenStream {
+    let source = input.to_string();
+    let ast = syn::parse_derive_input(&source).expect("Could not parse type definition.");
+    expand_equivalence(&ast).parse().expect("Could not parse generated implementation.")
+}
+
+fn expand_equivalence(ast: &DeriveInput) -> Tokens {
+    let name = &ast.ident;
+
+    // The datatype is cached in a `static` which cannot depend on type parameters.
+    if !ast.generics.lifetimes.is_empty() || !ast.generics.ty_params.is_empty() {
+        panic!("Equivalence cannot be derived for generic type `{}`.", name);
+    }
+
+    let fields = match ast.body {
+        Body::Struct(VariantData::Struct(ref fields)) |
+        Body::Struct(VariantData::Tuple(ref fields)) => fields,
+        _ => panic!("Equivalence can only be derived for structs with fields, `{}` is not one.",
+            name)
+    };
+
+    let mut members = Vec::new();
+    let mut blocklengths = Vec::new();
+    let mut types = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        members.push(match field.ident {
+            Some(ref ident) => ident.clone(),
+            None => Ident::new(i.to_string())
+        });
+        match field.ty {
+            Ty::Array(ref ty, ref len) => {
+                blocklengths.push(quote! { (#len) as ::mpi::Count });
+                types.push((**ty).clone());
+            }
+            ref ty => {
+                blocklengths.push(quote! { 1 });
+                types.push(ty.clone());
+            }
+        }
+    }
+    let members = &members;
+    let indices = (0..fields.len()).map(|i| Ident::new(i.to_string())).collect::<Vec<_>>();
+
+    quote! {
+        impl ::mpi::datatype::EquivalentDatatype for #name {
+            type Out = &'static ::mpi::datatype::UserDatatype;
+
+            fn equivalent_datatype() -> Self::Out {
+                static INIT: ::std::sync::Once = ::std::sync::ONCE_INIT;
+                static mut DATATYPE: Option<::mpi::datatype::UserDatatype> = None;
+
+                INIT.call_once(|| unsafe {
+                    // The offsets are taken from raw pointers into uninitialized storage, no value
+                    // of the type is ever created.
+                    let storage = ::std::mem::MaybeUninit::<#name>::uninit();
+                    let base = storage.as_ptr();
+                    let base_address = base as ::mpi::Address;
+                    let blocklengths = [#(#blocklengths),*];
+                    let displacements = [
+                        #(::std::ptr::addr_of!((*base).#members) as ::mpi::Address
+                            - base_address),*
+                    ];
+
+                    let types = (
+                        #(<#types as ::mpi::datatype::EquivalentDatatype>::equivalent_datatype(),)*
+                    );
+                    let raw_types: &[&::mpi::raw::RawDatatype] = &[#(&types.#indices),*];
+
+                    // The extent has to match the size of the Rust type including any padding so
+                    // that consecutive elements of slices are described correctly.
+                    let datatype = ::mpi::datatype::UserDatatype::structured(&blocklengths,
+                        &displacements, raw_types).and_then(|t| {
+                            ::mpi::datatype::UserDatatype::resized(&t, 0,
+                                ::std::mem::size_of::<#name>() as ::mpi::Address)
+                        }).expect("Could not create equivalent datatype.");
+                    DATATYPE = Some(datatype);
+                });
+
+                unsafe { DATATYPE.as_ref().unwrap() }
+            }
+        }
+    }
+}
@@ -2,28 +2,35 @@
 //!
 //! Developing...
 //!
+//! Non-blocking and persistent reductions borrow their operation for the lifetime of the `Scope`
+//! they are started in, so that a `UserOperation` outlives every reduction that uses it.
+//!
+//! Persistent collective operations require a library that implements version 4.0 of the MPI
+//! standard (see `get_version()`), with older libraries they return an `Error` of class
+//! `MPI_ERR_UNSUPPORTED_OPERATION`.
+//!
 //! # Unfinished features
 //!
-//! - **5.5**: Varying counts gather operation, `MPI_Gatherv()`
-//! - **5.6**: Scatter, `MPI_Scatterv()`
-//! - **5.7**: Gather-to-all, `MPI_Allgatherv()`
-//! - **5.8**: All-to-all, `MPI_Alltoallv()`, `MPI_Alltoallw()`
-//! - **5.9**: Global reduction operations, `MPI_Op_create()`, `MPI_Op_free()`,
-//! `MPI_Op_commutative()`
-//! - **5.10**: Reduce-scatter, `MPI_Reduce_scatter_block()`, `MPI_Reduce_scatter()`
-//! - **5.12**: Nonblocking collective operations, `MPI_Igatherv()`, `MPI_Iscatterv()`,
-//! `MPI_Iallgatherv()`, `MPI_Ialltoallv()`, `MPI_Ialltoallw()`,
-//! `MPI_Ireduce()`, `MPI_Iallreduce()`, `MPI_Ireduce_scatter_block()`, `MPI_Ireduce_scatter()`,
-//! `MPI_Iscan()`, `MPI_Iexscan()`
-
-use std::{mem, ptr};
+//! None of the collective operations in chapter 5 are missing.
+//!
+//! - **6.13 (MPI 4.0)**: Persistent collective operations, the varying counts variants and
+//! persistent neighborhood collectives
+
+use std::{mem, ptr, slice};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
+use libc::{c_int, c_void};
+
+use super::{Count, Error};
+use super::{check, check_version_4};
 use ffi;
-use ffi::{MPI_Request, MPI_Op};
+use ffi::{MPI_Request, MPI_Op, MPI_Datatype};
 
 use datatype::traits::*;
+use datatype::{DatatypePartition, DatatypePartitionMut};
 use raw::traits::*;
-use request::{Request, ReadRequest, WriteRequest, ReadWriteRequest};
+use request::{Request, ReadRequest, WriteRequest, ReadWriteRequest, PersistentCollective, Scope};
 use topology::traits::*;
 use topology::{Rank, Identifier};
 
@@ -43,12 +50,12 @@ pub trait Barrier {
     /// # Examples
     ///
     /// See `examples/barrier.rs`
-    fn barrier(&self);
+    fn barrier(&self) -> Result<(), Error>;
 }
 
 impl<C: Communicator> Barrier for C {
-    fn barrier(&self) {
-        unsafe { ffi::MPI_Barrier(self.communicator().as_raw()); }
+    fn barrier(&self) -> Result<(), Error> {
+        check(unsafe { ffi::MPI_Barrier(self.communicator().as_raw()) })
     }
 }
 
@@ -60,6 +67,18 @@ impl<C: Communicator> Barrier for C {
 pub trait Root: Communicator {
     /// Rank of the root process
     fn root_rank(&self) -> Rank;
+
+    /// Whether the calling process is the root process
+    ///
+    /// On an inter-communicator, this is the case if the calling process passes `MPI_ROOT`, see
+    /// `InterCommunicator::root_process()`.
+    fn is_root(&self) -> bool {
+        if self.communicator().is_intercommunicator() {
+            self.root_rank() == ffi::RSMPI_ROOT
+        } else {
+            self.communicator().rank() == self.root_rank()
+        }
+    }
 }
 
 impl<'a, C: 'a + RawCommunicator> Root for Identifier<'a, C> {
@@ -82,20 +101,18 @@ pub trait BroadcastInto {
     /// # Examples
     ///
     /// See `examples/broadcast.rs`
-    fn broadcast_into<Buf: BufferMut + ?Sized>(&self, buffer: &mut Buf);
+    fn broadcast_into<Buf: BufferMut + ?Sized>(&self, buffer: &mut Buf) -> Result<(), Error>;
 }
 
 impl<T: Root> BroadcastInto for T {
-    fn broadcast_into<Buf: BufferMut + ?Sized>(&self, buffer: &mut Buf) {
-        unsafe {
+    fn broadcast_into<Buf: BufferMut + ?Sized>(&self, buffer: &mut Buf) -> Result<(), Error> {
+        check(unsafe {
             ffi::MPI_Bcast(buffer.pointer_mut(), buffer.count(), buffer.datatype().as_raw(),
-                self.root_rank(), self.communicator().as_raw());
-        }
+                self.root_rank(), self.communicator().as_raw())
+        })
     }
 }
 
-// TODO: Introduce "partitioned buffer" for varying count gather/scatter/alltoall?
-
 /// Gather contents of buffers on `Root`.
 ///
 /// After the call completes, the contents of the `Buffer`s on all ranks will be
@@ -112,7 +129,7 @@ pub trait GatherInto {
     /// # Examples
     ///
     /// See `examples/gather.rs`
-    fn gather_into<S: Buffer + ?Sized>(&self, sendbuf: &S);
+    fn gather_into<S: Buffer + ?Sized>(&self, sendbuf: &S) -> Result<(), Error>;
 
     /// Gather the contents of all `sendbuf`s into `recvbuf` on `Root` `&self`.
     ///
@@ -121,27 +138,76 @@ pub trait GatherInto {
     /// # Examples
     ///
     /// See `examples/gather.rs`
-    fn gather_into_root<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R);
+    fn gather_into_root<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error>;
 }
 
 impl<T: Root> GatherInto for T {
-    fn gather_into<S: Buffer + ?Sized>(&self, sendbuf: &S) {
-        assert!(self.communicator().rank() != self.root_rank());
-        unsafe {
+    fn gather_into<S: Buffer + ?Sized>(&self, sendbuf: &S) -> Result<(), Error> {
+        assert!(!self.is_root());
+        check(unsafe {
             ffi::MPI_Gather(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
                 ptr::null_mut(), 0, u8::equivalent_datatype().as_raw(),
-                self.root_rank(), self.communicator().as_raw());
-        }
+                self.root_rank(), self.communicator().as_raw())
+        })
     }
 
-    fn gather_into_root<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) {
-        assert!(self.communicator().rank() == self.root_rank());
-        unsafe {
-            let recvcount = recvbuf.count() / self.communicator().size();
+    fn gather_into_root<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error> {
+        assert!(self.is_root());
+        let recvcount = recvbuf.count() / self.communicator().target_size();
+        check(unsafe {
             ffi::MPI_Gather(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
                 recvbuf.pointer_mut(), recvcount, recvbuf.datatype().as_raw(),
-                self.root_rank(), self.communicator().as_raw());
-        }
+                self.root_rank(), self.communicator().as_raw())
+        })
+    }
+}
+
+/// Gather contents of buffers of varying size on `Root`.
+///
+/// After the call completes, the contents of the `Buffer`s on all ranks will be stored in the
+/// parts of the partitioned `Buffer` on `Root`, one part per rank.
+///
+/// # Standard section(s)
+///
+/// 5.5
+pub trait GatherVarcountInto {
+    /// Gather the contents of all `sendbuf`s on `Root` `&self`.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/gather_varcount.rs`
+    fn gather_varcount_into<S: Buffer + ?Sized>(&self, sendbuf: &S) -> Result<(), Error>;
+
+    /// Gather the contents of all `sendbuf`s into the parts of `recvbuf` on `Root` `&self`.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/gather_varcount.rs`
+    fn gather_varcount_into_root<S: Buffer + ?Sized, R: PartitionedBufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error>;
+}
+
+impl<T: Root> GatherVarcountInto for T {
+    fn gather_varcount_into<S: Buffer + ?Sized>(&self, sendbuf: &S) -> Result<(), Error> {
+        assert!(!self.is_root());
+        check(unsafe {
+            ffi::MPI_Gatherv(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
+                ptr::null_mut(), ptr::null(), ptr::null(), u8::equivalent_datatype().as_raw(),
+                self.root_rank(), self.communicator().as_raw())
+        })
+    }
+
+    fn gather_varcount_into_root<S: Buffer + ?Sized, R: PartitionedBufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error> {
+        assert!(self.is_root());
+        assert_eq!(self.communicator().target_size(), recvbuf.counts().count());
+        check(unsafe {
+            ffi::MPI_Gatherv(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
+                recvbuf.pointer_mut(), recvbuf.counts().as_ptr(), recvbuf.displs().as_ptr(),
+                recvbuf.datatype().as_raw(), self.root_rank(), self.communicator().as_raw())
+        })
     }
 }
 
@@ -160,16 +226,45 @@ pub trait AllGatherInto {
     /// # Examples
     ///
     /// See `examples/all_gather.rs`
-    fn all_gather_into<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R);
+    fn all_gather_into<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error>;
 }
 
 impl<C: Communicator> AllGatherInto for C {
-    fn all_gather_into<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) {
-        unsafe {
+    fn all_gather_into<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error> {
+        check(unsafe {
             ffi::MPI_Allgather(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
-                recvbuf.pointer_mut(), recvbuf.count() / self.communicator().size(),
-                recvbuf.datatype().as_raw(), self.communicator().as_raw());
-        }
+                recvbuf.pointer_mut(), recvbuf.count() / self.communicator().target_size(),
+                recvbuf.datatype().as_raw(), self.communicator().as_raw())
+        })
+    }
+}
+
+/// Gather contents of buffers of varying size on all participating processes.
+///
+/// After the call completes, the contents of the send `Buffer`s on all processes will be stored
+/// in the parts of the partitioned receive `Buffer`s on all ranks, one part per rank.
+///
+/// # Standard section(s)
+///
+/// 5.7
+pub trait AllGatherVarcountInto {
+    /// Gather the contents of all `sendbuf`s into the parts of all `recvbuf`s on all processes
+    /// in the communicator.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/all_gather_varcount.rs`
+    fn all_gather_varcount_into<S: Buffer + ?Sized, R: PartitionedBufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error>;
+}
+
+impl<C: Communicator> AllGatherVarcountInto for C {
+    fn all_gather_varcount_into<S: Buffer + ?Sized, R: PartitionedBufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error> {
+        assert_eq!(self.communicator().target_size(), recvbuf.counts().count());
+        check(unsafe {
+            ffi::MPI_Allgatherv(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
+                recvbuf.pointer_mut(), recvbuf.counts().as_ptr(), recvbuf.displs().as_ptr(),
+                recvbuf.datatype().as_raw(), self.communicator().as_raw())
+        })
     }
 }
 
@@ -189,7 +284,7 @@ pub trait ScatterInto {
     /// # Examples
     ///
     /// See `examples/scatter.rs`
-    fn scatter_into<R: BufferMut + ?Sized>(&self, recvbuf: &mut R);
+    fn scatter_into<R: BufferMut + ?Sized>(&self, recvbuf: &mut R) -> Result<(), Error>;
 
     /// Scatter the contents of `sendbuf` to all participating processes.
     ///
@@ -198,27 +293,77 @@ pub trait ScatterInto {
     /// # Examples
     ///
     /// See `examples/scatter.rs`
-    fn scatter_into_root<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R);
+    fn scatter_into_root<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error>;
 }
 
 impl<T: Root> ScatterInto for T {
-    fn scatter_into<R: BufferMut + ?Sized>(&self, recvbuf: &mut R) {
-        assert!(self.communicator().rank() != self.root_rank());
-        unsafe {
+    fn scatter_into<R: BufferMut + ?Sized>(&self, recvbuf: &mut R) -> Result<(), Error> {
+        assert!(!self.is_root());
+        check(unsafe {
             ffi::MPI_Scatter(ptr::null(), 0, u8::equivalent_datatype().as_raw(),
                 recvbuf.pointer_mut(), recvbuf.count(), recvbuf.datatype().as_raw(),
-                self.root_rank(), self.communicator().as_raw());
-        }
+                self.root_rank(), self.communicator().as_raw())
+        })
     }
 
-    fn scatter_into_root<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) {
-        assert!(self.communicator().rank() == self.root_rank());
-        let sendcount = sendbuf.count() / self.communicator().size();
-        unsafe {
+    fn scatter_into_root<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error> {
+        assert!(self.is_root());
+        let sendcount = sendbuf.count() / self.communicator().target_size();
+        check(unsafe {
             ffi::MPI_Scatter(sendbuf.pointer(), sendcount, sendbuf.datatype().as_raw(),
                 recvbuf.pointer_mut(), recvbuf.count(), recvbuf.datatype().as_raw(),
-                self.root_rank(), self.communicator().as_raw());
-        }
+                self.root_rank(), self.communicator().as_raw())
+        })
+    }
+}
+
+/// Scatter parts of varying size of a buffer on the root process to all processes.
+///
+/// After the call completes each participating process will have received a part of the
+/// partitioned send `Buffer` on the root process.
+///
+/// # Standard section(s)
+///
+/// 5.6
+pub trait ScatterVarcountInto {
+    /// Scatter data from the root process to all participating processes.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/scatter_varcount.rs`
+    fn scatter_varcount_into<R: BufferMut + ?Sized>(&self, recvbuf: &mut R) -> Result<(), Error>;
+
+    /// Scatter the parts of `sendbuf` to all participating processes.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/scatter_varcount.rs`
+    fn scatter_varcount_into_root<S: PartitionedBuffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error>;
+}
+
+impl<T: Root> ScatterVarcountInto for T {
+    fn scatter_varcount_into<R: BufferMut + ?Sized>(&self, recvbuf: &mut R) -> Result<(), Error> {
+        assert!(!self.is_root());
+        check(unsafe {
+            ffi::MPI_Scatterv(ptr::null(), ptr::null(), ptr::null(),
+                u8::equivalent_datatype().as_raw(), recvbuf.pointer_mut(), recvbuf.count(),
+                recvbuf.datatype().as_raw(), self.root_rank(), self.communicator().as_raw())
+        })
+    }
+
+    fn scatter_varcount_into_root<S: PartitionedBuffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error> {
+        assert!(self.is_root());
+        assert_eq!(self.communicator().target_size(), sendbuf.counts().count());
+        check(unsafe {
+            ffi::MPI_Scatterv(sendbuf.pointer(), sendbuf.counts().as_ptr(),
+                sendbuf.displs().as_ptr(), sendbuf.datatype().as_raw(), recvbuf.pointer_mut(),
+                recvbuf.count(), recvbuf.datatype().as_raw(), self.root_rank(),
+                self.communicator().as_raw())
+        })
     }
 }
 
@@ -233,17 +378,82 @@ pub trait AllToAllInto {
     /// # Examples
     ///
     /// See `examples/all_to_all.rs`
-    fn all_to_all_into<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R);
+    fn all_to_all_into<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error>;
+
+    /// Distribute the parts of `sendbuf` from all ranks to the parts of `recvbuf` on all ranks,
+    /// where every part has its own count, displacement in bytes and datatype.
+    ///
+    /// Part `i` of `sendbuf` is sent to rank `i`, part `i` of `recvbuf` is received from rank `i`.
+    /// If a displacement does not fit into a `Count`, an `Error` of class `MPI_ERR_ARG` is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/all_to_all_w.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.8
+    fn all_to_all_w_into<SD, S: ?Sized, RD, R: ?Sized>(&self, sendbuf: &DatatypePartition<SD, S>, recvbuf: &mut DatatypePartitionMut<RD, R>) -> Result<(), Error>
+        where SD: RawDatatype, S: Pointer, RD: RawDatatype, R: PointerMut;
 }
 
 impl<C: Communicator> AllToAllInto for C {
-    fn all_to_all_into<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) {
-        let c_size = self.communicator().size();
-        unsafe {
+    fn all_to_all_into<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error> {
+        let c_size = self.communicator().target_size();
+        check(unsafe {
             ffi::MPI_Alltoall(sendbuf.pointer(), sendbuf.count() / c_size, sendbuf.datatype().as_raw(),
                 recvbuf.pointer_mut(), recvbuf.count() / c_size, recvbuf.datatype().as_raw(),
-                self.communicator().as_raw());
-        }
+                self.communicator().as_raw())
+        })
+    }
+
+    fn all_to_all_w_into<SD, S: ?Sized, RD, R: ?Sized>(&self, sendbuf: &DatatypePartition<SD, S>, recvbuf: &mut DatatypePartitionMut<RD, R>) -> Result<(), Error>
+        where SD: RawDatatype, S: Pointer, RD: RawDatatype, R: PointerMut
+    {
+        let c_size = self.communicator().target_size();
+        assert_eq!(c_size, sendbuf.counts().count());
+        assert_eq!(c_size, recvbuf.counts().count());
+        let sdispls = try!(sendbuf.narrow_displs()
+            .ok_or(Error::from_raw(ffi::RSMPI_ERR_ARG))).as_ptr();
+        let rdispls = try!(recvbuf.narrow_displs()
+            .ok_or(Error::from_raw(ffi::RSMPI_ERR_ARG))).as_ptr();
+        check(unsafe {
+            ffi::MPI_Alltoallw(sendbuf.pointer(), sendbuf.counts().as_ptr(), sdispls,
+                sendbuf.datatypes().as_ptr(), recvbuf.pointer_mut(), recvbuf.counts().as_ptr(),
+                rdispls, recvbuf.datatypes().as_ptr(), self.communicator().as_raw())
+        })
+    }
+}
+
+/// Distribute the parts of varying size of the send `Buffer`s from all processes to the parts of
+/// the receive `Buffer`s on all processes.
+///
+/// # Standard section(s)
+///
+/// 5.8
+pub trait AllToAllVarcountInto {
+    /// Distribute the parts of `sendbuf` from all ranks to the parts of `recvbuf` on all ranks.
+    ///
+    /// Part `i` of `sendbuf` is sent to rank `i`, part `i` of `recvbuf` is received from rank `i`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/all_to_all_varcount.rs`
+    fn all_to_all_varcount_into<S: PartitionedBuffer + ?Sized, R: PartitionedBufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error>;
+}
+
+impl<C: Communicator> AllToAllVarcountInto for C {
+    fn all_to_all_varcount_into<S: PartitionedBuffer + ?Sized, R: PartitionedBufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error> {
+        let c_size = self.communicator().target_size();
+        assert_eq!(c_size, sendbuf.counts().count());
+        assert_eq!(c_size, recvbuf.counts().count());
+        check(unsafe {
+            ffi::MPI_Alltoallv(sendbuf.pointer(), sendbuf.counts().as_ptr(),
+                sendbuf.displs().as_ptr(), sendbuf.datatype().as_raw(), recvbuf.pointer_mut(),
+                recvbuf.counts().as_ptr(), recvbuf.displs().as_ptr(),
+                recvbuf.datatype().as_raw(), self.communicator().as_raw())
+        })
     }
 }
 
@@ -275,7 +485,11 @@ impl SystemOperation {
         logical_or => ffi::RSMPI_LOR,
         bitwise_or => ffi::RSMPI_BOR,
         logical_xor => ffi::RSMPI_LXOR,
-        bitwise_xor => ffi::RSMPI_BXOR
+        bitwise_xor => ffi::RSMPI_BXOR,
+        min_location => ffi::RSMPI_MINLOC,
+        max_location => ffi::RSMPI_MAXLOC,
+        replace => ffi::RSMPI_REPLACE,
+        no_op => ffi::RSMPI_NO_OP
     }
 }
 
@@ -286,6 +500,187 @@ impl AsRaw for SystemOperation {
 
 impl RawOperation for SystemOperation { }
 
+/// The type-erased closure of a `UserOperation`
+///
+/// Combines `len` elements of the input vector with the elements of the input-output vector, both
+/// of which are described by the datatype passed last.
+type OperationFn = Fn(*mut c_void, *mut c_void, c_int, MPI_Datatype) + Sync;
+
+/// The maximum number of `UserOperation`s that can exist at the same time
+///
+/// `MPI_User_function` does not carry any user data, so every `UserOperation` occupies one of a
+/// fixed number of slots, each of which has its own trampoline function.
+const MAX_USER_OPERATIONS: usize = 32;
+
+static USED_OPERATION_SLOTS: AtomicUsize = AtomicUsize::new(0);
+static mut OPERATION_SLOTS: [Option<&'static OperationFn>; MAX_USER_OPERATIONS] =
+    [None; MAX_USER_OPERATIONS];
+
+macro_rules! user_operation_trampolines {
+    ($($slot:expr => $name:ident),*) => (
+        $(unsafe extern "C" fn $name(invec: *mut c_void, inoutvec: *mut c_void, len: *mut c_int,
+            datatype: *mut MPI_Datatype)
+        {
+            call_operation_slot($slot, invec, inoutvec, *len, *datatype)
+        })*
+
+        static OPERATION_TRAMPOLINES: [ffi::MPI_User_function; MAX_USER_OPERATIONS] =
+            [$($name),*];
+    )
+}
+
+user_operation_trampolines! {
+    0 => operation_trampoline_0, 1 => operation_trampoline_1, 2 => operation_trampoline_2,
+    3 => operation_trampoline_3, 4 => operation_trampoline_4, 5 => operation_trampoline_5,
+    6 => operation_trampoline_6, 7 => operation_trampoline_7, 8 => operation_trampoline_8,
+    9 => operation_trampoline_9, 10 => operation_trampoline_10, 11 => operation_trampoline_11,
+    12 => operation_trampoline_12, 13 => operation_trampoline_13, 14 => operation_trampoline_14,
+    15 => operation_trampoline_15, 16 => operation_trampoline_16, 17 => operation_trampoline_17,
+    18 => operation_trampoline_18, 19 => operation_trampoline_19, 20 => operation_trampoline_20,
+    21 => operation_trampoline_21, 22 => operation_trampoline_22, 23 => operation_trampoline_23,
+    24 => operation_trampoline_24, 25 => operation_trampoline_25, 26 => operation_trampoline_26,
+    27 => operation_trampoline_27, 28 => operation_trampoline_28, 29 => operation_trampoline_29,
+    30 => operation_trampoline_30, 31 => operation_trampoline_31
+}
+
+unsafe fn call_operation_slot(slot: usize, invec: *mut c_void, inoutvec: *mut c_void, len: c_int,
+    datatype: MPI_Datatype)
+{
+    let completed = match OPERATION_SLOTS[slot] {
+        // Unwinding across the `extern "C"` trampoline is undefined behavior.
+        Some(f) => panic::catch_unwind(AssertUnwindSafe(|| f(invec, inoutvec, len, datatype)))
+            .is_ok(),
+        // The `UserOperation` was dropped while a reduction using it was still pending.
+        None => false
+    };
+    if !completed {
+        ffi::MPI_Abort(ffi::RSMPI_COMM_WORLD, ffi::RSMPI_ERR_OP);
+    }
+}
+
+/// Reserve a free slot for a user operation and store the closure `f` in it or return `None` if
+/// all slots are in use.
+fn claim_operation_slot(f: Box<OperationFn>) -> Option<usize> {
+    loop {
+        let used = USED_OPERATION_SLOTS.load(Ordering::SeqCst);
+        let slot = (!used).trailing_zeros() as usize;
+        if slot >= MAX_USER_OPERATIONS {
+            return None;
+        }
+        if USED_OPERATION_SLOTS.compare_exchange(used, used | 1 << slot, Ordering::SeqCst,
+            Ordering::SeqCst).is_ok()
+        {
+            unsafe { OPERATION_SLOTS[slot] = Some(&*Box::into_raw(f)); }
+            return Some(slot);
+        }
+    }
+}
+
+/// Free the closure stored in `slot` and make the slot available again.
+fn release_operation_slot(slot: usize) {
+    unsafe {
+        if let Some(f) = OPERATION_SLOTS[slot].take() {
+            drop(Box::from_raw(f as *const OperationFn as *mut OperationFn));
+        }
+    }
+    USED_OPERATION_SLOTS.fetch_and(!(1 << slot), Ordering::SeqCst);
+}
+
+/// A user-defined operation that runs a Rust closure
+///
+/// The closure combines the elements of its first argument with the elements of its second
+/// argument, storing the result in the latter. MPI may call it from any thread, so it has to be
+/// `Sync`. Reducing a buffer whose datatype is not the equivalent datatype of `T`, or a panic in
+/// the closure, aborts the program via `MPI_Abort()`.
+///
+/// At most 32 user operations can exist at the same time, creating more returns an `Error` of
+/// class `MPI_ERR_OTHER`. Non-blocking and persistent reductions borrow the operation, so it
+/// outlives every reduction that uses it.
+///
+/// # Examples
+///
+/// See `examples/user_operation.rs`
+///
+/// # Standard section(s)
+///
+/// 5.9.5
+pub struct UserOperation {
+    raw: MPI_Op,
+    slot: usize
+}
+
+impl UserOperation {
+    /// Create a user-defined operation from the closure `f`.
+    ///
+    /// If `commute` is `true`, MPI is allowed to apply the operation in any order.
+    pub fn new<T, F>(commute: bool, f: F) -> Result<UserOperation, Error>
+        where T: EquivalentDatatype, F: Fn(&[T], &mut [T]) + Sync + 'static
+    {
+        let slot = claim_operation_slot(Box::new(move |invec, inoutvec, len, datatype| {
+            if datatype != unsafe { T::equivalent_datatype().as_raw() } {
+                panic!("User operation applied to a datatype that is not equivalent to its type.");
+            }
+            let len = len as usize;
+            let (invec, inoutvec) = unsafe {
+                (slice::from_raw_parts(invec as *const T, len),
+                    slice::from_raw_parts_mut(inoutvec as *mut T, len))
+            };
+            f(invec, inoutvec)
+        }));
+        let slot = try!(slot.ok_or(Error::from_raw(ffi::RSMPI_ERR_OTHER)));
+        let mut raw: MPI_Op = unsafe { mem::uninitialized() };
+        if let Err(e) = check(unsafe {
+            ffi::MPI_Op_create(Some(OPERATION_TRAMPOLINES[slot]), commute as c_int, &mut raw)
+        }) {
+            release_operation_slot(slot);
+            return Err(e);
+        }
+        Ok(UserOperation { raw: raw, slot: slot })
+    }
+
+    /// Create a commutative user-defined operation from the closure `f`.
+    pub fn commutative<T, F>(f: F) -> Result<UserOperation, Error>
+        where T: EquivalentDatatype, F: Fn(&[T], &mut [T]) + Sync + 'static
+    {
+        UserOperation::new(true, f)
+    }
+
+    /// Create a non-commutative user-defined operation from the closure `f`.
+    ///
+    /// The operation is applied in ascending order of the ranks.
+    pub fn non_commutative<T, F>(f: F) -> Result<UserOperation, Error>
+        where T: EquivalentDatatype, F: Fn(&[T], &mut [T]) + Sync + 'static
+    {
+        UserOperation::new(false, f)
+    }
+
+    /// Whether MPI may apply the operation in any order
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.5
+    pub fn is_commutative(&self) -> bool {
+        let mut commute: c_int = unsafe { mem::uninitialized() };
+        unsafe { ffi::MPI_Op_commutative(self.raw, &mut commute); }
+        commute != 0
+    }
+}
+
+impl AsRaw for UserOperation {
+    type Raw = MPI_Op;
+    unsafe fn as_raw(&self) -> Self::Raw { self.raw }
+}
+
+impl RawOperation for UserOperation { }
+
+impl Drop for UserOperation {
+    fn drop(&mut self) {
+        unsafe { ffi::MPI_Op_free(&mut self.raw); }
+        assert_eq!(self.raw, ffi::RSMPI_OP_NULL);
+        release_operation_slot(self.slot);
+    }
+}
+
 macro_rules! reduce_into_specializations {
     ($($name:ident => $operation:expr),*) => (
         $(fn $name<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: Option<&mut R>) {
@@ -308,7 +703,7 @@ pub trait ReduceInto {
     /// # Examples
     ///
     /// See `examples/reduce.rs`
-    fn reduce_into<S: Buffer + ?Sized, O: RawOperation>(&self, sendbuf: &S, op: O);
+    fn reduce_into<S: Buffer + ?Sized, O: RawOperation>(&self, sendbuf: &S, op: O) -> Result<(), Error>;
 
     /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
     /// stores the result in `recvbuf` on the `Root` process.
@@ -318,7 +713,7 @@ pub trait ReduceInto {
     /// # Examples
     ///
     /// See `examples/reduce.rs`
-    fn reduce_into_root<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O);
+    fn reduce_into_root<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) -> Result<(), Error>;
 
 //    reduce_into_specializations! {
 //        max_into => SystemOperation::max(),
@@ -335,20 +730,20 @@ pub trait ReduceInto {
 }
 
 impl<T: Root> ReduceInto for T {
-    fn reduce_into<S: Buffer + ?Sized, O: RawOperation>(&self, sendbuf: &S, op: O) {
-        assert!(self.communicator().rank() != self.root_rank());
-        unsafe {
+    fn reduce_into<S: Buffer + ?Sized, O: RawOperation>(&self, sendbuf: &S, op: O) -> Result<(), Error> {
+        assert!(!self.is_root());
+        check(unsafe {
             ffi::MPI_Reduce(sendbuf.pointer(), ptr::null_mut(), sendbuf.count(), sendbuf.datatype().as_raw(),
-                op.as_raw(), self.root_rank(), self.communicator().as_raw());
-        }
+                op.as_raw(), self.root_rank(), self.communicator().as_raw())
+        })
     }
 
-    fn reduce_into_root<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) {
-        assert!(self.communicator().rank() == self.root_rank());
-        unsafe {
+    fn reduce_into_root<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) -> Result<(), Error> {
+        assert!(self.is_root());
+        check(unsafe {
             ffi::MPI_Reduce(sendbuf.pointer(), recvbuf.pointer_mut(), sendbuf.count(), sendbuf.datatype().as_raw(),
-                op.as_raw(), self.root_rank(), self.communicator().as_raw());
-        }
+                op.as_raw(), self.root_rank(), self.communicator().as_raw())
+        })
     }
 }
 
@@ -364,18 +759,78 @@ pub trait AllReduceInto {
     /// # Examples
     ///
     /// See `examples/reduce.rs`
-    fn all_reduce_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O);
+    fn all_reduce_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) -> Result<(), Error>;
 }
 
 impl<C: Communicator> AllReduceInto for C {
-    fn all_reduce_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) {
-        unsafe {
+    fn all_reduce_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) -> Result<(), Error> {
+        check(unsafe {
             ffi::MPI_Allreduce(sendbuf.pointer(), recvbuf.pointer_mut(), sendbuf.count(),
-                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw());
-        }
+                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw())
+        })
+    }
+}
+
+/// Perform a global reduction, scattering equally sized blocks of the result to all processes.
+///
+/// # Standard section(s)
+///
+/// 5.10.1
+pub trait ReduceScatterBlockInto {
+    /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
+    /// scatters the result in blocks of `recvbuf.count()` elements, block `i` to rank `i`.
+    ///
+    /// `sendbuf` has to contain one block per process in the group.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/reduce_scatter.rs`
+    fn reduce_scatter_block_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) -> Result<(), Error>;
+}
+
+impl<C: Communicator> ReduceScatterBlockInto for C {
+    fn reduce_scatter_block_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) -> Result<(), Error> {
+        assert_eq!(recvbuf.count() * self.communicator().size(), sendbuf.count());
+        check(unsafe {
+            ffi::MPI_Reduce_scatter_block(sendbuf.pointer(), recvbuf.pointer_mut(), recvbuf.count(),
+                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw())
+        })
+    }
+}
+
+/// Perform a global reduction, scattering blocks of varying size of the result to all processes.
+///
+/// # Standard section(s)
+///
+/// 5.10.2
+pub trait ReduceScatterInto {
+    /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
+    /// scatters the result in blocks, block `i` containing `recvcounts[i]` elements to rank `i`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/reduce_scatter.rs`
+    fn reduce_scatter_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, recvcounts: &[Count], op: O) -> Result<(), Error>;
+}
+
+impl<C: Communicator> ReduceScatterInto for C {
+    fn reduce_scatter_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, recvcounts: &[Count], op: O) -> Result<(), Error> {
+        assert_reduce_scatter_counts(self.communicator(), sendbuf.count(), recvbuf.count(), recvcounts);
+        check(unsafe {
+            ffi::MPI_Reduce_scatter(sendbuf.pointer(), recvbuf.pointer_mut(), recvcounts.as_ptr(),
+                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw())
+        })
     }
 }
 
+/// Checks that `recvcounts` has one entry per process in the group of `comm`, that the blocks
+/// add up to the `sendcount` and that the local block fits into the receive buffer.
+fn assert_reduce_scatter_counts<C: RawCommunicator>(comm: &C, sendcount: Count, recvcount: Count, recvcounts: &[Count]) {
+    assert_eq!(comm.size(), recvcounts.count());
+    assert_eq!(sendcount, recvcounts.iter().fold(0, |acc, &c| acc + c));
+    assert!(recvcount >= recvcounts[comm.rank() as usize]);
+}
+
 /// Perform a local reduction.
 ///
 /// # Standard section(s)
@@ -385,11 +840,11 @@ impl<C: Communicator> AllReduceInto for C {
 /// # Examples
 ///
 /// See `examples/redure.rs`
-pub fn reduce_local_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(inbuf: &S, inoutbuf: &mut R, op: O) {
-    unsafe {
+pub fn reduce_local_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(inbuf: &S, inoutbuf: &mut R, op: O) -> Result<(), Error> {
+    check(unsafe {
         ffi::MPI_Reduce_local(inbuf.pointer(), inoutbuf.pointer_mut(), inbuf.count(),
-          inbuf.datatype().as_raw(), op.as_raw());
-    }
+          inbuf.datatype().as_raw(), op.as_raw())
+    })
 }
 
 /// Perform a global inclusive prefix reduction.
@@ -404,15 +859,15 @@ pub trait ScanInto {
     /// # Examples
     ///
     /// See `examples/scan.rs`
-    fn scan_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O);
+    fn scan_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) -> Result<(), Error>;
 }
 
 impl<C: Communicator> ScanInto for C {
-    fn scan_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) {
-        unsafe {
+    fn scan_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) -> Result<(), Error> {
+        check(unsafe {
             ffi::MPI_Scan(sendbuf.pointer(), recvbuf.pointer_mut(), sendbuf.count(),
-                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw());
-        }
+                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw())
+        })
     }
 }
 
@@ -428,15 +883,15 @@ pub trait ExclusiveScanInto {
     /// # Examples
     ///
     /// See `examples/scan.rs`
-    fn exclusive_scan_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O);
+    fn exclusive_scan_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) -> Result<(), Error>;
 }
 
 impl<C: Communicator> ExclusiveScanInto for C {
-    fn exclusive_scan_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) {
-        unsafe {
+    fn exclusive_scan_into<S: Buffer + ?Sized, R: BufferMut + ?Sized, O: RawOperation>(&self, sendbuf: &S, recvbuf: &mut R, op: O) -> Result<(), Error> {
+        check(unsafe {
             ffi::MPI_Exscan(sendbuf.pointer(), recvbuf.pointer_mut(), sendbuf.count(),
-                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw());
-        }
+                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw())
+        })
     }
 }
 
@@ -457,19 +912,19 @@ pub trait ImmediateBarrier {
     /// # Examples
     ///
     /// See `examples/immediate_barrier.rs`
-    fn immediate_barrier(&self) -> BarrierRequest;
+    fn immediate_barrier(&self) -> Result<BarrierRequest, Error>;
 }
 
 impl<C: Communicator> ImmediateBarrier for C {
-    fn immediate_barrier(&self) -> BarrierRequest {
+    fn immediate_barrier(&self) -> Result<BarrierRequest, Error> {
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe { ffi::MPI_Ibarrier(self.communicator().as_raw(), &mut request); }
-        BarrierRequest::from_raw(request)
+        try!(check(unsafe { ffi::MPI_Ibarrier(self.communicator().as_raw(), &mut request) }));
+        Ok(BarrierRequest::from_raw(request))
     }
 }
 
 /// A request object for an immediate (non-blocking) broadcast operation
-pub type BroadcastRequest<'b, Buf> = WriteRequest<'b, Buf>;
+pub type BroadcastRequest<'s, Buf> = WriteRequest<'s, Buf>;
 
 /// Non-blocking broadcast of a value from a `Root` process to all other processes.
 ///
@@ -482,17 +937,17 @@ pub trait ImmediateBroadcastInto {
     /// # Examples
     ///
     /// See `examples/immediate_broadcast.rs`
-    fn immediate_broadcast_into<'b, Buf: 'b + BufferMut + ?Sized>(&self, buf: &'b mut Buf) -> BroadcastRequest<'b, Buf>;
+    fn immediate_broadcast_into<'a, 's, Buf: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf) -> Result<BroadcastRequest<'s, Buf>, Error>;
 }
 
 impl<R: Root> ImmediateBroadcastInto for R {
-    fn immediate_broadcast_into<'b, Buf: 'b + BufferMut + ?Sized>(&self, buf: &'b mut Buf) -> BroadcastRequest<'b, Buf> {
+    fn immediate_broadcast_into<'a, 's, Buf: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf) -> Result<BroadcastRequest<'s, Buf>, Error> {
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Ibcast(buf.pointer_mut(), buf.count(), buf.datatype().as_raw(),
-                self.root_rank(), self.communicator().as_raw(), &mut request);
-        }
-        BroadcastRequest::from_raw(request, buf)
+                self.root_rank(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(BroadcastRequest::from_raw(request, scope, buf))
     }
 }
 
@@ -500,7 +955,7 @@ impl<R: Root> ImmediateBroadcastInto for R {
 pub type GatherRequest<'s, S> = ReadRequest<'s, S>;
 
 /// A request object for an immediate (non-blocking) gather operation on the root process
-pub type GatherRootRequest<'s, 'r, S, R> = ReadWriteRequest<'s, 'r, S, R>;
+pub type GatherRootRequest<'s, S, R> = ReadWriteRequest<'s, S, R>;
 
 /// Non-blocking gather of values at the `Root` process
 ///
@@ -515,7 +970,7 @@ pub trait ImmediateGatherInto {
     /// # Examples
     ///
     /// See `examples/immediate_gather.rs`
-    fn immediate_gather_into<'s, S: 's + Buffer + ?Sized>(&self, sendbuf: &'s S) -> GatherRequest<'s, S>;
+    fn immediate_gather_into<'a, 's, S: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S) -> Result<GatherRequest<'s, S>, Error>;
 
     /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
     ///
@@ -524,36 +979,88 @@ pub trait ImmediateGatherInto {
     /// # Examples
     ///
     /// See `examples/immediate_gather.rs`
-    fn immediate_gather_into_root<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized>(&self, sendbuf: &'s S, recvbuf: &'r mut R) -> GatherRootRequest<'s, 'r, S, R>;
+    fn immediate_gather_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<GatherRootRequest<'s, S, R>, Error>;
 }
 
 impl<T: Root> ImmediateGatherInto for T {
-    fn immediate_gather_into<'s, S: 's + Buffer + ?Sized>(&self, sendbuf: &'s S) -> GatherRequest<'s, S> {
-        assert!(self.communicator().rank() != self.root_rank());
+    fn immediate_gather_into<'a, 's, S: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S) -> Result<GatherRequest<'s, S>, Error> {
+        assert!(!self.is_root());
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Igather(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
                 ptr::null_mut(), 0, u8::equivalent_datatype().as_raw(),
-                self.root_rank(), self.communicator().as_raw(), &mut request);
-        }
-        GatherRequest::from_raw(request, sendbuf)
+                self.root_rank(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(GatherRequest::from_raw(request, scope, sendbuf))
     }
 
-    fn immediate_gather_into_root<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized>(&self, sendbuf: &'s S, recvbuf: &'r mut R) -> GatherRootRequest<'s, 'r, S, R> {
-        assert!(self.communicator().rank() == self.root_rank());
+    fn immediate_gather_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<GatherRootRequest<'s, S, R>, Error> {
+        assert!(self.is_root());
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe {
-            let recvcount = recvbuf.count() / self.communicator().size();
+        let recvcount = recvbuf.count() / self.communicator().target_size();
+        try!(check(unsafe {
             ffi::MPI_Igather(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
                 recvbuf.pointer_mut(), recvcount, recvbuf.datatype().as_raw(),
-                self.root_rank(), self.communicator().as_raw(), &mut request);
-        }
-        GatherRootRequest::from_raw(request, sendbuf, recvbuf)
+                self.root_rank(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(GatherRootRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Non-blocking gather of values of varying size at the `Root` process
+///
+/// # Standard section(s)
+///
+/// 5.12.3
+pub trait ImmediateGatherVarcountInto {
+    /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_gather_varcount.rs`
+    fn immediate_gather_varcount_into<'a, 's, S: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S) -> Result<GatherRequest<'s, S>, Error>;
+
+    /// Initiate non-blocking gather of the contents of all `sendbuf`s into the parts of
+    /// `recvbuf` on `Root` `&self`.
+    ///
+    /// This function must be called on the root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_gather_varcount.rs`
+    fn immediate_gather_varcount_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + PartitionedBufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<GatherRootRequest<'s, S, R>, Error>;
+}
+
+impl<T: Root> ImmediateGatherVarcountInto for T {
+    fn immediate_gather_varcount_into<'a, 's, S: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S) -> Result<GatherRequest<'s, S>, Error> {
+        assert!(!self.is_root());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Igatherv(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
+                ptr::null_mut(), ptr::null(), ptr::null(), u8::equivalent_datatype().as_raw(),
+                self.root_rank(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(GatherRequest::from_raw(request, scope, sendbuf))
+    }
+
+    fn immediate_gather_varcount_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + PartitionedBufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<GatherRootRequest<'s, S, R>, Error> {
+        assert!(self.is_root());
+        assert_eq!(self.communicator().target_size(), recvbuf.counts().count());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Igatherv(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
+                recvbuf.pointer_mut(), recvbuf.counts().as_ptr(), recvbuf.displs().as_ptr(),
+                recvbuf.datatype().as_raw(), self.root_rank(), self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(GatherRootRequest::from_raw(request, scope, sendbuf, recvbuf))
     }
 }
 
 /// A request object for an immediate (non-blocking) all-gather operation
-pub type AllGatherRequest<'s, 'r, S, R> = ReadWriteRequest<'s, 'r, S, R>;
+pub type AllGatherRequest<'s, S, R> = ReadWriteRequest<'s, S, R>;
 
 /// Non-blocking gather of contents of buffers on all participating processes.
 ///
@@ -567,27 +1074,55 @@ pub trait ImmediateAllGatherInto {
     /// # Examples
     ///
     /// See `examples/immediate_all_gather.rs`
-    fn immediate_all_gather_into<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized>(&self, sendbuf: &'s S, recvbuf: &'r mut R) -> AllGatherRequest<'s, 'r, S, R>;
+    fn immediate_all_gather_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<AllGatherRequest<'s, S, R>, Error>;
 }
 
 impl<C: Communicator> ImmediateAllGatherInto for C {
-    fn immediate_all_gather_into<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized>(&self, sendbuf: &'s S, recvbuf: &'r mut R) -> AllGatherRequest<'s, 'r, S, R> {
+    fn immediate_all_gather_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<AllGatherRequest<'s, S, R>, Error> {
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe {
-            let recvcount = recvbuf.count() / self.communicator().size();
+        let recvcount = recvbuf.count() / self.communicator().target_size();
+        try!(check(unsafe {
             ffi::MPI_Iallgather(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
                 recvbuf.pointer_mut(), recvcount, recvbuf.datatype().as_raw(),
-                self.communicator().as_raw(), &mut request);
-        }
-        AllGatherRequest::from_raw(request, sendbuf, recvbuf)
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(AllGatherRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Non-blocking gather of contents of buffers of varying size on all participating processes.
+///
+/// # Standard section(s)
+///
+/// 5.12.5
+pub trait ImmediateAllGatherVarcountInto {
+    /// Initiate non-blocking gather of the contents of all `sendbuf`s into the parts of all
+    /// `recvbuf`s on all processes in the communicator.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_all_gather_varcount.rs`
+    fn immediate_all_gather_varcount_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + PartitionedBufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<AllGatherRequest<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> ImmediateAllGatherVarcountInto for C {
+    fn immediate_all_gather_varcount_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + PartitionedBufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<AllGatherRequest<'s, S, R>, Error> {
+        assert_eq!(self.communicator().target_size(), recvbuf.counts().count());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Iallgatherv(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
+                recvbuf.pointer_mut(), recvbuf.counts().as_ptr(), recvbuf.displs().as_ptr(),
+                recvbuf.datatype().as_raw(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(AllGatherRequest::from_raw(request, scope, sendbuf, recvbuf))
     }
 }
 
 /// A request object for an immediate (non-blocking) scatter operation
-pub type ScatterRequest<'r, R> = WriteRequest<'r, R>;
+pub type ScatterRequest<'s, R> = WriteRequest<'s, R>;
 
 /// A request object for an immediate (non-blocking) scatter operation on the root process
-pub type ScatterRootRequest<'s, 'r, S, R> = ReadWriteRequest<'s, 'r, S, R>;
+pub type ScatterRootRequest<'s, S, R> = ReadWriteRequest<'s, S, R>;
 
 /// Non-blocking scatter of values from the `Root` process
 ///
@@ -602,7 +1137,7 @@ pub trait ImmediateScatterInto {
     /// # Examples
     ///
     /// See `examples/immediate_scatter.rs`
-    fn immediate_scatter_into<'r, R: 'r + BufferMut + ?Sized>(&self, recvbuf: &'r mut R) -> ScatterRequest<'r, R>;
+    fn immediate_scatter_into<'a, 's, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, recvbuf: &'a mut R) -> Result<ScatterRequest<'s, R>, Error>;
 
     /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
     ///
@@ -611,36 +1146,88 @@ pub trait ImmediateScatterInto {
     /// # Examples
     ///
     /// See `examples/immediate_scatter.rs`
-    fn immediate_scatter_into_root<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized>(&self, sendbuf: &'s S, recvbuf: &'r mut R) -> ScatterRootRequest<'s, 'r, S, R>;
+    fn immediate_scatter_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<ScatterRootRequest<'s, S, R>, Error>;
 }
 
 impl<T: Root> ImmediateScatterInto for T {
-    fn immediate_scatter_into<'r, R: 'r + BufferMut + ?Sized>(&self, recvbuf: &'r mut R) -> ScatterRequest<'r, R> {
-        assert!(self.communicator().rank() != self.root_rank());
+    fn immediate_scatter_into<'a, 's, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, recvbuf: &'a mut R) -> Result<ScatterRequest<'s, R>, Error> {
+        assert!(!self.is_root());
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Iscatter(ptr::null(), 0, u8::equivalent_datatype().as_raw(),
                 recvbuf.pointer_mut(), recvbuf.count(), recvbuf.datatype().as_raw(),
-                self.root_rank(), self.communicator().as_raw(), &mut request);
-        }
-        ScatterRequest::from_raw(request, recvbuf)
+                self.root_rank(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(ScatterRequest::from_raw(request, scope, recvbuf))
     }
 
-    fn immediate_scatter_into_root<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized>(&self, sendbuf: &'s S, recvbuf: &'r mut R) -> ScatterRootRequest<'s, 'r, S, R> {
-        assert!(self.communicator().rank() == self.root_rank());
+    fn immediate_scatter_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<ScatterRootRequest<'s, S, R>, Error> {
+        assert!(self.is_root());
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe {
-            let sendcount = sendbuf.count() / self.communicator().size();
+        let sendcount = sendbuf.count() / self.communicator().target_size();
+        try!(check(unsafe {
             ffi::MPI_Iscatter(sendbuf.pointer(), sendcount, sendbuf.datatype().as_raw(),
                 recvbuf.pointer_mut(), recvbuf.count(), recvbuf.datatype().as_raw(),
-                self.root_rank(), self.communicator().as_raw(), &mut request);
-        }
-        ScatterRootRequest::from_raw(request, sendbuf, recvbuf)
+                self.root_rank(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(ScatterRootRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Non-blocking scatter of parts of varying size of a buffer on the `Root` process
+///
+/// # Standard section(s)
+///
+/// 5.12.4
+pub trait ImmediateScatterVarcountInto {
+    /// Initiate non-blocking scatter of the parts of the partitioned buffer on `Root` `&self`.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_scatter_varcount.rs`
+    fn immediate_scatter_varcount_into<'a, 's, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, recvbuf: &'a mut R) -> Result<ScatterRequest<'s, R>, Error>;
+
+    /// Initiate non-blocking scatter of the parts of `sendbuf` from `Root` `&self`.
+    ///
+    /// This function must be called on the root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_scatter_varcount.rs`
+    fn immediate_scatter_varcount_into_root<'a, 's, S: 'a + PartitionedBuffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<ScatterRootRequest<'s, S, R>, Error>;
+}
+
+impl<T: Root> ImmediateScatterVarcountInto for T {
+    fn immediate_scatter_varcount_into<'a, 's, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, recvbuf: &'a mut R) -> Result<ScatterRequest<'s, R>, Error> {
+        assert!(!self.is_root());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Iscatterv(ptr::null(), ptr::null(), ptr::null(),
+                u8::equivalent_datatype().as_raw(), recvbuf.pointer_mut(), recvbuf.count(),
+                recvbuf.datatype().as_raw(), self.root_rank(), self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(ScatterRequest::from_raw(request, scope, recvbuf))
+    }
+
+    fn immediate_scatter_varcount_into_root<'a, 's, S: 'a + PartitionedBuffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<ScatterRootRequest<'s, S, R>, Error> {
+        assert!(self.is_root());
+        assert_eq!(self.communicator().target_size(), sendbuf.counts().count());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Iscatterv(sendbuf.pointer(), sendbuf.counts().as_ptr(),
+                sendbuf.displs().as_ptr(), sendbuf.datatype().as_raw(), recvbuf.pointer_mut(),
+                recvbuf.count(), recvbuf.datatype().as_raw(), self.root_rank(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(ScatterRootRequest::from_raw(request, scope, sendbuf, recvbuf))
     }
 }
 
 /// A request object for an immediate (non-blocking) all-to-all operation
-pub type AllToAllRequest<'s, 'r, S, R> = ReadWriteRequest<'s, 'r, S, R>;
+pub type AllToAllRequest<'s, S, R> = ReadWriteRequest<'s, S, R>;
 
 /// Non-blocking all-to-all communication.
 ///
@@ -653,18 +1240,1033 @@ pub trait ImmediateAllToAllInto {
     /// # Examples
     ///
     /// See `examples/immediate_all_to_all.rs`
-    fn immediate_all_to_all_into<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized>(&self, sendbuf: &'s S, recvbuf: &'r mut R) -> AllToAllRequest<'s, 'r, S, R>;
+    fn immediate_all_to_all_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<AllToAllRequest<'s, S, R>, Error>;
+
+    /// Initiate non-blocking all-to-all communication of parts that each have their own count,
+    /// displacement in bytes and datatype.
+    ///
+    /// If a displacement does not fit into a `Count`, an `Error` of class `MPI_ERR_ARG` is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_all_to_all_w.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.6
+    fn immediate_all_to_all_w_into<'a, 's, 'sd, 'sb, 'rd, 'rb, SD, S: ?Sized, RD, R: ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a DatatypePartition<'sd, 'sb, SD, S>, recvbuf: &'a mut DatatypePartitionMut<'rd, 'rb, RD, R>) -> Result<AllToAllRequest<'s, DatatypePartition<'sd, 'sb, SD, S>, DatatypePartitionMut<'rd, 'rb, RD, R>>, Error>
+        where SD: 'sd + RawDatatype, S: 'sb + Pointer, RD: 'rd + RawDatatype, R: 'rb + PointerMut;
 }
 
 impl<C: Communicator> ImmediateAllToAllInto for C {
-    fn immediate_all_to_all_into<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized>(&self, sendbuf: &'s S, recvbuf: &'r mut R) -> AllToAllRequest<'s, 'r, S, R> {
+    fn immediate_all_to_all_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<AllToAllRequest<'s, S, R>, Error> {
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        let c_size = self.communicator().size();
-        unsafe {
+        let c_size = self.communicator().target_size();
+        try!(check(unsafe {
             ffi::MPI_Ialltoall(sendbuf.pointer(), sendbuf.count() / c_size, sendbuf.datatype().as_raw(),
                 recvbuf.pointer_mut(), recvbuf.count() / c_size, recvbuf.datatype().as_raw(),
-                self.communicator().as_raw(), &mut request);
-        }
-        AllToAllRequest::from_raw(request, sendbuf, recvbuf)
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(AllToAllRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+
+    fn immediate_all_to_all_w_into<'a, 's, 'sd, 'sb, 'rd, 'rb, SD, S: ?Sized, RD, R: ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a DatatypePartition<'sd, 'sb, SD, S>, recvbuf: &'a mut DatatypePartitionMut<'rd, 'rb, RD, R>) -> Result<AllToAllRequest<'s, DatatypePartition<'sd, 'sb, SD, S>, DatatypePartitionMut<'rd, 'rb, RD, R>>, Error>
+        where SD: 'sd + RawDatatype, S: 'sb + Pointer, RD: 'rd + RawDatatype, R: 'rb + PointerMut
+    {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        let c_size = self.communicator().target_size();
+        assert_eq!(c_size, sendbuf.counts().count());
+        assert_eq!(c_size, recvbuf.counts().count());
+        // The displacement arrays are owned by the partitions, which the request borrows.
+        let sdispls = try!(sendbuf.narrow_displs()
+            .ok_or(Error::from_raw(ffi::RSMPI_ERR_ARG))).as_ptr();
+        let rdispls = try!(recvbuf.narrow_displs()
+            .ok_or(Error::from_raw(ffi::RSMPI_ERR_ARG))).as_ptr();
+        try!(check(unsafe {
+            ffi::MPI_Ialltoallw(sendbuf.pointer(), sendbuf.counts().as_ptr(), sdispls,
+                sendbuf.datatypes().as_ptr(), recvbuf.pointer_mut(), recvbuf.counts().as_ptr(),
+                rdispls, recvbuf.datatypes().as_ptr(), self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(AllToAllRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Non-blocking all-to-all communication of parts of varying size.
+///
+/// # Standard section(s)
+///
+/// 5.12.6
+pub trait ImmediateAllToAllVarcountInto {
+    /// Initiate non-blocking all-to-all communication of the parts of `sendbuf` and `recvbuf`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_all_to_all_varcount.rs`
+    fn immediate_all_to_all_varcount_into<'a, 's, S: 'a + PartitionedBuffer + ?Sized, R: 'a + PartitionedBufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<AllToAllRequest<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> ImmediateAllToAllVarcountInto for C {
+    fn immediate_all_to_all_varcount_into<'a, 's, S: 'a + PartitionedBuffer + ?Sized, R: 'a + PartitionedBufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<AllToAllRequest<'s, S, R>, Error> {
+        let c_size = self.communicator().target_size();
+        assert_eq!(c_size, sendbuf.counts().count());
+        assert_eq!(c_size, recvbuf.counts().count());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Ialltoallv(sendbuf.pointer(), sendbuf.counts().as_ptr(),
+                sendbuf.displs().as_ptr(), sendbuf.datatype().as_raw(), recvbuf.pointer_mut(),
+                recvbuf.counts().as_ptr(), recvbuf.displs().as_ptr(),
+                recvbuf.datatype().as_raw(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(AllToAllRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// A request object for an immediate (non-blocking) reduce operation
+pub type ReduceRequest<'s, S> = ReadRequest<'s, S>;
+
+/// A request object for an immediate (non-blocking) reduce operation on the root process
+pub type ReduceRootRequest<'s, S, R> = ReadWriteRequest<'s, S, R>;
+
+/// Non-blocking global reduction, storing the result on the `Root` process
+///
+/// # Standard section(s)
+///
+/// 5.12.7
+pub trait ImmediateReduceInto {
+    /// Initiate a non-blocking global reduction under the operation `op` of the input data in
+    /// `sendbuf` whose result is stored on the `Root` process.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_reduce.rs`
+    fn immediate_reduce_into<'a, 's, S: 'a + Buffer + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, op: &'a O) -> Result<ReduceRequest<'s, S>, Error>;
+
+    /// Initiate a non-blocking global reduction under the operation `op` of the input data in
+    /// `sendbuf` whose result is stored in `recvbuf` on the `Root` process.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_reduce.rs`
+    fn immediate_reduce_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R, op: &'a O) -> Result<ReduceRootRequest<'s, S, R>, Error>;
+}
+
+impl<T: Root> ImmediateReduceInto for T {
+    fn immediate_reduce_into<'a, 's, S: 'a + Buffer + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, op: &'a O) -> Result<ReduceRequest<'s, S>, Error> {
+        assert!(!self.is_root());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Ireduce(sendbuf.pointer(), ptr::null_mut(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), op.as_raw(), self.root_rank(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(ReduceRequest::from_raw(request, scope, sendbuf))
+    }
+
+    fn immediate_reduce_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R, op: &'a O) -> Result<ReduceRootRequest<'s, S, R>, Error> {
+        assert!(self.is_root());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Ireduce(sendbuf.pointer(), recvbuf.pointer_mut(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), op.as_raw(), self.root_rank(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(ReduceRootRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// A request object for an immediate (non-blocking) all-reduce operation
+pub type AllReduceRequest<'s, S, R> = ReadWriteRequest<'s, S, R>;
+
+/// Non-blocking global reduction, storing the result on all processes
+///
+/// # Standard section(s)
+///
+/// 5.12.8
+pub trait ImmediateAllReduceInto {
+    /// Initiate a non-blocking global reduction under the operation `op` of the input data in
+    /// `sendbuf` whose result is stored in `recvbuf` on all processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_reduce.rs`
+    fn immediate_all_reduce_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R, op: &'a O) -> Result<AllReduceRequest<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> ImmediateAllReduceInto for C {
+    fn immediate_all_reduce_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R, op: &'a O) -> Result<AllReduceRequest<'s, S, R>, Error> {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Iallreduce(sendbuf.pointer(), recvbuf.pointer_mut(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(AllReduceRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// A request object for an immediate (non-blocking) reduce-scatter operation
+pub type ReduceScatterRequest<'s, S, R> = ReadWriteRequest<'s, S, R>;
+
+/// Non-blocking global reduction, scattering equally sized blocks of the result to all processes.
+///
+/// # Standard section(s)
+///
+/// 5.12.9
+pub trait ImmediateReduceScatterBlockInto {
+    /// Initiate a non-blocking global reduction under the operation `op` of the input data in
+    /// `sendbuf`, scattering the result in blocks of `recvbuf.count()` elements.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_reduce_scatter.rs`
+    fn immediate_reduce_scatter_block_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R, op: &'a O) -> Result<ReduceScatterRequest<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> ImmediateReduceScatterBlockInto for C {
+    fn immediate_reduce_scatter_block_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R, op: &'a O) -> Result<ReduceScatterRequest<'s, S, R>, Error> {
+        assert_eq!(recvbuf.count() * self.communicator().size(), sendbuf.count());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Ireduce_scatter_block(sendbuf.pointer(), recvbuf.pointer_mut(),
+                recvbuf.count(), sendbuf.datatype().as_raw(), op.as_raw(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(ReduceScatterRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Non-blocking global reduction, scattering blocks of varying size of the result to all
+/// processes.
+///
+/// # Standard section(s)
+///
+/// 5.12.10
+pub trait ImmediateReduceScatterInto {
+    /// Initiate a non-blocking global reduction under the operation `op` of the input data in
+    /// `sendbuf`, scattering the result in blocks, block `i` containing `recvcounts[i]` elements.
+    ///
+    /// `recvcounts` is borrowed for as long as `recvbuf` since MPI reads it until the operation
+    /// completes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_reduce_scatter.rs`
+    fn immediate_reduce_scatter_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R, recvcounts: &'a [Count], op: &'a O) -> Result<ReduceScatterRequest<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> ImmediateReduceScatterInto for C {
+    fn immediate_reduce_scatter_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R, recvcounts: &'a [Count], op: &'a O) -> Result<ReduceScatterRequest<'s, S, R>, Error> {
+        assert_reduce_scatter_counts(self.communicator(), sendbuf.count(), recvbuf.count(), recvcounts);
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Ireduce_scatter(sendbuf.pointer(), recvbuf.pointer_mut(),
+                recvcounts.as_ptr(), sendbuf.datatype().as_raw(), op.as_raw(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(ReduceScatterRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// A request object for an immediate (non-blocking) scan operation
+pub type ScanRequest<'s, S, R> = ReadWriteRequest<'s, S, R>;
+
+/// Non-blocking global inclusive prefix reduction
+///
+/// # Standard section(s)
+///
+/// 5.12.11
+pub trait ImmediateScanInto {
+    /// Initiate a non-blocking global inclusive prefix reduction of the data in `sendbuf` into
+    /// `recvbuf` under operation `op`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_scan.rs`
+    fn immediate_scan_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R, op: &'a O) -> Result<ScanRequest<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> ImmediateScanInto for C {
+    fn immediate_scan_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R, op: &'a O) -> Result<ScanRequest<'s, S, R>, Error> {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Iscan(sendbuf.pointer(), recvbuf.pointer_mut(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(ScanRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// A request object for an immediate (non-blocking) exclusive scan operation
+pub type ExclusiveScanRequest<'s, S, R> = ReadWriteRequest<'s, S, R>;
+
+/// Non-blocking global exclusive prefix reduction
+///
+/// # Standard section(s)
+///
+/// 5.12.12
+pub trait ImmediateExclusiveScanInto {
+    /// Initiate a non-blocking global exclusive prefix reduction of the data in `sendbuf` into
+    /// `recvbuf` under operation `op`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_scan.rs`
+    fn immediate_exclusive_scan_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R, op: &'a O) -> Result<ExclusiveScanRequest<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> ImmediateExclusiveScanInto for C {
+    fn immediate_exclusive_scan_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R, op: &'a O) -> Result<ExclusiveScanRequest<'s, S, R>, Error> {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Iexscan(sendbuf.pointer(), recvbuf.pointer_mut(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(ExclusiveScanRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// The number of processes a neighborhood collective on `comm` receives from and sends to
+///
+/// Returns `(0, 0)` if `comm` does not have a process topology.
+fn neighbor_degrees<C: RawCommunicator>(comm: &C) -> Result<(Count, Count), Error> {
+    let mut topology: c_int = unsafe { mem::uninitialized() };
+    try!(check(unsafe { ffi::MPI_Topo_test(comm.as_raw(), &mut topology) }));
+    if topology == ffi::RSMPI_CART {
+        let mut ndims: Count = unsafe { mem::uninitialized() };
+        try!(check(unsafe { ffi::MPI_Cartdim_get(comm.as_raw(), &mut ndims) }));
+        Ok((2 * ndims, 2 * ndims))
+    } else if topology == ffi::RSMPI_GRAPH {
+        let mut n: Count = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Graph_neighbors_count(comm.as_raw(), comm.rank(), &mut n)
+        }));
+        Ok((n, n))
+    } else if topology == ffi::RSMPI_DIST_GRAPH {
+        let mut indegree: Count = unsafe { mem::uninitialized() };
+        let mut outdegree: Count = unsafe { mem::uninitialized() };
+        let mut weighted: c_int = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Dist_graph_neighbors_count(comm.as_raw(), &mut indegree, &mut outdegree,
+                &mut weighted)
+        }));
+        Ok((indegree, outdegree))
+    } else {
+        Ok((0, 0))
+    }
+}
+
+/// Splits `count` elements evenly among `degree` neighbors.
+fn per_neighbor(count: Count, degree: Count) -> Count {
+    if degree > 0 { count / degree } else { 0 }
+}
+
+/// Gather contents of buffers from all neighbors in a process topology.
+///
+/// After the call completes, the contents of the send `Buffer`s of all source neighbors will be
+/// concatenated into the receive `Buffer`, in the order of the neighbors as given by the topology.
+///
+/// # Standard section(s)
+///
+/// 7.6.1
+pub trait NeighborAllGatherInto {
+    /// Gather the contents of the `sendbuf`s of all source neighbors into `recvbuf`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/neighbor.rs`
+    fn neighbor_all_gather_into<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error>;
+}
+
+impl<C: TopologyCommunicator> NeighborAllGatherInto for C {
+    fn neighbor_all_gather_into<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error> {
+        let (indegree, _) = try!(neighbor_degrees(self.communicator()));
+        let recvcount = per_neighbor(recvbuf.count(), indegree);
+        check(unsafe {
+            ffi::MPI_Neighbor_allgather(sendbuf.pointer(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), recvbuf.pointer_mut(), recvcount,
+                recvbuf.datatype().as_raw(), self.communicator().as_raw())
+        })
+    }
+}
+
+/// Gather contents of buffers of varying size from all neighbors in a process topology.
+///
+/// # Standard section(s)
+///
+/// 7.6.1
+pub trait NeighborAllGatherVarcountInto {
+    /// Gather the contents of the `sendbuf`s of all source neighbors into the parts of
+    /// `recvbuf`, one part per source neighbor.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/neighbor.rs`
+    fn neighbor_all_gather_varcount_into<S: Buffer + ?Sized, R: PartitionedBufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error>;
+}
+
+impl<C: TopologyCommunicator> NeighborAllGatherVarcountInto for C {
+    fn neighbor_all_gather_varcount_into<S: Buffer + ?Sized, R: PartitionedBufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error> {
+        let (indegree, _) = try!(neighbor_degrees(self.communicator()));
+        assert_eq!(indegree, recvbuf.counts().count());
+        check(unsafe {
+            ffi::MPI_Neighbor_allgatherv(sendbuf.pointer(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), recvbuf.pointer_mut(), recvbuf.counts().as_ptr(),
+                recvbuf.displs().as_ptr(), recvbuf.datatype().as_raw(),
+                self.communicator().as_raw())
+        })
+    }
+}
+
+/// Exchange the contents of buffers with all neighbors in a process topology.
+///
+/// # Standard section(s)
+///
+/// 7.6.1
+pub trait NeighborAllToAllInto {
+    /// Send one equally sized part of `sendbuf` to each destination neighbor and receive one
+    /// equally sized part of `recvbuf` from each source neighbor.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/neighbor.rs`
+    fn neighbor_all_to_all_into<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error>;
+
+    /// Send one part of `sendbuf` to each destination neighbor and receive one part of `recvbuf`
+    /// from each source neighbor, where every part has its own count, displacement and datatype.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/neighbor.rs`
+    fn neighbor_all_to_all_w_into<SD, S: ?Sized, RD, R: ?Sized>(&self, sendbuf: &DatatypePartition<SD, S>, recvbuf: &mut DatatypePartitionMut<RD, R>) -> Result<(), Error>
+        where SD: RawDatatype, S: Pointer, RD: RawDatatype, R: PointerMut;
+}
+
+impl<C: TopologyCommunicator> NeighborAllToAllInto for C {
+    fn neighbor_all_to_all_into<S: Buffer + ?Sized, R: BufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error> {
+        let (indegree, outdegree) = try!(neighbor_degrees(self.communicator()));
+        let sendcount = per_neighbor(sendbuf.count(), outdegree);
+        let recvcount = per_neighbor(recvbuf.count(), indegree);
+        check(unsafe {
+            ffi::MPI_Neighbor_alltoall(sendbuf.pointer(), sendcount, sendbuf.datatype().as_raw(),
+                recvbuf.pointer_mut(), recvcount, recvbuf.datatype().as_raw(),
+                self.communicator().as_raw())
+        })
+    }
+
+    fn neighbor_all_to_all_w_into<SD, S: ?Sized, RD, R: ?Sized>(&self, sendbuf: &DatatypePartition<SD, S>, recvbuf: &mut DatatypePartitionMut<RD, R>) -> Result<(), Error>
+        where SD: RawDatatype, S: Pointer, RD: RawDatatype, R: PointerMut
+    {
+        let (indegree, outdegree) = try!(neighbor_degrees(self.communicator()));
+        assert_eq!(outdegree, sendbuf.counts().count());
+        assert_eq!(indegree, recvbuf.counts().count());
+        check(unsafe {
+            ffi::MPI_Neighbor_alltoallw(sendbuf.pointer(), sendbuf.counts().as_ptr(),
+                sendbuf.displs().as_ptr(), sendbuf.datatypes().as_ptr(), recvbuf.pointer_mut(),
+                recvbuf.counts().as_ptr(), recvbuf.displs().as_ptr(),
+                recvbuf.datatypes().as_ptr(), self.communicator().as_raw())
+        })
+    }
+}
+
+/// Exchange the contents of buffers of varying size with all neighbors in a process topology.
+///
+/// # Standard section(s)
+///
+/// 7.6.1
+pub trait NeighborAllToAllVarcountInto {
+    /// Send the parts of `sendbuf` to the destination neighbors and receive the parts of
+    /// `recvbuf` from the source neighbors.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/neighbor.rs`
+    fn neighbor_all_to_all_varcount_into<S: PartitionedBuffer + ?Sized, R: PartitionedBufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error>;
+}
+
+impl<C: TopologyCommunicator> NeighborAllToAllVarcountInto for C {
+    fn neighbor_all_to_all_varcount_into<S: PartitionedBuffer + ?Sized, R: PartitionedBufferMut + ?Sized>(&self, sendbuf: &S, recvbuf: &mut R) -> Result<(), Error> {
+        let (indegree, outdegree) = try!(neighbor_degrees(self.communicator()));
+        assert_eq!(outdegree, sendbuf.counts().count());
+        assert_eq!(indegree, recvbuf.counts().count());
+        check(unsafe {
+            ffi::MPI_Neighbor_alltoallv(sendbuf.pointer(), sendbuf.counts().as_ptr(),
+                sendbuf.displs().as_ptr(), sendbuf.datatype().as_raw(), recvbuf.pointer_mut(),
+                recvbuf.counts().as_ptr(), recvbuf.displs().as_ptr(),
+                recvbuf.datatype().as_raw(), self.communicator().as_raw())
+        })
+    }
+}
+
+/// A request object for an immediate (non-blocking) neighborhood collective operation
+pub type NeighborRequest<'s, S, R> = ReadWriteRequest<'s, S, R>;
+
+/// Non-blocking gather of contents of buffers from all neighbors in a process topology
+///
+/// # Standard section(s)
+///
+/// 7.7.1
+pub trait ImmediateNeighborAllGatherInto {
+    /// Initiate non-blocking gather of the contents of the `sendbuf`s of all source neighbors
+    /// into `recvbuf`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_neighbor.rs`
+    fn immediate_neighbor_all_gather_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<NeighborRequest<'s, S, R>, Error>;
+}
+
+impl<C: TopologyCommunicator> ImmediateNeighborAllGatherInto for C {
+    fn immediate_neighbor_all_gather_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<NeighborRequest<'s, S, R>, Error> {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        let (indegree, _) = try!(neighbor_degrees(self.communicator()));
+        let recvcount = per_neighbor(recvbuf.count(), indegree);
+        try!(check(unsafe {
+            ffi::MPI_Ineighbor_allgather(sendbuf.pointer(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), recvbuf.pointer_mut(), recvcount,
+                recvbuf.datatype().as_raw(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(NeighborRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Non-blocking gather of contents of buffers of varying size from all neighbors in a process
+/// topology
+///
+/// # Standard section(s)
+///
+/// 7.7.1
+pub trait ImmediateNeighborAllGatherVarcountInto {
+    /// Initiate non-blocking gather of the contents of the `sendbuf`s of all source neighbors
+    /// into the parts of `recvbuf`, one part per source neighbor.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_neighbor.rs`
+    fn immediate_neighbor_all_gather_varcount_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + PartitionedBufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<NeighborRequest<'s, S, R>, Error>;
+}
+
+impl<C: TopologyCommunicator> ImmediateNeighborAllGatherVarcountInto for C {
+    fn immediate_neighbor_all_gather_varcount_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + PartitionedBufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<NeighborRequest<'s, S, R>, Error> {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        let (indegree, _) = try!(neighbor_degrees(self.communicator()));
+        assert_eq!(indegree, recvbuf.counts().count());
+        try!(check(unsafe {
+            ffi::MPI_Ineighbor_allgatherv(sendbuf.pointer(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), recvbuf.pointer_mut(), recvbuf.counts().as_ptr(),
+                recvbuf.displs().as_ptr(), recvbuf.datatype().as_raw(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(NeighborRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Non-blocking exchange of the contents of buffers with all neighbors in a process topology
+///
+/// # Standard section(s)
+///
+/// 7.7.1
+pub trait ImmediateNeighborAllToAllInto {
+    /// Initiate non-blocking exchange of equally sized parts of `sendbuf` and `recvbuf` with
+    /// the destination and source neighbors.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_neighbor.rs`
+    fn immediate_neighbor_all_to_all_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<NeighborRequest<'s, S, R>, Error>;
+
+    /// Initiate non-blocking exchange of parts of `sendbuf` and `recvbuf` with the destination
+    /// and source neighbors, where every part has its own count, displacement and datatype.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_neighbor.rs`
+    fn immediate_neighbor_all_to_all_w_into<'a, 's, 'sd, 'sb, 'rd, 'rb, SD, S: ?Sized, RD, R: ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a DatatypePartition<'sd, 'sb, SD, S>, recvbuf: &'a mut DatatypePartitionMut<'rd, 'rb, RD, R>) -> Result<NeighborRequest<'s, DatatypePartition<'sd, 'sb, SD, S>, DatatypePartitionMut<'rd, 'rb, RD, R>>, Error>
+        where SD: 'sd + RawDatatype, S: 'sb + Pointer, RD: 'rd + RawDatatype, R: 'rb + PointerMut;
+}
+
+impl<C: TopologyCommunicator> ImmediateNeighborAllToAllInto for C {
+    fn immediate_neighbor_all_to_all_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<NeighborRequest<'s, S, R>, Error> {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        let (indegree, outdegree) = try!(neighbor_degrees(self.communicator()));
+        let sendcount = per_neighbor(sendbuf.count(), outdegree);
+        let recvcount = per_neighbor(recvbuf.count(), indegree);
+        try!(check(unsafe {
+            ffi::MPI_Ineighbor_alltoall(sendbuf.pointer(), sendcount, sendbuf.datatype().as_raw(),
+                recvbuf.pointer_mut(), recvcount, recvbuf.datatype().as_raw(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(NeighborRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+
+    fn immediate_neighbor_all_to_all_w_into<'a, 's, 'sd, 'sb, 'rd, 'rb, SD, S: ?Sized, RD, R: ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a DatatypePartition<'sd, 'sb, SD, S>, recvbuf: &'a mut DatatypePartitionMut<'rd, 'rb, RD, R>) -> Result<NeighborRequest<'s, DatatypePartition<'sd, 'sb, SD, S>, DatatypePartitionMut<'rd, 'rb, RD, R>>, Error>
+        where SD: 'sd + RawDatatype, S: 'sb + Pointer, RD: 'rd + RawDatatype, R: 'rb + PointerMut
+    {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        let (indegree, outdegree) = try!(neighbor_degrees(self.communicator()));
+        assert_eq!(outdegree, sendbuf.counts().count());
+        assert_eq!(indegree, recvbuf.counts().count());
+        try!(check(unsafe {
+            ffi::MPI_Ineighbor_alltoallw(sendbuf.pointer(), sendbuf.counts().as_ptr(),
+                sendbuf.displs().as_ptr(), sendbuf.datatypes().as_ptr(), recvbuf.pointer_mut(),
+                recvbuf.counts().as_ptr(), recvbuf.displs().as_ptr(),
+                recvbuf.datatypes().as_ptr(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(NeighborRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Non-blocking exchange of the contents of buffers of varying size with all neighbors in a
+/// process topology
+///
+/// # Standard section(s)
+///
+/// 7.7.1
+pub trait ImmediateNeighborAllToAllVarcountInto {
+    /// Initiate non-blocking exchange of the parts of `sendbuf` and `recvbuf` with the
+    /// destination and source neighbors.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_neighbor.rs`
+    fn immediate_neighbor_all_to_all_varcount_into<'a, 's, S: 'a + PartitionedBuffer + ?Sized, R: 'a + PartitionedBufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<NeighborRequest<'s, S, R>, Error>;
+}
+
+impl<C: TopologyCommunicator> ImmediateNeighborAllToAllVarcountInto for C {
+    fn immediate_neighbor_all_to_all_varcount_into<'a, 's, S: 'a + PartitionedBuffer + ?Sized, R: 'a + PartitionedBufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a S, recvbuf: &'a mut R) -> Result<NeighborRequest<'s, S, R>, Error> {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        let (indegree, outdegree) = try!(neighbor_degrees(self.communicator()));
+        assert_eq!(outdegree, sendbuf.counts().count());
+        assert_eq!(indegree, recvbuf.counts().count());
+        try!(check(unsafe {
+            ffi::MPI_Ineighbor_alltoallv(sendbuf.pointer(), sendbuf.counts().as_ptr(),
+                sendbuf.displs().as_ptr(), sendbuf.datatype().as_raw(), recvbuf.pointer_mut(),
+                recvbuf.counts().as_ptr(), recvbuf.displs().as_ptr(),
+                recvbuf.datatype().as_raw(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(NeighborRequest::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Persistent barrier synchronization among all processes in a `Communicator`
+///
+/// # Standard section(s)
+///
+/// 6.13.1 (MPI 4.0)
+pub trait PersistentBarrier {
+    /// Create a request for a barrier synchronization across all processes in the `Communicator`
+    /// `&self`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_barrier<'a, 's>(&self, scope: &'s Scope<'a>) -> Result<PersistentCollective<'s, [()], [()]>, Error>;
+}
+
+impl<C: Communicator> PersistentBarrier for C {
+    fn persistent_barrier<'a, 's>(&self, scope: &'s Scope<'a>) -> Result<PersistentCollective<'s, [()], [()]>, Error> {
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Barrier_init(self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, &mut [], &mut []))
+    }
+}
+
+/// Persistent broadcast of a value from a `Root` process to all other processes.
+///
+/// # Standard section(s)
+///
+/// 6.13.2 (MPI 4.0)
+pub trait PersistentBroadcastInto {
+    /// Create a request for broadcasting the contents of `buf` from the `Root` process to all
+    /// other processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_broadcast_into<'a, 's, Buf: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf) -> Result<PersistentCollective<'s, [()], Buf>, Error>;
+}
+
+impl<R: Root> PersistentBroadcastInto for R {
+    fn persistent_broadcast_into<'a, 's, Buf: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf) -> Result<PersistentCollective<'s, [()], Buf>, Error> {
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Bcast_init(buf.pointer_mut(), buf.count(), buf.datatype().as_raw(),
+                self.root_rank(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, &mut [], buf))
+    }
+}
+
+/// Persistent gather of contents of buffers on `Root`.
+///
+/// # Standard section(s)
+///
+/// 6.13.3 (MPI 4.0)
+pub trait PersistentGatherInto {
+    /// Create a request for gathering the contents of all `sendbuf`s on `Root` `&self`.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_gather_into<'a, 's, S: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S) -> Result<PersistentCollective<'s, S, [()]>, Error>;
+
+    /// Create a request for gathering the contents of all `sendbuf`s into `recvbuf` on `Root`
+    /// `&self`.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_gather_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R) -> Result<PersistentCollective<'s, S, R>, Error>;
+}
+
+impl<T: Root> PersistentGatherInto for T {
+    fn persistent_gather_into<'a, 's, S: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S) -> Result<PersistentCollective<'s, S, [()]>, Error> {
+        assert!(!self.is_root());
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Gather_init(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
+                ptr::null_mut(), 0, u8::equivalent_datatype().as_raw(), self.root_rank(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, sendbuf, &mut []))
+    }
+
+    fn persistent_gather_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R) -> Result<PersistentCollective<'s, S, R>, Error> {
+        assert!(self.is_root());
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        let recvcount = recvbuf.count() / self.communicator().target_size();
+        try!(check(unsafe {
+            ffi::RSMPI_Gather_init(sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(),
+                recvbuf.pointer_mut(), recvcount, recvbuf.datatype().as_raw(), self.root_rank(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Persistent scatter of the contents of a buffer on `Root` to all processes.
+///
+/// # Standard section(s)
+///
+/// 6.13.4 (MPI 4.0)
+pub trait PersistentScatterInto {
+    /// Create a request for scattering data from `Root` `&self` into `recvbuf`.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_scatter_into<'a, 's, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, recvbuf: &'a mut R) -> Result<PersistentCollective<'s, [()], R>, Error>;
+
+    /// Create a request for scattering the contents of `sendbuf` from `Root` `&self` to all
+    /// processes.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_scatter_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R) -> Result<PersistentCollective<'s, S, R>, Error>;
+}
+
+impl<T: Root> PersistentScatterInto for T {
+    fn persistent_scatter_into<'a, 's, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, recvbuf: &'a mut R) -> Result<PersistentCollective<'s, [()], R>, Error> {
+        assert!(!self.is_root());
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Scatter_init(ptr::null(), 0, u8::equivalent_datatype().as_raw(),
+                recvbuf.pointer_mut(), recvbuf.count(), recvbuf.datatype().as_raw(),
+                self.root_rank(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, &mut [], recvbuf))
+    }
+
+    fn persistent_scatter_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R) -> Result<PersistentCollective<'s, S, R>, Error> {
+        assert!(self.is_root());
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        let sendcount = sendbuf.count() / self.communicator().target_size();
+        try!(check(unsafe {
+            ffi::RSMPI_Scatter_init(sendbuf.pointer(), sendcount, sendbuf.datatype().as_raw(),
+                recvbuf.pointer_mut(), recvbuf.count(), recvbuf.datatype().as_raw(),
+                self.root_rank(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Persistent gather of contents of buffers on all participating processes.
+///
+/// # Standard section(s)
+///
+/// 6.13.5 (MPI 4.0)
+pub trait PersistentAllGatherInto {
+    /// Create a request for gathering the contents of `sendbuf` on all processes into `recvbuf`
+    /// on all processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_all_gather_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R) -> Result<PersistentCollective<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> PersistentAllGatherInto for C {
+    fn persistent_all_gather_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R) -> Result<PersistentCollective<'s, S, R>, Error> {
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        let recvcount = recvbuf.count() / self.communicator().target_size();
+        try!(check(unsafe {
+            ffi::RSMPI_Allgather_init(sendbuf.pointer(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), recvbuf.pointer_mut(), recvcount,
+                recvbuf.datatype().as_raw(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Persistent all-to-all communication.
+///
+/// # Standard section(s)
+///
+/// 6.13.6 (MPI 4.0)
+pub trait PersistentAllToAllInto {
+    /// Create a request for distributing equally sized parts of `sendbuf` on all processes to
+    /// `recvbuf` on all processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_all_to_all_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R) -> Result<PersistentCollective<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> PersistentAllToAllInto for C {
+    fn persistent_all_to_all_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R) -> Result<PersistentCollective<'s, S, R>, Error> {
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        let c_size = self.communicator().target_size();
+        try!(check(unsafe {
+            ffi::RSMPI_Alltoall_init(sendbuf.pointer(), sendbuf.count() / c_size,
+                sendbuf.datatype().as_raw(), recvbuf.pointer_mut(), recvbuf.count() / c_size,
+                recvbuf.datatype().as_raw(), self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Persistent global reduction, storing the result on the `Root` process
+///
+/// # Standard section(s)
+///
+/// 6.13.7 (MPI 4.0)
+pub trait PersistentReduceInto {
+    /// Create a request for a global reduction under the operation `op` of the input data in
+    /// `sendbuf`, the result is stored on the `Root` process.
+    ///
+    /// This function is to be called by all processes in the communicator except for the `Root`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_reduce_into<'a, 's, S: 'a + Buffer + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, op: &'a O) -> Result<PersistentCollective<'s, S, [()]>, Error>;
+
+    /// Create a request for a global reduction under the operation `op` of the input data in
+    /// `sendbuf` whose result is stored in `recvbuf`.
+    ///
+    /// This function is to be called by the `Root`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_reduce_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R, op: &'a O) -> Result<PersistentCollective<'s, S, R>, Error>;
+}
+
+impl<T: Root> PersistentReduceInto for T {
+    fn persistent_reduce_into<'a, 's, S: 'a + Buffer + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, op: &'a O) -> Result<PersistentCollective<'s, S, [()]>, Error> {
+        assert!(!self.is_root());
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Reduce_init(sendbuf.pointer(), ptr::null_mut(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), op.as_raw(), self.root_rank(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, sendbuf, &mut []))
+    }
+
+    fn persistent_reduce_into_root<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R, op: &'a O) -> Result<PersistentCollective<'s, S, R>, Error> {
+        assert!(self.is_root());
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Reduce_init(sendbuf.pointer(), recvbuf.pointer_mut(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), op.as_raw(), self.root_rank(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Persistent global reduction, storing the result on all processes
+///
+/// # Standard section(s)
+///
+/// 6.13.8 (MPI 4.0)
+pub trait PersistentAllReduceInto {
+    /// Create a request for a global reduction under the operation `op` of the input data in
+    /// `sendbuf` whose result is stored in `recvbuf` on all processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_all_reduce_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R, op: &'a O) -> Result<PersistentCollective<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> PersistentAllReduceInto for C {
+    fn persistent_all_reduce_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R, op: &'a O) -> Result<PersistentCollective<'s, S, R>, Error> {
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Allreduce_init(sendbuf.pointer(), recvbuf.pointer_mut(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Persistent global reduction, scattering blocks of the result to all processes
+///
+/// # Standard section(s)
+///
+/// 6.13.9 (MPI 4.0)
+pub trait PersistentReduceScatterBlockInto {
+    /// Create a request for a global reduction under the operation `op` of the input data in
+    /// `sendbuf` which scatters the result in blocks of `recvbuf.count()` elements, block `i` to
+    /// rank `i`.
+    ///
+    /// `sendbuf` has to contain one block per process in the group.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_reduce_scatter_block_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R, op: &'a O) -> Result<PersistentCollective<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> PersistentReduceScatterBlockInto for C {
+    fn persistent_reduce_scatter_block_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R, op: &'a O) -> Result<PersistentCollective<'s, S, R>, Error> {
+        assert_eq!(recvbuf.count() * self.communicator().size(), sendbuf.count());
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Reduce_scatter_block_init(sendbuf.pointer(), recvbuf.pointer_mut(),
+                recvbuf.count(), sendbuf.datatype().as_raw(), op.as_raw(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Persistent global reduction, scattering blocks of varying size of the result to all processes
+///
+/// # Standard section(s)
+///
+/// 6.13.10 (MPI 4.0)
+pub trait PersistentReduceScatterInto {
+    /// Create a request for a global reduction under the operation `op` of the input data in
+    /// `sendbuf` which scatters the result in blocks, block `i` containing `recvcounts[i]`
+    /// elements to rank `i`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_reduce_scatter_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R, recvcounts: &'a [Count], op: &'a O) -> Result<PersistentCollective<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> PersistentReduceScatterInto for C {
+    fn persistent_reduce_scatter_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R, recvcounts: &'a [Count], op: &'a O) -> Result<PersistentCollective<'s, S, R>, Error> {
+        assert_reduce_scatter_counts(self.communicator(), sendbuf.count(), recvbuf.count(), recvcounts);
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Reduce_scatter_init(sendbuf.pointer(), recvbuf.pointer_mut(),
+                recvcounts.as_ptr(), sendbuf.datatype().as_raw(), op.as_raw(),
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Persistent global inclusive prefix reduction
+///
+/// # Standard section(s)
+///
+/// 6.13.11 (MPI 4.0)
+pub trait PersistentScanInto {
+    /// Create a request for a global inclusive prefix reduction of the data in `sendbuf` into
+    /// `recvbuf` under operation `op`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_scan_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R, op: &'a O) -> Result<PersistentCollective<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> PersistentScanInto for C {
+    fn persistent_scan_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R, op: &'a O) -> Result<PersistentCollective<'s, S, R>, Error> {
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Scan_init(sendbuf.pointer(), recvbuf.pointer_mut(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, sendbuf, recvbuf))
+    }
+}
+
+/// Persistent global exclusive prefix reduction
+///
+/// # Standard section(s)
+///
+/// 6.13.12 (MPI 4.0)
+pub trait PersistentExclusiveScanInto {
+    /// Create a request for a global exclusive prefix reduction of the data in `sendbuf` into
+    /// `recvbuf` under operation `op`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent_collective.rs`
+    fn persistent_exclusive_scan_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R, op: &'a O) -> Result<PersistentCollective<'s, S, R>, Error>;
+}
+
+impl<C: Communicator> PersistentExclusiveScanInto for C {
+    fn persistent_exclusive_scan_into<'a, 's, S: 'a + Buffer + ?Sized, R: 'a + BufferMut + ?Sized, O: 'a + RawOperation>(&self, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R, op: &'a O) -> Result<PersistentCollective<'s, S, R>, Error> {
+        try!(check_version_4());
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Exscan_init(sendbuf.pointer(), recvbuf.pointer_mut(), sendbuf.count(),
+                sendbuf.datatype().as_raw(), op.as_raw(), self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(PersistentCollective::from_raw(request, scope, sendbuf, recvbuf))
     }
 }
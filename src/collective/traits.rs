@@ -1,5 +1,18 @@
 //! Collective communication traits
-pub use super::{Barrier, Root, BroadcastInto, GatherInto, AllGatherInto, ScatterInto,
-                AllToAllInto, ReduceInto, AllReduceInto, ScanInto, ExclusiveScanInto,
+pub use super::{Barrier, Root, BroadcastInto, GatherInto, GatherVarcountInto, AllGatherInto,
+                AllGatherVarcountInto, ScatterInto, ScatterVarcountInto, AllToAllInto,
+                AllToAllVarcountInto, ReduceInto, AllReduceInto, ReduceScatterBlockInto,
+                ReduceScatterInto, ScanInto, ExclusiveScanInto,
                 ImmediateBarrier, ImmediateBroadcastInto, ImmediateGatherInto, ImmediateAllGatherInto,
-                ImmediateScatterInto, ImmediateAllToAllInto};
+                ImmediateScatterInto, ImmediateAllToAllInto, ImmediateGatherVarcountInto,
+                ImmediateAllGatherVarcountInto, ImmediateScatterVarcountInto,
+                ImmediateAllToAllVarcountInto, ImmediateReduceInto, ImmediateAllReduceInto,
+                ImmediateReduceScatterBlockInto, ImmediateReduceScatterInto, ImmediateScanInto,
+                ImmediateExclusiveScanInto, NeighborAllGatherInto,
+                NeighborAllGatherVarcountInto, NeighborAllToAllInto, NeighborAllToAllVarcountInto,
+                ImmediateNeighborAllGatherInto, ImmediateNeighborAllGatherVarcountInto,
+                ImmediateNeighborAllToAllInto, ImmediateNeighborAllToAllVarcountInto,
+                PersistentBarrier, PersistentBroadcastInto, PersistentGatherInto,
+                PersistentScatterInto, PersistentAllGatherInto, PersistentAllToAllInto,
+                PersistentReduceInto, PersistentAllReduceInto, PersistentReduceScatterBlockInto,
+                PersistentReduceScatterInto, PersistentScanInto, PersistentExclusiveScanInto};
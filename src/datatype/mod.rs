@@ -17,42 +17,57 @@
 //! A `Buffer` describes a specific piece of data in memory that MPI should operate on. In addition
 //! to specifying the datatype of the data. It knows the address in memory where the data begins
 //! and how many instances of the datatype are contained in the data. The `Buffer` trait is
-//! implemented for slices that contain types implementing `EquivalentDatatype`.
+//! implemented for slices, `Vec`s and boxed slices that contain types implementing
+//! `EquivalentDatatype` as well as for `str` and `String` which are sent as bytes. With the
+//! `ndarray` feature, it is also implemented for arrays from the `ndarray` crate, which may be
+//! non-contiguous. Such strided arrays are described as a single instance of a derived datatype
+//! and cannot be divided among processes by collective operations.
+//!
+//! With the `derive` feature enabled, `EquivalentDatatype` can be derived for structs whose fields
+//! implement `EquivalentDatatype` (or are arrays of such types) via `#[derive(Equivalence)]`. The
+//! resulting `UserDatatype` is built from the offsets of the fields and resized to the size of the
+//! struct once and then cached.
 //!
 //! In order to use arbitrary datatypes to describe the contents of a slice, the `View` type is
 //! provided. However, since it can be used to instruct the underlying MPI implementation to
 //! rummage around arbitrary parts of memory, its constructors are currently marked unsafe.
 //!
+//! How a datatype was constructed can be inspected via `DatatypeExt::decode()` which returns a
+//! `DecodedDatatype` tree that can be pretty-printed via `{:#?}`.
+//!
+//! Buffers can be packed into a `PackedBuffer`, either in the native representation of a
+//! communicator via the `Pack` trait or in the portable `external32` representation via
+//! `pack_external32_into()`. A `PackedBuffer` is itself a `Buffer` and can be sent and received
+//! like any other.
+//!
+//! Collective operations with varying counts operate on a buffer that is split into one part per
+//! process. A `Partition` describes such a split of a `Buffer` via a count and a displacement per
+//! part. A `DatatypePartition` additionally gives every part its own datatype.
+//!
 //! # Unfinished features
 //!
-//! - **4.1.2**: Datatype constructors, `MPI_Type_create_struct()`
-//! - **4.1.3**: Subarray datatype constructors, `MPI_Type_create_subarray()`,
-//! - **4.1.4**: Distributed array datatype constructors, `MPI_Type_create_darray()`
 //! - **4.1.5**: Address and size functions, `MPI_Get_address()`, `MPI_Aint_add()`,
-//! `MPI_Aint_diff()`, `MPI_Type_size()`, `MPI_Type_size_x()`
-//! - **4.1.7**: Extent and bounds of datatypes: `MPI_Type_get_extent()`,
-//! `MPI_Type_get_extent_x()`, `MPI_Type_create_resized()`
-//! - **4.1.8**: True extent of datatypes, `MPI_Type_get_true_extent()`,
-//! `MPI_Type_get_true_extent_x()`
+//! `MPI_Aint_diff()`
 //! - **4.1.10**: Duplicating a datatype, `MPI_Type_dup()`
 //! - **4.1.11**: `MPI_Get_elements()`, `MPI_Get_elements_x()`
-//! - **4.1.13**: Decoding a datatype, `MPI_Type_get_envelope()`, `MPI_Type_get_contents()`
-//! - **4.2**: Pack and unpack, `MPI_Pack()`, `MPI_Unpack()`, `MPI_Pack_size()`
-//! - **4.3**: Canonical pack and unpack, `MPI_Pack_external()`, `MPI_Unpack_external()`,
-//! `MPI_Pack_external_size()`
 
-use std::{mem};
+use std::{fmt, mem};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::{Mutex, Once, ONCE_INIT};
 
-use libc::{c_void};
+use libc::{c_char, c_int, c_void};
 
 use conv::ConvUtil;
 
-use super::{Address, Count};
+use super::{Address, Count, Error};
+use super::check;
 
 use ffi;
-use ffi::MPI_Datatype;
+use ffi::{MPI_Count, MPI_Datatype};
 
 use raw::traits::*;
+use topology::{Communicator, Rank};
 
 pub mod traits;
 
@@ -71,6 +86,21 @@ impl AsRaw for SystemDatatype {
 
 impl RawDatatype for SystemDatatype { }
 
+impl fmt::Debug for SystemDatatype {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let bufsize = ffi::RSMPI_MAX_OBJECT_NAME.value_as().expect(
+            &format!("MPI_MAX_OBJECT_NAME ({}) cannot be expressed as a usize.",
+                ffi::RSMPI_MAX_OBJECT_NAME));
+        let mut buf = vec![0u8; bufsize];
+        let mut len: c_int = 0;
+
+        unsafe { ffi::MPI_Type_get_name(self.0, buf.as_mut_ptr() as *mut c_char, &mut len); }
+        buf.truncate(len.value_as().expect(
+            &format!("Length of datatype name ({}) cannot be expressed as a usize.", len)));
+        write!(f, "{}", String::from_utf8_lossy(&buf))
+    }
+}
+
 /// A direct equivalence exists between the implementing type and an MPI datatype
 ///
 /// # Standard section(s)
@@ -105,6 +135,179 @@ equivalent_system_datatype!(u16, ffi::RSMPI_UINT16_T);
 equivalent_system_datatype!(u32, ffi::RSMPI_UINT32_T);
 equivalent_system_datatype!(u64, ffi::RSMPI_UINT64_T);
 
+#[cfg(target_pointer_width = "32")]
+equivalent_system_datatype!(isize, ffi::RSMPI_INT32_T);
+#[cfg(target_pointer_width = "32")]
+equivalent_system_datatype!(usize, ffi::RSMPI_UINT32_T);
+#[cfg(target_pointer_width = "64")]
+equivalent_system_datatype!(isize, ffi::RSMPI_INT64_T);
+#[cfg(target_pointer_width = "64")]
+equivalent_system_datatype!(usize, ffi::RSMPI_UINT64_T);
+
+equivalent_system_datatype!(bool, ffi::RSMPI_C_BOOL);
+
+#[cfg(feature = "complex")]
+equivalent_system_datatype!(::num_complex::Complex<f32>, ffi::RSMPI_C_FLOAT_COMPLEX);
+#[cfg(feature = "complex")]
+equivalent_system_datatype!(::num_complex::Complex<f64>, ffi::RSMPI_C_DOUBLE_COMPLEX);
+
+macro_rules! equivalent_array_datatype {
+    ($($n:expr)*) => ($(
+        impl<T: EquivalentDatatype> EquivalentDatatype for [T; $n] {
+            type Out = &'static UserDatatype;
+            fn equivalent_datatype() -> Self::Out {
+                equivalent_contiguous_datatype($n, T::equivalent_datatype())
+            }
+        }
+    )*)
+}
+
+/// The contiguous datatype of `count` elements of `oldtype`, created on first use and cached for
+/// the remainder of the program.
+fn equivalent_contiguous_datatype<D: RawDatatype>(count: Count, oldtype: D)
+    -> &'static UserDatatype
+{
+    static INIT: Once = ONCE_INIT;
+    static mut DATATYPES: Option<Mutex<HashMap<(MPI_Datatype, Count), &'static UserDatatype>>> =
+        None;
+
+    unsafe {
+        INIT.call_once(|| { DATATYPES = Some(Mutex::new(HashMap::new())); });
+        let mut datatypes = DATATYPES.as_ref().unwrap().lock()
+            .expect("Cache of contiguous datatypes is poisoned.");
+        *datatypes.entry((oldtype.as_raw(), count)).or_insert_with(|| {
+            let datatype = UserDatatype::contiguous(count, oldtype)
+                .expect("Could not create contiguous datatype for array.");
+            &*Box::into_raw(Box::new(datatype))
+        })
+    }
+}
+
+equivalent_array_datatype!(1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28
+    29 30 31 32);
+
+/// A value paired with an index, e.g. the rank of the process that holds the value
+///
+/// Used with the `SystemOperation`s `min_location()` and `max_location()` to find the minimum or
+/// maximum of a set of values along with the index of one of its occurrences.
+///
+/// # Examples
+///
+/// See `examples/reduce_location.rs`
+///
+/// # Standard section(s)
+///
+/// 5.9.4
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ValueIndex<T> {
+    /// The value
+    pub value: T,
+    /// The index associated with the value
+    pub index: c_int
+}
+
+impl<T> ValueIndex<T> {
+    /// Pairs `value` with `index`.
+    pub fn new(value: T, index: c_int) -> ValueIndex<T> {
+        ValueIndex { value: value, index: index }
+    }
+}
+
+equivalent_system_datatype!(ValueIndex<f32>, ffi::RSMPI_FLOAT_INT);
+equivalent_system_datatype!(ValueIndex<f64>, ffi::RSMPI_DOUBLE_INT);
+equivalent_system_datatype!(ValueIndex<i16>, ffi::RSMPI_SHORT_INT);
+equivalent_system_datatype!(ValueIndex<i32>, ffi::RSMPI_2INT);
+
+/// The storage order of a multi-dimensional array
+///
+/// # Standard section(s)
+///
+/// 4.1.3
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Order {
+    /// Row-major order, the last dimension varies fastest.
+    C,
+    /// Column-major order, the first dimension varies fastest.
+    Fortran,
+    /// An order this library does not know, identified by its raw value, as it may be returned
+    /// when decoding a datatype
+    Unknown(c_int)
+}
+
+impl Order {
+    /// The raw value understood by the MPI C API
+    fn as_raw(&self) -> c_int {
+        match *self {
+            Order::C => ffi::RSMPI_ORDER_C,
+            Order::Fortran => ffi::RSMPI_ORDER_FORTRAN,
+            Order::Unknown(order) => order
+        }
+    }
+
+    /// Converts the raw value used by the MPI C API
+    fn from_raw(order: c_int) -> Order {
+        if order == ffi::RSMPI_ORDER_C {
+            Order::C
+        } else if order == ffi::RSMPI_ORDER_FORTRAN {
+            Order::Fortran
+        } else {
+            Order::Unknown(order)
+        }
+    }
+}
+
+/// How one dimension of a distributed array is distributed across the process grid
+///
+/// # Standard section(s)
+///
+/// 4.1.4
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Distribution {
+    /// One contiguous block of the default size per process
+    Block,
+    /// One contiguous block of the given size per process
+    BlockOf(Count),
+    /// Blocks of one element dealt out to the processes round-robin
+    Cyclic,
+    /// Blocks of the given size dealt out to the processes round-robin
+    CyclicOf(Count),
+    /// The dimension is not distributed.
+    Undistributed,
+    /// A distribution this library does not know, identified by its raw value and distribution
+    /// argument, as it may be returned when decoding a datatype
+    Unknown(c_int, c_int)
+}
+
+impl Distribution {
+    /// The raw distribution and distribution argument understood by the MPI C API
+    fn as_raw(&self) -> (c_int, c_int) {
+        match *self {
+            Distribution::Block => (ffi::RSMPI_DISTRIBUTE_BLOCK, ffi::RSMPI_DISTRIBUTE_DFLT_DARG),
+            Distribution::BlockOf(n) => (ffi::RSMPI_DISTRIBUTE_BLOCK, n),
+            Distribution::Cyclic => (ffi::RSMPI_DISTRIBUTE_CYCLIC, ffi::RSMPI_DISTRIBUTE_DFLT_DARG),
+            Distribution::CyclicOf(n) => (ffi::RSMPI_DISTRIBUTE_CYCLIC, n),
+            Distribution::Undistributed =>
+                (ffi::RSMPI_DISTRIBUTE_NONE, ffi::RSMPI_DISTRIBUTE_DFLT_DARG),
+            Distribution::Unknown(distrib, darg) => (distrib, darg)
+        }
+    }
+
+    /// Converts the raw distribution and distribution argument used by the MPI C API
+    fn from_raw(distrib: c_int, darg: c_int) -> Distribution {
+        let default = darg == ffi::RSMPI_DISTRIBUTE_DFLT_DARG;
+        if distrib == ffi::RSMPI_DISTRIBUTE_BLOCK {
+            if default { Distribution::Block } else { Distribution::BlockOf(darg) }
+        } else if distrib == ffi::RSMPI_DISTRIBUTE_CYCLIC {
+            if default { Distribution::Cyclic } else { Distribution::CyclicOf(darg) }
+        } else if distrib == ffi::RSMPI_DISTRIBUTE_NONE {
+            Distribution::Undistributed
+        } else {
+            Distribution::Unknown(distrib, darg)
+        }
+    }
+}
+
 /// A user defined MPI datatype
 ///
 /// # Standard section(s)
@@ -121,13 +324,13 @@ impl UserDatatype {
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn contiguous<D: RawDatatype>(count: Count, oldtype: D) -> UserDatatype {
+    pub fn contiguous<D: RawDatatype>(count: Count, oldtype: D) -> Result<UserDatatype, Error> {
         let mut newtype: MPI_Datatype = unsafe { mem::uninitialized() };
         unsafe {
-            ffi::MPI_Type_contiguous(count, oldtype.as_raw(), &mut newtype);
-            ffi::MPI_Type_commit(&mut newtype);
+            try!(check(ffi::MPI_Type_contiguous(count, oldtype.as_raw(), &mut newtype)));
+            try!(check(ffi::MPI_Type_commit(&mut newtype)));
         }
-        UserDatatype(newtype)
+        Ok(UserDatatype(newtype))
     }
 
     /// Construct a new datatype out of `count` blocks of `blocklength` elements of `oldtype`
@@ -139,13 +342,14 @@ impl UserDatatype {
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn vector<D: RawDatatype>(count: Count, blocklength: Count, stride: Count, oldtype: D) -> UserDatatype {
+    pub fn vector<D: RawDatatype>(count: Count, blocklength: Count, stride: Count, oldtype: D) -> Result<UserDatatype, Error> {
         let mut newtype: MPI_Datatype = unsafe { mem::uninitialized() };
         unsafe {
-            ffi::MPI_Type_vector(count, blocklength, stride, oldtype.as_raw(), &mut newtype);
-            ffi::MPI_Type_commit(&mut newtype);
+            try!(check(ffi::MPI_Type_vector(count, blocklength, stride, oldtype.as_raw(),
+                &mut newtype)));
+            try!(check(ffi::MPI_Type_commit(&mut newtype)));
         }
-        UserDatatype(newtype)
+        Ok(UserDatatype(newtype))
     }
 
     /// Like `vector()` but `stride` is given in bytes rather than elements of `oldtype`.
@@ -153,13 +357,14 @@ impl UserDatatype {
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn heterogeneous_vector<D: RawDatatype>(count: Count, blocklength: Count, stride: Address, oldtype: D) -> UserDatatype {
+    pub fn heterogeneous_vector<D: RawDatatype>(count: Count, blocklength: Count, stride: Address, oldtype: D) -> Result<UserDatatype, Error> {
         let mut newtype: MPI_Datatype = unsafe { mem::uninitialized() };
         unsafe {
-            ffi::MPI_Type_hvector(count, blocklength, stride, oldtype.as_raw(), &mut newtype);
-            ffi::MPI_Type_commit(&mut newtype);
+            try!(check(ffi::MPI_Type_hvector(count, blocklength, stride, oldtype.as_raw(),
+                &mut newtype)));
+            try!(check(ffi::MPI_Type_commit(&mut newtype)));
         }
-        UserDatatype(newtype)
+        Ok(UserDatatype(newtype))
     }
 
     /// Constructs a new type out of multiple blocks of individual length and displacement.
@@ -169,15 +374,15 @@ impl UserDatatype {
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn indexed<D: RawDatatype>(blocklengths: &[Count], displacements: &[Count], oldtype: D) -> UserDatatype {
+    pub fn indexed<D: RawDatatype>(blocklengths: &[Count], displacements: &[Count], oldtype: D) -> Result<UserDatatype, Error> {
         assert_eq!(blocklengths.len(), displacements.len());
         let mut newtype: MPI_Datatype = unsafe { mem::uninitialized() };
         unsafe {
-            ffi::MPI_Type_indexed(blocklengths.count(), blocklengths.as_ptr(),
-                displacements.as_ptr(), oldtype.as_raw(), &mut newtype);
-            ffi::MPI_Type_commit(&mut newtype);
+            try!(check(ffi::MPI_Type_indexed(blocklengths.count(), blocklengths.as_ptr(),
+                displacements.as_ptr(), oldtype.as_raw(), &mut newtype)));
+            try!(check(ffi::MPI_Type_commit(&mut newtype)));
         }
-        UserDatatype(newtype)
+        Ok(UserDatatype(newtype))
     }
 
     /// Constructs a new type out of multiple blocks of individual length and displacement.
@@ -187,15 +392,15 @@ impl UserDatatype {
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn heterogeneous_indexed<D: RawDatatype>(blocklengths: &[Count], displacements: &[Address], oldtype: D) -> UserDatatype {
+    pub fn heterogeneous_indexed<D: RawDatatype>(blocklengths: &[Count], displacements: &[Address], oldtype: D) -> Result<UserDatatype, Error> {
         assert_eq!(blocklengths.len(), displacements.len());
         let mut newtype: MPI_Datatype = unsafe { mem::uninitialized() };
         unsafe {
-            ffi::MPI_Type_create_hindexed(blocklengths.count(), blocklengths.as_ptr(),
-                displacements.as_ptr(), oldtype.as_raw(), &mut newtype);
-            ffi::MPI_Type_commit(&mut newtype);
+            try!(check(ffi::MPI_Type_create_hindexed(blocklengths.count(), blocklengths.as_ptr(),
+                displacements.as_ptr(), oldtype.as_raw(), &mut newtype)));
+            try!(check(ffi::MPI_Type_commit(&mut newtype)));
         }
-        UserDatatype(newtype)
+        Ok(UserDatatype(newtype))
     }
 
     /// Construct a new type out of blocks of the same length and individual displacements.
@@ -203,14 +408,14 @@ impl UserDatatype {
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn indexed_block<D: RawDatatype>(blocklength: Count, displacements: &[Count], oldtype: D) -> UserDatatype {
+    pub fn indexed_block<D: RawDatatype>(blocklength: Count, displacements: &[Count], oldtype: D) -> Result<UserDatatype, Error> {
         let mut newtype: MPI_Datatype = unsafe { mem::uninitialized() };
         unsafe {
-            ffi::MPI_Type_create_indexed_block(displacements.count(), blocklength,
-                displacements.as_ptr(), oldtype.as_raw(), &mut newtype);
-            ffi::MPI_Type_commit(&mut newtype);
+            try!(check(ffi::MPI_Type_create_indexed_block(displacements.count(), blocklength,
+                displacements.as_ptr(), oldtype.as_raw(), &mut newtype)));
+            try!(check(ffi::MPI_Type_commit(&mut newtype)));
         }
-        UserDatatype(newtype)
+        Ok(UserDatatype(newtype))
     }
 
     /// Construct a new type out of blocks of the same length and individual displacements.
@@ -219,14 +424,112 @@ impl UserDatatype {
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn heterogeneous_indexed_block<D: RawDatatype>(blocklength: Count, displacements: &[Address], oldtype: D) -> UserDatatype {
+    pub fn heterogeneous_indexed_block<D: RawDatatype>(blocklength: Count, displacements: &[Address], oldtype: D) -> Result<UserDatatype, Error> {
+        let mut newtype: MPI_Datatype = unsafe { mem::uninitialized() };
+        unsafe {
+            try!(check(ffi::MPI_Type_create_hindexed_block(displacements.count(), blocklength,
+                displacements.as_ptr(), oldtype.as_raw(), &mut newtype)));
+            try!(check(ffi::MPI_Type_commit(&mut newtype)));
+        }
+        Ok(UserDatatype(newtype))
+    }
+
+    /// Constructs a new type out of blocks of different datatypes, e.g. to describe the layout
+    /// of a struct. Block `i` will be `blocklengths[i]` items of datatype `types[i]` displaced by
+    /// `displacements[i]` bytes.
+    ///
+    /// # Examples
+    /// See `examples/structured.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn structured(blocklengths: &[Count], displacements: &[Address], types: &[&RawDatatype]) -> Result<UserDatatype, Error> {
+        assert_eq!(blocklengths.len(), displacements.len());
+        assert_eq!(blocklengths.len(), types.len());
+        let types = types.iter().map(|t| unsafe { t.as_raw() }).collect::<Vec<_>>();
+        let mut newtype: MPI_Datatype = unsafe { mem::uninitialized() };
+        unsafe {
+            try!(check(ffi::MPI_Type_create_struct(blocklengths.count(), blocklengths.as_ptr(),
+                displacements.as_ptr(), types.as_ptr(), &mut newtype)));
+            try!(check(ffi::MPI_Type_commit(&mut newtype)));
+        }
+        Ok(UserDatatype(newtype))
+    }
+
+    /// Constructs a new type describing an n-dimensional subarray of an n-dimensional array of
+    /// `oldtype`. The full array has extent `sizes[i]` in dimension `i`, the subarray has extent
+    /// `subsizes[i]` and starts at index `starts[i]`.
+    ///
+    /// # Examples
+    /// See `examples/subarray.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.3
+    pub fn subarray<D: RawDatatype>(sizes: &[Count], subsizes: &[Count], starts: &[Count], order: Order, oldtype: D) -> Result<UserDatatype, Error> {
+        assert_eq!(sizes.len(), subsizes.len());
+        assert_eq!(sizes.len(), starts.len());
+        assert!(sizes.iter().zip(subsizes.iter()).zip(starts.iter())
+                .all(|((&n, &m), &s)| m >= 1 && s >= 0 && s + m <= n),
+            "Subarray does not fit into array of sizes {:?}.", sizes);
         let mut newtype: MPI_Datatype = unsafe { mem::uninitialized() };
         unsafe {
-            ffi::MPI_Type_create_hindexed_block(displacements.count(), blocklength,
-                displacements.as_ptr(), oldtype.as_raw(), &mut newtype);
-            ffi::MPI_Type_commit(&mut newtype);
+            try!(check(ffi::MPI_Type_create_subarray(sizes.count(), sizes.as_ptr(),
+                subsizes.as_ptr(), starts.as_ptr(), order.as_raw(), oldtype.as_raw(),
+                &mut newtype)));
+            try!(check(ffi::MPI_Type_commit(&mut newtype)));
         }
-        UserDatatype(newtype)
+        Ok(UserDatatype(newtype))
+    }
+
+    /// Constructs a new type describing the part of an n-dimensional array of `oldtype` that is
+    /// owned by process `rank` out of `size` processes. The global array has extent `gsizes[i]`
+    /// in dimension `i` which is distributed as described by `distribs[i]` across `psizes[i]`
+    /// processes of a process grid in the same order as the array.
+    ///
+    /// # Examples
+    /// See `examples/darray.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.4
+    pub fn distributed_array<D: RawDatatype>(size: Rank, rank: Rank, gsizes: &[Count], distribs: &[Distribution], psizes: &[Count], order: Order, oldtype: D) -> Result<UserDatatype, Error> {
+        assert_eq!(gsizes.len(), distribs.len());
+        assert_eq!(gsizes.len(), psizes.len());
+        assert!(0 <= rank && rank < size, "Rank {} is not part of a grid of {} processes.", rank,
+            size);
+        assert_eq!(size, psizes.iter().fold(1, |acc, &p| acc * p));
+        let (distribs, dargs): (Vec<c_int>, Vec<c_int>) = distribs.iter()
+            .map(|d| d.as_raw()).unzip();
+        let mut newtype: MPI_Datatype = unsafe { mem::uninitialized() };
+        unsafe {
+            try!(check(ffi::MPI_Type_create_darray(size, rank, gsizes.count(), gsizes.as_ptr(),
+                distribs.as_ptr(), dargs.as_ptr(), psizes.as_ptr(), order.as_raw(),
+                oldtype.as_raw(), &mut newtype)));
+            try!(check(ffi::MPI_Type_commit(&mut newtype)));
+        }
+        Ok(UserDatatype(newtype))
+    }
+
+    /// Constructs a copy of `oldtype` with its lower bound set to `lower_bound` and its extent set
+    /// to `extent` bytes. The extent determines the displacement between consecutive instances
+    /// of the datatype in a buffer.
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.7
+    pub fn resized<D: RawDatatype>(oldtype: D, lower_bound: Address, extent: Address) -> Result<UserDatatype, Error> {
+        let mut newtype: MPI_Datatype = unsafe { mem::uninitialized() };
+        unsafe {
+            try!(check(ffi::MPI_Type_create_resized(oldtype.as_raw(), lower_bound, extent,
+                &mut newtype)));
+            try!(check(ffi::MPI_Type_commit(&mut newtype)));
+        }
+        Ok(UserDatatype(newtype))
     }
 }
 
@@ -246,6 +549,351 @@ impl AsRaw for UserDatatype {
 
 impl RawDatatype for UserDatatype { }
 
+/// Extension methods implemented on datatypes
+pub trait DatatypeExt: RawDatatype {
+    /// The number of bytes of actual data in one instance of the datatype, i.e. without gaps
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.5
+    fn size(&self) -> Result<Address, Error> {
+        let mut size: MPI_Count = unsafe { mem::uninitialized() };
+        try!(check(unsafe { ffi::MPI_Type_size_x(self.as_raw(), &mut size) }));
+        Ok(count_as_address(size))
+    }
+
+    /// The lower bound and the extent of the datatype in bytes
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.7
+    fn extent(&self) -> Result<(Address, Address), Error> {
+        let mut lower_bound: MPI_Count = unsafe { mem::uninitialized() };
+        let mut extent: MPI_Count = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Type_get_extent_x(self.as_raw(), &mut lower_bound, &mut extent)
+        }));
+        Ok((count_as_address(lower_bound), count_as_address(extent)))
+    }
+
+    /// The lower bound and the extent of the data actually covered by the datatype in bytes,
+    /// ignoring any resizing
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.8
+    fn true_extent(&self) -> Result<(Address, Address), Error> {
+        let mut lower_bound: MPI_Count = unsafe { mem::uninitialized() };
+        let mut extent: MPI_Count = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Type_get_true_extent_x(self.as_raw(), &mut lower_bound, &mut extent)
+        }));
+        Ok((count_as_address(lower_bound), count_as_address(extent)))
+    }
+
+    /// Decodes how the datatype was constructed, recursively down to the named datatypes it is
+    /// built from
+    ///
+    /// # Examples
+    /// See `examples/decode.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.13
+    fn decode(&self) -> Result<DecodedDatatype, Error> {
+        unsafe { decode_raw(self.as_raw(), false) }
+    }
+}
+
+impl<T: RawDatatype> DatatypeExt for T { }
+
+fn count_as_address(count: MPI_Count) -> Address {
+    count.value_as().expect(&format!("Byte count ({}) cannot be expressed as an Address.", count))
+}
+
+/// Describes how a datatype was constructed
+///
+/// The variants mirror the constructors of `UserDatatype` and carry the arguments that were passed
+/// to them. The `Debug` implementation prints named datatypes by their MPI name, so `{:#?}` gives a
+/// readable tree of the whole construction.
+///
+/// # Examples
+/// See `examples/decode.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.13
+#[derive(Debug)]
+pub enum DecodedDatatype {
+    /// A named datatype, e.g. `MPI_DOUBLE`
+    Named(SystemDatatype),
+    /// A duplicate of another datatype
+    Duplicate(Box<DecodedDatatype>),
+    /// See `UserDatatype::contiguous()`
+    Contiguous {
+        /// Number of instances of `oldtype`
+        count: Count,
+        /// The constituent datatype
+        oldtype: Box<DecodedDatatype>
+    },
+    /// See `UserDatatype::vector()`
+    Vector {
+        /// Number of blocks
+        count: Count,
+        /// Number of instances of `oldtype` in each block
+        blocklength: Count,
+        /// Distance between the starts of the blocks in multiples of the extent of `oldtype`
+        stride: Count,
+        /// The constituent datatype
+        oldtype: Box<DecodedDatatype>
+    },
+    /// See `UserDatatype::heterogeneous_vector()`
+    HeterogeneousVector {
+        /// Number of blocks
+        count: Count,
+        /// Number of instances of `oldtype` in each block
+        blocklength: Count,
+        /// Distance between the starts of the blocks in bytes
+        stride: Address,
+        /// The constituent datatype
+        oldtype: Box<DecodedDatatype>
+    },
+    /// See `UserDatatype::indexed()`
+    Indexed {
+        /// Number of instances of `oldtype` in each block
+        blocklengths: Vec<Count>,
+        /// Displacements of the blocks in multiples of the extent of `oldtype`
+        displacements: Vec<Count>,
+        /// The constituent datatype
+        oldtype: Box<DecodedDatatype>
+    },
+    /// See `UserDatatype::heterogeneous_indexed()`
+    HeterogeneousIndexed {
+        /// Number of instances of `oldtype` in each block
+        blocklengths: Vec<Count>,
+        /// Displacements of the blocks in bytes
+        displacements: Vec<Address>,
+        /// The constituent datatype
+        oldtype: Box<DecodedDatatype>
+    },
+    /// See `UserDatatype::indexed_block()`
+    IndexedBlock {
+        /// Number of instances of `oldtype` in every block
+        blocklength: Count,
+        /// Displacements of the blocks in multiples of the extent of `oldtype`
+        displacements: Vec<Count>,
+        /// The constituent datatype
+        oldtype: Box<DecodedDatatype>
+    },
+    /// See `UserDatatype::heterogeneous_indexed_block()`
+    HeterogeneousIndexedBlock {
+        /// Number of instances of `oldtype` in every block
+        blocklength: Count,
+        /// Displacements of the blocks in bytes
+        displacements: Vec<Address>,
+        /// The constituent datatype
+        oldtype: Box<DecodedDatatype>
+    },
+    /// See `UserDatatype::structured()`
+    Structured {
+        /// Number of instances of the respective type in each block
+        blocklengths: Vec<Count>,
+        /// Displacements of the blocks in bytes
+        displacements: Vec<Address>,
+        /// The constituent datatypes of the blocks
+        types: Vec<DecodedDatatype>
+    },
+    /// See `UserDatatype::subarray()`
+    Subarray {
+        /// Number of elements of `oldtype` in each dimension of the full array
+        sizes: Vec<Count>,
+        /// Number of elements of `oldtype` in each dimension of the subarray
+        subsizes: Vec<Count>,
+        /// Starting coordinates of the subarray in each dimension
+        starts: Vec<Count>,
+        /// Storage order of the array
+        order: Order,
+        /// The constituent datatype
+        oldtype: Box<DecodedDatatype>
+    },
+    /// See `UserDatatype::distributed_array()`
+    DistributedArray {
+        /// Number of processes in the process grid
+        size: Rank,
+        /// Rank of the process the datatype describes the local part of
+        rank: Rank,
+        /// Number of elements of `oldtype` in each dimension of the global array
+        gsizes: Vec<Count>,
+        /// Distribution of each dimension
+        distribs: Vec<Distribution>,
+        /// Number of processes in each dimension of the process grid
+        psizes: Vec<Count>,
+        /// Storage order of the array
+        order: Order,
+        /// The constituent datatype
+        oldtype: Box<DecodedDatatype>
+    },
+    /// See `UserDatatype::resized()`
+    Resized {
+        /// The new lower bound in bytes
+        lower_bound: Address,
+        /// The new extent in bytes
+        extent: Address,
+        /// The constituent datatype
+        oldtype: Box<DecodedDatatype>
+    },
+    /// A Fortran 90 `REAL` type with the given precision and range
+    F90Real {
+        /// Decimal precision
+        precision: c_int,
+        /// Decimal exponent range
+        range: c_int
+    },
+    /// A Fortran 90 `COMPLEX` type with the given precision and range
+    F90Complex {
+        /// Decimal precision
+        precision: c_int,
+        /// Decimal exponent range
+        range: c_int
+    },
+    /// A Fortran 90 `INTEGER` type with the given range
+    F90Integer {
+        /// Decimal exponent range
+        range: c_int
+    },
+    /// A datatype built by a constructor this library does not know, identified by its raw
+    /// combiner, e.g. one that was added in a newer version of the MPI standard
+    Unknown(c_int)
+}
+
+/// Decodes `datatype`, which is freed afterwards if it is `contained` in another datatype, since
+/// `MPI_Type_get_contents()` hands out new handles for derived datatypes.
+unsafe fn decode_raw(datatype: MPI_Datatype, contained: bool) -> Result<DecodedDatatype, Error> {
+    let mut num_integers: c_int = mem::uninitialized();
+    let mut num_addresses: c_int = mem::uninitialized();
+    let mut num_datatypes: c_int = mem::uninitialized();
+    let mut combiner: c_int = mem::uninitialized();
+    try!(check(ffi::MPI_Type_get_envelope(datatype, &mut num_integers, &mut num_addresses,
+        &mut num_datatypes, &mut combiner)));
+
+    if combiner == ffi::RSMPI_COMBINER_NAMED {
+        return Ok(DecodedDatatype::Named(SystemDatatype(datatype)));
+    }
+    let _contained = if contained { Some(UserDatatype(datatype)) } else { None };
+
+    let mut integers: Vec<c_int> = vec![0; num_integers.value_as().expect(
+        &format!("Number of integers ({}) cannot be expressed as a usize.", num_integers))];
+    let mut addresses: Vec<Address> = vec![0; num_addresses.value_as().expect(
+        &format!("Number of addresses ({}) cannot be expressed as a usize.", num_addresses))];
+    let mut datatypes: Vec<MPI_Datatype> = vec![ffi::RSMPI_DATATYPE_NULL;
+        num_datatypes.value_as().expect(
+            &format!("Number of datatypes ({}) cannot be expressed as a usize.", num_datatypes))];
+    try!(check(ffi::MPI_Type_get_contents(datatype, num_integers, num_addresses, num_datatypes,
+        integers.as_mut_ptr(), addresses.as_mut_ptr(), datatypes.as_mut_ptr())));
+    // Every contained datatype is decoded, so that all of them are freed even if one fails.
+    let types = datatypes.into_iter().map(|t| decode_raw(t, true)).collect::<Vec<_>>();
+    let mut types = try!(types.into_iter().collect::<Result<Vec<_>, _>>());
+
+    let n = if integers.is_empty() { 0 } else { integers[0] as usize };
+    Ok(if combiner == ffi::RSMPI_COMBINER_DUP {
+        DecodedDatatype::Duplicate(Box::new(types.remove(0)))
+    } else if combiner == ffi::RSMPI_COMBINER_CONTIGUOUS {
+        DecodedDatatype::Contiguous {
+            count: integers[0],
+            oldtype: Box::new(types.remove(0))
+        }
+    } else if combiner == ffi::RSMPI_COMBINER_VECTOR {
+        DecodedDatatype::Vector {
+            count: integers[0],
+            blocklength: integers[1],
+            stride: integers[2],
+            oldtype: Box::new(types.remove(0))
+        }
+    } else if combiner == ffi::RSMPI_COMBINER_HVECTOR {
+        DecodedDatatype::HeterogeneousVector {
+            count: integers[0],
+            blocklength: integers[1],
+            stride: addresses[0],
+            oldtype: Box::new(types.remove(0))
+        }
+    } else if combiner == ffi::RSMPI_COMBINER_INDEXED {
+        DecodedDatatype::Indexed {
+            blocklengths: integers[1..n + 1].to_vec(),
+            displacements: integers[n + 1..2 * n + 1].to_vec(),
+            oldtype: Box::new(types.remove(0))
+        }
+    } else if combiner == ffi::RSMPI_COMBINER_HINDEXED {
+        DecodedDatatype::HeterogeneousIndexed {
+            blocklengths: integers[1..n + 1].to_vec(),
+            displacements: addresses,
+            oldtype: Box::new(types.remove(0))
+        }
+    } else if combiner == ffi::RSMPI_COMBINER_INDEXED_BLOCK {
+        DecodedDatatype::IndexedBlock {
+            blocklength: integers[1],
+            displacements: integers[2..n + 2].to_vec(),
+            oldtype: Box::new(types.remove(0))
+        }
+    } else if combiner == ffi::RSMPI_COMBINER_HINDEXED_BLOCK {
+        DecodedDatatype::HeterogeneousIndexedBlock {
+            blocklength: integers[1],
+            displacements: addresses,
+            oldtype: Box::new(types.remove(0))
+        }
+    } else if combiner == ffi::RSMPI_COMBINER_STRUCT {
+        DecodedDatatype::Structured {
+            blocklengths: integers[1..n + 1].to_vec(),
+            displacements: addresses,
+            types: types
+        }
+    } else if combiner == ffi::RSMPI_COMBINER_SUBARRAY {
+        DecodedDatatype::Subarray {
+            sizes: integers[1..n + 1].to_vec(),
+            subsizes: integers[n + 1..2 * n + 1].to_vec(),
+            starts: integers[2 * n + 1..3 * n + 1].to_vec(),
+            order: Order::from_raw(integers[3 * n + 1]),
+            oldtype: Box::new(types.remove(0))
+        }
+    } else if combiner == ffi::RSMPI_COMBINER_DARRAY {
+        let n = integers[2] as usize;
+        DecodedDatatype::DistributedArray {
+            size: integers[0],
+            rank: integers[1],
+            gsizes: integers[3..n + 3].to_vec(),
+            distribs: integers[n + 3..2 * n + 3].iter()
+                .zip(integers[2 * n + 3..3 * n + 3].iter())
+                .map(|(&distrib, &darg)| Distribution::from_raw(distrib, darg))
+                .collect(),
+            psizes: integers[3 * n + 3..4 * n + 3].to_vec(),
+            order: Order::from_raw(integers[4 * n + 3]),
+            oldtype: Box::new(types.remove(0))
+        }
+    } else if combiner == ffi::RSMPI_COMBINER_RESIZED {
+        DecodedDatatype::Resized {
+            lower_bound: addresses[0],
+            extent: addresses[1],
+            oldtype: Box::new(types.remove(0))
+        }
+    } else if combiner == ffi::RSMPI_COMBINER_F90_REAL {
+        DecodedDatatype::F90Real { precision: integers[0], range: integers[1] }
+    } else if combiner == ffi::RSMPI_COMBINER_F90_COMPLEX {
+        DecodedDatatype::F90Complex { precision: integers[0], range: integers[1] }
+    } else if combiner == ffi::RSMPI_COMBINER_F90_INTEGER {
+        DecodedDatatype::F90Integer { range: integers[0] }
+    } else {
+        DecodedDatatype::Unknown(combiner)
+    })
+}
+
 /// Something that has an associated datatype
 // TODO: merge this into Buffer, maybe?
 pub trait Datatype {
@@ -265,6 +913,43 @@ impl<T> Datatype for [T] where T: EquivalentDatatype {
     fn datatype(&self) -> Self::Out { <T as EquivalentDatatype>::equivalent_datatype() }
 }
 
+impl<T> Datatype for Vec<T> where T: EquivalentDatatype {
+    type Out = <T as EquivalentDatatype>::Out;
+    fn datatype(&self) -> Self::Out { <T as EquivalentDatatype>::equivalent_datatype() }
+}
+
+impl<T> Datatype for Box<[T]> where T: EquivalentDatatype {
+    type Out = <T as EquivalentDatatype>::Out;
+    fn datatype(&self) -> Self::Out { <T as EquivalentDatatype>::equivalent_datatype() }
+}
+
+impl Datatype for str {
+    type Out = SystemDatatype;
+    fn datatype(&self) -> Self::Out { SystemDatatype(ffi::RSMPI_CHAR) }
+}
+
+impl Datatype for String {
+    type Out = SystemDatatype;
+    fn datatype(&self) -> Self::Out { SystemDatatype(ffi::RSMPI_CHAR) }
+}
+
+// A `char` is sent as the 32 bit integer value of its Unicode scalar. Not every such integer is a
+// valid `char`, so `char`s can only be sent, not received.
+impl Datatype for char {
+    type Out = SystemDatatype;
+    fn datatype(&self) -> Self::Out { u32::equivalent_datatype() }
+}
+
+impl Datatype for [char] {
+    type Out = SystemDatatype;
+    fn datatype(&self) -> Self::Out { u32::equivalent_datatype() }
+}
+
+impl Datatype for Vec<char> {
+    type Out = SystemDatatype;
+    fn datatype(&self) -> Self::Out { u32::equivalent_datatype() }
+}
+
 /// A countable collection of things.
 pub trait Collection {
     /// How many things are in this connection.
@@ -281,6 +966,36 @@ impl<T> Collection for [T] where T: EquivalentDatatype {
     }
 }
 
+impl<T> Collection for Vec<T> where T: EquivalentDatatype {
+    fn count(&self) -> Count { self[..].count() }
+}
+
+impl<T> Collection for Box<[T]> where T: EquivalentDatatype {
+    fn count(&self) -> Count { self[..].count() }
+}
+
+impl Collection for str {
+    fn count(&self) -> Count { self.as_bytes().count() }
+}
+
+impl Collection for String {
+    fn count(&self) -> Count { self.as_bytes().count() }
+}
+
+impl Collection for char {
+    fn count(&self) -> Count { 1 }
+}
+
+impl Collection for [char] {
+    fn count(&self) -> Count {
+        self.len().value_as().expect("Length of slice cannot be expressed as an MPI Count.")
+    }
+}
+
+impl Collection for Vec<char> {
+    fn count(&self) -> Count { self[..].count() }
+}
+
 /// Provides a pointer to the starting address in memory.
 pub trait Pointer {
     /// A pointer to the starting address in memory
@@ -295,6 +1010,34 @@ impl<T> Pointer for [T] where T: EquivalentDatatype {
     unsafe fn pointer(&self) -> *const c_void { mem::transmute(self.as_ptr()) }
 }
 
+impl<T> Pointer for Vec<T> where T: EquivalentDatatype {
+    unsafe fn pointer(&self) -> *const c_void { self[..].pointer() }
+}
+
+impl<T> Pointer for Box<[T]> where T: EquivalentDatatype {
+    unsafe fn pointer(&self) -> *const c_void { self[..].pointer() }
+}
+
+impl Pointer for str {
+    unsafe fn pointer(&self) -> *const c_void { self.as_bytes().pointer() }
+}
+
+impl Pointer for String {
+    unsafe fn pointer(&self) -> *const c_void { self.as_bytes().pointer() }
+}
+
+impl Pointer for char {
+    unsafe fn pointer(&self) -> *const c_void { mem::transmute(self) }
+}
+
+impl Pointer for [char] {
+    unsafe fn pointer(&self) -> *const c_void { mem::transmute(self.as_ptr()) }
+}
+
+impl Pointer for Vec<char> {
+    unsafe fn pointer(&self) -> *const c_void { self[..].pointer() }
+}
+
 /// Provides a mutable pointer to the starting address in memory.
 pub trait PointerMut {
     /// A mutable pointer to the starting address in memory
@@ -309,17 +1052,34 @@ impl<T> PointerMut for [T] where T: EquivalentDatatype {
     unsafe fn pointer_mut(&mut self) -> *mut c_void { mem::transmute(self.as_mut_ptr()) }
 }
 
+impl<T> PointerMut for Vec<T> where T: EquivalentDatatype {
+    unsafe fn pointer_mut(&mut self) -> *mut c_void { self[..].pointer_mut() }
+}
+
+impl<T> PointerMut for Box<[T]> where T: EquivalentDatatype {
+    unsafe fn pointer_mut(&mut self) -> *mut c_void { self[..].pointer_mut() }
+}
+
 /// A buffer is a region in memory that starts at `pointer()` and contains `count()` copies of
 /// `datatype()`.
 pub trait Buffer: Pointer + Collection + Datatype { }
 impl<T> Buffer for T where T: EquivalentDatatype { }
 impl<T> Buffer for [T] where T: EquivalentDatatype { }
+impl<T> Buffer for Vec<T> where T: EquivalentDatatype { }
+impl<T> Buffer for Box<[T]> where T: EquivalentDatatype { }
+impl Buffer for str { }
+impl Buffer for String { }
+impl Buffer for char { }
+impl Buffer for [char] { }
+impl Buffer for Vec<char> { }
 
 /// A mutable buffer is a region in memory that starts at `pointer_mut()` and contains `count()`
 /// copies of `datatype()`.
 pub trait BufferMut: PointerMut + Collection + Datatype { }
 impl<T> BufferMut for T where T: EquivalentDatatype { }
 impl<T> BufferMut for [T] where T: EquivalentDatatype { }
+impl<T> BufferMut for Vec<T> where T: EquivalentDatatype { }
+impl<T> BufferMut for Box<[T]> where T: EquivalentDatatype { }
 
 /// A buffer with a user specified count and datatype
 ///
@@ -408,3 +1168,582 @@ where D: RawDatatype, B: PointerMut {
 
 impl<'d, 'b, D: 'd, B: 'b + ?Sized> BufferMut for MutView<'d, 'b, D, B>
 where D: RawDatatype, B: PointerMut { }
+
+/// The datatype of one instance of a strided `ndarray` array, built from its shape and strides as
+/// nested heterogeneous vectors, one per dimension, created on first use and cached for the
+/// remainder of the program.
+#[cfg(feature = "ndarray")]
+fn strided_array_datatype<A: EquivalentDatatype>(shape: &[usize], strides: &[isize])
+    -> &'static UserDatatype
+{
+    static INIT: Once = ONCE_INIT;
+    static mut DATATYPES: Option<Mutex<HashMap<(MPI_Datatype, Vec<usize>, Vec<isize>),
+        &'static UserDatatype>>> = None;
+
+    unsafe {
+        INIT.call_once(|| { DATATYPES = Some(Mutex::new(HashMap::new())); });
+        let mut datatypes = DATATYPES.as_ref().unwrap().lock()
+            .expect("Cache of array datatypes is poisoned.");
+        let key = (A::equivalent_datatype().as_raw(), shape.to_vec(), strides.to_vec());
+        *datatypes.entry(key).or_insert_with(|| {
+            let element_size = mem::size_of::<A>() as Address;
+            let mut datatype = UserDatatype::contiguous(1, A::equivalent_datatype())
+                .expect("Could not create datatype for array.");
+            for (&len, &stride) in shape.iter().zip(strides.iter()).rev() {
+                datatype = UserDatatype::heterogeneous_vector(len.value_as().expect(
+                    &format!("Length of array axis ({}) cannot be expressed as an MPI Count.",
+                        len)),
+                    1, stride as Address * element_size, &datatype)
+                    .expect("Could not create datatype for array.");
+            }
+            &*Box::into_raw(Box::new(datatype))
+        })
+    }
+}
+
+/// Arrays in standard layout are described as `len()` elements which have the same type signature
+/// as `A`. All other arrays are described as one instance of a datatype that covers the whole
+/// array, so they cannot be divided among several processes, e.g. as the receive buffer of
+/// `gather_into_root()` or the send buffer of `scatter_into_root()`.
+#[cfg(feature = "ndarray")]
+impl<A, S, D> Datatype for ::ndarray::ArrayBase<S, D>
+where A: EquivalentDatatype, S: ::ndarray::Data<Elem = A>, D: ::ndarray::Dimension {
+    type Out = &'static UserDatatype;
+    fn datatype(&self) -> Self::Out {
+        if self.is_standard_layout() {
+            equivalent_contiguous_datatype(1, A::equivalent_datatype())
+        } else {
+            strided_array_datatype::<A>(self.shape(), self.strides())
+        }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<A, S, D> Collection for ::ndarray::ArrayBase<S, D>
+where A: EquivalentDatatype, S: ::ndarray::Data<Elem = A>, D: ::ndarray::Dimension {
+    fn count(&self) -> Count {
+        if self.is_standard_layout() {
+            self.len().value_as().expect(&format!(
+                "Length of array ({}) cannot be expressed as an MPI Count.", self.len()))
+        } else {
+            1
+        }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<A, S, D> Pointer for ::ndarray::ArrayBase<S, D>
+where A: EquivalentDatatype, S: ::ndarray::Data<Elem = A>, D: ::ndarray::Dimension {
+    unsafe fn pointer(&self) -> *const c_void { self.as_ptr() as *const c_void }
+}
+
+#[cfg(feature = "ndarray")]
+impl<A, S, D> PointerMut for ::ndarray::ArrayBase<S, D>
+where A: EquivalentDatatype, S: ::ndarray::DataMut<Elem = A>, D: ::ndarray::Dimension {
+    unsafe fn pointer_mut(&mut self) -> *mut c_void { self.as_mut_ptr() as *mut c_void }
+}
+
+#[cfg(feature = "ndarray")]
+impl<A, S, D> Buffer for ::ndarray::ArrayBase<S, D>
+where A: EquivalentDatatype, S: ::ndarray::Data<Elem = A>, D: ::ndarray::Dimension { }
+
+#[cfg(feature = "ndarray")]
+impl<A, S, D> BufferMut for ::ndarray::ArrayBase<S, D>
+where A: EquivalentDatatype, S: ::ndarray::DataMut<Elem = A>, D: ::ndarray::Dimension { }
+
+/// Data packed into a contiguous sequence of bytes
+///
+/// The bytes are either in the native representation of the communicator they were packed with
+/// or in the portable `external32` representation. Natively packed data is sent and received as
+/// `MPI_PACKED`, data in the `external32` representation as `MPI_BYTE`.
+///
+/// Unpacking proceeds from the start of the buffer, each call to `unpack_from()` or
+/// `unpack_external32_from()` continues where the previous one left off.
+///
+/// # Examples
+/// See `examples/pack.rs`, `examples/pack_external32.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2, 4.3
+pub struct PackedBuffer {
+    bytes: Vec<u8>,
+    unpacked: usize,
+    external32: bool
+}
+
+impl PackedBuffer {
+    /// An empty buffer for data in the native representation
+    pub fn new() -> PackedBuffer {
+        PackedBuffer::from_bytes(Vec::new())
+    }
+
+    /// An empty buffer for data in the `external32` representation
+    pub fn external32() -> PackedBuffer {
+        PackedBuffer::external32_from_bytes(Vec::new())
+    }
+
+    /// A buffer holding `bytes` that were packed in the native representation
+    ///
+    /// This can also be used to receive packed data into `bytes`, in which case its length must
+    /// be large enough to hold the incoming message.
+    pub fn from_bytes(bytes: Vec<u8>) -> PackedBuffer {
+        PackedBuffer { bytes: bytes, unpacked: 0, external32: false }
+    }
+
+    /// A buffer holding `bytes` that were packed in the `external32` representation
+    ///
+    /// This can also be used to receive packed data into `bytes`, in which case its length must
+    /// be large enough to hold the incoming message.
+    pub fn external32_from_bytes(bytes: Vec<u8>) -> PackedBuffer {
+        PackedBuffer { bytes: bytes, unpacked: 0, external32: true }
+    }
+
+    /// Whether the buffer holds data in the `external32` representation
+    pub fn is_external32(&self) -> bool {
+        self.external32
+    }
+
+    /// The number of packed bytes
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Whether the buffer holds no packed bytes
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// The packed bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consumes the buffer and returns the packed bytes
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Makes room for `size` more bytes at the end of the buffer and returns a pointer to the
+    /// beginning of the buffer along with the new size of the buffer and the current position.
+    fn grow(&mut self, size: usize) -> (*mut c_void, usize, usize) {
+        let position = self.bytes.len();
+        self.bytes.resize(position + size, 0);
+        (self.bytes.as_mut_ptr() as *mut c_void, self.bytes.len(), position)
+    }
+}
+
+impl Default for PackedBuffer {
+    fn default() -> PackedBuffer {
+        PackedBuffer::new()
+    }
+}
+
+impl Datatype for PackedBuffer {
+    type Out = SystemDatatype;
+    fn datatype(&self) -> Self::Out {
+        SystemDatatype(if self.external32 { ffi::RSMPI_BYTE } else { ffi::RSMPI_PACKED })
+    }
+}
+
+impl Collection for PackedBuffer {
+    fn count(&self) -> Count {
+        self.bytes.len().value_as().expect(
+            "Length of packed buffer cannot be expressed as an MPI Count.")
+    }
+}
+
+impl Pointer for PackedBuffer {
+    unsafe fn pointer(&self) -> *const c_void { self.bytes.as_ptr() as *const c_void }
+}
+
+impl PointerMut for PackedBuffer {
+    unsafe fn pointer_mut(&mut self) -> *mut c_void { self.bytes.as_mut_ptr() as *mut c_void }
+}
+
+impl Buffer for PackedBuffer { }
+
+impl BufferMut for PackedBuffer { }
+
+/// Packing and unpacking of buffers in the native representation of a communicator
+///
+/// # Standard section(s)
+///
+/// 4.2
+pub trait Pack {
+    /// An upper bound on the number of bytes needed to pack `inbuf`
+    ///
+    /// # Examples
+    /// See `examples/pack.rs`
+    fn pack_size<Buf: ?Sized + Buffer>(&self, inbuf: &Buf) -> Result<Count, Error>;
+
+    /// Packs `inbuf` and appends it to the packed data in `outbuf`.
+    ///
+    /// # Examples
+    /// See `examples/pack.rs`
+    fn pack_into<Buf: ?Sized + Buffer>(&self, inbuf: &Buf, outbuf: &mut PackedBuffer) -> Result<(), Error>;
+
+    /// Unpacks the next `outbuf.count()` instances of `outbuf.datatype()` from `inbuf` into
+    /// `outbuf`.
+    ///
+    /// # Examples
+    /// See `examples/pack.rs`
+    fn unpack_from<Buf: ?Sized + BufferMut>(&self, inbuf: &mut PackedBuffer, outbuf: &mut Buf) -> Result<(), Error>;
+}
+
+impl<C: Communicator> Pack for C {
+    fn pack_size<Buf: ?Sized + Buffer>(&self, inbuf: &Buf) -> Result<Count, Error> {
+        let mut size: Count = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Pack_size(inbuf.count(), inbuf.datatype().as_raw(),
+                self.communicator().as_raw(), &mut size)
+        }));
+        Ok(size)
+    }
+
+    fn pack_into<Buf: ?Sized + Buffer>(&self, inbuf: &Buf, outbuf: &mut PackedBuffer) -> Result<(), Error> {
+        assert!(!outbuf.external32,
+            "Cannot pack natively into a buffer in the external32 representation.");
+        let size = try!(self.pack_size(inbuf));
+        let (pointer, outsize, start) = outbuf.grow(size.value_as().expect(
+            &format!("Pack size ({}) cannot be expressed as a usize.", size)));
+        let mut position: c_int = start.value_as().expect(
+            "Length of packed buffer cannot be expressed as an MPI Count.");
+        let result = check(unsafe {
+            ffi::MPI_Pack(inbuf.pointer(), inbuf.count(), inbuf.datatype().as_raw(), pointer,
+                outsize.value_as().expect(
+                    "Length of packed buffer cannot be expressed as an MPI Count."),
+                &mut position, self.communicator().as_raw())
+        });
+        // Drop the slack left by the upper bound, or everything on failure.
+        outbuf.bytes.truncate(if result.is_ok() { position as usize } else { start });
+        result
+    }
+
+    fn unpack_from<Buf: ?Sized + BufferMut>(&self, inbuf: &mut PackedBuffer, outbuf: &mut Buf) -> Result<(), Error> {
+        assert!(!inbuf.external32,
+            "Cannot unpack natively from a buffer in the external32 representation.");
+        let mut position: c_int = inbuf.unpacked.value_as().expect(
+            "Position in packed buffer cannot be expressed as an MPI Count.");
+        try!(check(unsafe {
+            ffi::MPI_Unpack(inbuf.bytes.as_ptr() as *const c_void, inbuf.count(), &mut position,
+                outbuf.pointer_mut(), outbuf.count(), outbuf.datatype().as_raw(),
+                self.communicator().as_raw())
+        }));
+        inbuf.unpacked = position as usize;
+        Ok(())
+    }
+}
+
+/// Name of the portable data representation understood by `MPI_Pack_external()`
+const EXTERNAL32: &'static [u8] = b"external32\0";
+
+/// The number of bytes needed to pack `inbuf` in the `external32` representation
+///
+/// # Examples
+/// See `examples/pack_external32.rs`
+///
+/// # Standard section(s)
+///
+/// 4.3
+pub fn external32_pack_size<Buf: ?Sized + Buffer>(inbuf: &Buf) -> Result<Address, Error> {
+    let mut size: Address = unsafe { mem::uninitialized() };
+    try!(check(unsafe {
+        ffi::MPI_Pack_external_size(EXTERNAL32.as_ptr() as *const c_char, inbuf.count(),
+            inbuf.datatype().as_raw(), &mut size)
+    }));
+    Ok(size)
+}
+
+/// Packs `inbuf` in the `external32` representation and appends it to the packed data in `outbuf`.
+///
+/// # Examples
+/// See `examples/pack_external32.rs`
+///
+/// # Standard section(s)
+///
+/// 4.3
+pub fn pack_external32_into<Buf: ?Sized + Buffer>(inbuf: &Buf, outbuf: &mut PackedBuffer) -> Result<(), Error> {
+    assert!(outbuf.external32,
+        "Cannot pack into a buffer in the native representation as external32.");
+    let size = try!(external32_pack_size(inbuf));
+    let (pointer, outsize, start) = outbuf.grow(size.value_as().expect(
+        &format!("Pack size ({}) cannot be expressed as a usize.", size)));
+    let mut position: Address = start.value_as().expect(
+        "Length of packed buffer cannot be expressed as an Address.");
+    let result = check(unsafe {
+        ffi::MPI_Pack_external(EXTERNAL32.as_ptr() as *const c_char, inbuf.pointer(),
+            inbuf.count(), inbuf.datatype().as_raw(), pointer,
+            outsize.value_as().expect("Length of packed buffer cannot be expressed as an Address."),
+            &mut position)
+    });
+    outbuf.bytes.truncate(if result.is_ok() { position as usize } else { start });
+    result
+}
+
+/// Unpacks the next `outbuf.count()` instances of `outbuf.datatype()` from `inbuf`, which holds
+/// data in the `external32` representation, into `outbuf`.
+///
+/// # Examples
+/// See `examples/pack_external32.rs`
+///
+/// # Standard section(s)
+///
+/// 4.3
+pub fn unpack_external32_from<Buf: ?Sized + BufferMut>(inbuf: &mut PackedBuffer, outbuf: &mut Buf) -> Result<(), Error> {
+    assert!(inbuf.external32,
+        "Cannot unpack from a buffer in the native representation as external32.");
+    let mut position: Address = inbuf.unpacked.value_as().expect(
+        "Position in packed buffer cannot be expressed as an Address.");
+    try!(check(unsafe {
+        ffi::MPI_Unpack_external(EXTERNAL32.as_ptr() as *const c_char,
+            inbuf.bytes.as_ptr() as *const c_void,
+            inbuf.bytes.len().value_as().expect(
+                "Length of packed buffer cannot be expressed as an Address."),
+            &mut position, outbuf.pointer_mut(), outbuf.count(), outbuf.datatype().as_raw())
+    }));
+    inbuf.unpacked = position as usize;
+    Ok(())
+}
+
+/// Something that is split into parts of varying size
+pub trait Partitioned {
+    /// The number of elements in each part
+    fn counts(&self) -> &[Count];
+    /// The displacement of each part from the start of the whole in multiples of the extent of
+    /// the datatype
+    fn displs(&self) -> &[Count];
+}
+
+/// A buffer that is split into parts of varying size
+pub trait PartitionedBuffer: Partitioned + Pointer + Datatype { }
+
+/// A mutable buffer that is split into parts of varying size
+pub trait PartitionedBufferMut: Partitioned + PointerMut + Datatype { }
+
+/// Checks that all parts given by `counts` and `displs` lie within a buffer of `count` elements.
+fn assert_partition(count: Count, counts: &[Count], displs: &[Count]) {
+    assert_eq!(counts.len(), displs.len());
+    assert!(counts.iter().zip(displs.iter()).all(|(&c, &d)| c >= 0 && d >= 0 && c + d <= count),
+        "Partition does not fit into buffer of {} elements.", count);
+}
+
+/// A buffer split into parts of varying size
+///
+/// Part `i` consists of `counts[i]` elements starting `displs[i]` elements into the buffer.
+///
+/// # Examples
+///
+/// See `examples/gather_varcount.rs`
+pub struct Partition<'b, B: 'b + ?Sized> {
+    buffer: &'b B,
+    counts: &'b [Count],
+    displs: &'b [Count]
+}
+
+impl<'b, B: 'b + ?Sized> Partition<'b, B> where B: Buffer {
+    /// Partition `buffer` into parts of `counts[i]` elements starting at `displs[i]`.
+    ///
+    /// # Panics
+    ///
+    /// If `counts` and `displs` differ in length or any part does not lie within `buffer`
+    pub fn new(buffer: &'b B, counts: &'b [Count], displs: &'b [Count]) -> Partition<'b, B> {
+        assert_partition(buffer.count(), counts, displs);
+        Partition { buffer: buffer, counts: counts, displs: displs }
+    }
+}
+
+impl<'b, B: 'b + ?Sized> Partitioned for Partition<'b, B> {
+    fn counts(&self) -> &[Count] { self.counts }
+    fn displs(&self) -> &[Count] { self.displs }
+}
+
+impl<'b, B: 'b + ?Sized> Datatype for Partition<'b, B> where B: Buffer {
+    type Out = <B as Datatype>::Out;
+    fn datatype(&self) -> Self::Out { self.buffer.datatype() }
+}
+
+impl<'b, B: 'b + ?Sized> Pointer for Partition<'b, B> where B: Buffer {
+    unsafe fn pointer(&self) -> *const c_void { self.buffer.pointer() }
+}
+
+impl<'b, B: 'b + ?Sized> PartitionedBuffer for Partition<'b, B> where B: Buffer { }
+
+/// A mutable buffer split into parts of varying size
+///
+/// Part `i` consists of `counts[i]` elements starting `displs[i]` elements into the buffer.
+///
+/// # Examples
+///
+/// See `examples/gather_varcount.rs`
+pub struct PartitionMut<'b, B: 'b + ?Sized> {
+    buffer: &'b mut B,
+    counts: &'b [Count],
+    displs: &'b [Count]
+}
+
+impl<'b, B: 'b + ?Sized> PartitionMut<'b, B> where B: BufferMut {
+    /// Partition `buffer` into parts of `counts[i]` elements starting at `displs[i]`.
+    ///
+    /// # Panics
+    ///
+    /// If `counts` and `displs` differ in length or any part does not lie within `buffer`
+    pub fn new(buffer: &'b mut B, counts: &'b [Count], displs: &'b [Count]) -> PartitionMut<'b, B> {
+        assert_partition(buffer.count(), counts, displs);
+        PartitionMut { buffer: buffer, counts: counts, displs: displs }
+    }
+}
+
+impl<'b, B: 'b + ?Sized> Partitioned for PartitionMut<'b, B> {
+    fn counts(&self) -> &[Count] { self.counts }
+    fn displs(&self) -> &[Count] { self.displs }
+}
+
+impl<'b, B: 'b + ?Sized> Datatype for PartitionMut<'b, B> where B: BufferMut {
+    type Out = <B as Datatype>::Out;
+    fn datatype(&self) -> Self::Out { self.buffer.datatype() }
+}
+
+impl<'b, B: 'b + ?Sized> PointerMut for PartitionMut<'b, B> where B: BufferMut {
+    unsafe fn pointer_mut(&mut self) -> *mut c_void { self.buffer.pointer_mut() }
+}
+
+impl<'b, B: 'b + ?Sized> PartitionedBufferMut for PartitionMut<'b, B> where B: BufferMut { }
+
+/// Splits the `(count, displacement, datatype)` triples of a `DatatypePartition` into the
+/// separate arrays expected by MPI.
+fn split_datatype_parts<D: RawDatatype>(parts: &[(Count, Address, D)])
+    -> (Vec<Count>, Vec<Address>, Vec<MPI_Datatype>)
+{
+    let counts = parts.iter().map(|&(c, _, _)| c).collect();
+    let displs = parts.iter().map(|&(_, d, _)| d).collect();
+    let datatypes = parts.iter().map(|&(_, _, ref t)| unsafe { t.as_raw() }).collect();
+    (counts, displs, datatypes)
+}
+
+/// Narrows byte displacements to `Count`, the type used by `MPI_Alltoallw()`.
+fn narrow_displs(displs: &[Address]) -> Option<Vec<Count>> {
+    displs.iter().map(|&d| if d >= 0 && d <= Count::max_value() as Address {
+        Some(d as Count)
+    } else {
+        None
+    }).collect()
+}
+
+/// A buffer split into parts that each have their own count, displacement and datatype
+///
+/// Part `i` consists of `count` instances of `datatype` starting `displacement` bytes into the
+/// buffer.
+///
+/// # Safety
+///
+/// Like `View`s, datatype partitions can be used to instruct the underlying MPI library to
+/// rummage around at arbitrary locations in memory, so their constructors are marked `unsafe`.
+///
+/// # Examples
+///
+/// See `examples/all_to_all_w.rs`
+pub struct DatatypePartition<'d, 'b, D: 'd, B: 'b + ?Sized>
+where D: RawDatatype, B: Pointer {
+    buffer: &'b B,
+    counts: Vec<Count>,
+    displs: Vec<Address>,
+    narrow_displs: Option<Vec<Count>>,
+    datatypes: Vec<MPI_Datatype>,
+    phantom: PhantomData<&'d D>
+}
+
+impl<'d, 'b, D: 'd, B: 'b + ?Sized> DatatypePartition<'d, 'b, D, B>
+where D: RawDatatype, B: Pointer {
+    /// Partition `buffer` into parts described by `(count, displacement, datatype)` triples.
+    pub unsafe fn new(buffer: &'b B, parts: &'d [(Count, Address, D)]) -> DatatypePartition<'d, 'b, D, B> {
+        let (counts, displs, datatypes) = split_datatype_parts(parts);
+        DatatypePartition {
+            buffer: buffer,
+            counts: counts,
+            narrow_displs: narrow_displs(&displs[..]),
+            displs: displs,
+            datatypes: datatypes,
+            phantom: PhantomData
+        }
+    }
+
+    /// The number of elements in each part
+    pub fn counts(&self) -> &[Count] { &self.counts[..] }
+
+    /// The displacement of each part from the start of the buffer in bytes
+    pub fn displs(&self) -> &[Address] { &self.displs[..] }
+
+    /// The displacement of each part from the start of the buffer in bytes as a `Count`, or
+    /// `None` if any of them does not fit into a `Count`
+    pub fn narrow_displs(&self) -> Option<&[Count]> {
+        self.narrow_displs.as_ref().map(|d| &d[..])
+    }
+
+    /// The raw datatype of each part
+    pub fn datatypes(&self) -> &[MPI_Datatype] { &self.datatypes[..] }
+}
+
+impl<'d, 'b, D: 'd, B: 'b + ?Sized> Pointer for DatatypePartition<'d, 'b, D, B>
+where D: RawDatatype, B: Pointer {
+    unsafe fn pointer(&self) -> *const c_void { self.buffer.pointer() }
+}
+
+/// A mutable buffer split into parts that each have their own count, displacement and datatype
+///
+/// Part `i` consists of `count` instances of `datatype` starting `displacement` bytes into the
+/// buffer.
+///
+/// # Safety
+///
+/// Like `View`s, datatype partitions can be used to instruct the underlying MPI library to
+/// rummage around at arbitrary locations in memory, so their constructors are marked `unsafe`.
+///
+/// # Examples
+///
+/// See `examples/all_to_all_w.rs`
+pub struct DatatypePartitionMut<'d, 'b, D: 'd, B: 'b + ?Sized>
+where D: RawDatatype, B: PointerMut {
+    buffer: &'b mut B,
+    counts: Vec<Count>,
+    displs: Vec<Address>,
+    narrow_displs: Option<Vec<Count>>,
+    datatypes: Vec<MPI_Datatype>,
+    phantom: PhantomData<&'d D>
+}
+
+impl<'d, 'b, D: 'd, B: 'b + ?Sized> DatatypePartitionMut<'d, 'b, D, B>
+where D: RawDatatype, B: PointerMut {
+    /// Partition `buffer` into parts described by `(count, displacement, datatype)` triples.
+    pub unsafe fn new(buffer: &'b mut B, parts: &'d [(Count, Address, D)]) -> DatatypePartitionMut<'d, 'b, D, B> {
+        let (counts, displs, datatypes) = split_datatype_parts(parts);
+        DatatypePartitionMut {
+            buffer: buffer,
+            counts: counts,
+            narrow_displs: narrow_displs(&displs[..]),
+            displs: displs,
+            datatypes: datatypes,
+            phantom: PhantomData
+        }
+    }
+
+    /// The number of elements in each part
+    pub fn counts(&self) -> &[Count] { &self.counts[..] }
+
+    /// The displacement of each part from the start of the buffer in bytes
+    pub fn displs(&self) -> &[Address] { &self.displs[..] }
+
+    /// The displacement of each part from the start of the buffer in bytes as a `Count`, or
+    /// `None` if any of them does not fit into a `Count`
+    pub fn narrow_displs(&self) -> Option<&[Count]> {
+        self.narrow_displs.as_ref().map(|d| &d[..])
+    }
+
+    /// The raw datatype of each part
+    pub fn datatypes(&self) -> &[MPI_Datatype] { &self.datatypes[..] }
+}
+
+impl<'d, 'b, D: 'd, B: 'b + ?Sized> PointerMut for DatatypePartitionMut<'d, 'b, D, B>
+where D: RawDatatype, B: PointerMut {
+    unsafe fn pointer_mut(&mut self) -> *mut c_void { self.buffer.pointer_mut() }
+}
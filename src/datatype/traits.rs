@@ -1,3 +1,3 @@
 //! Datatype traits
-pub use super::{EquivalentDatatype, Datatype, Collection, Pointer, PointerMut,
-    Buffer, BufferMut};
+pub use super::{EquivalentDatatype, DatatypeExt, Datatype, Collection, Pointer, PointerMut,
+    Buffer, BufferMut, Pack, Partitioned, PartitionedBuffer, PartitionedBufferMut};
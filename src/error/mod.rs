@@ -0,0 +1,280 @@
+//! Error handling
+//!
+//! By default, an error in an MPI function aborts the entire parallel computation. Upon
+//! initialization, this library installs the predefined `MPI_ERRORS_RETURN` error handler on the
+//! world communicator and `MPI_COMM_SELF` instead, so that errors are reported back to the caller
+//! as an `Error` wrapped in a `Result`. Communicators created from these inherit the error
+//! handler.
+//!
+//! A different `ErrorHandler` can be attached to a communicator, either one of the predefined
+//! handlers or a user-defined handler that runs a Rust closure whenever an error is raised on the
+//! communicator.
+//!
+//! # Unfinished features
+//!
+//! - **8.3**: Error handling, `MPI_Comm_get_errhandler()`
+//!   - **8.3.2**: Error handlers for windows, `MPI_Win_create_errhandler()`,
+//!     `MPI_Win_set_errhandler()`, `MPI_Win_get_errhandler()`
+//!   - **8.3.3**: Error handlers for files, `MPI_File_create_errhandler()`,
+//!     `MPI_File_set_errhandler()`, `MPI_File_get_errhandler()`
+//! - **8.5**: Error classes, error codes, and error handlers, `MPI_Add_error_class()`,
+//! `MPI_Add_error_code()`, `MPI_Add_error_string()`
+
+use std::{error, fmt, mem};
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex, Once, ONCE_INIT};
+
+use libc::{c_char, c_int};
+
+use conv::ConvUtil;
+
+use super::check;
+use ffi;
+use ffi::{MPI_Comm, MPI_Errhandler};
+
+use raw::traits::*;
+use topology::Communicator;
+
+pub mod traits;
+
+/// An error reported by the MPI library
+///
+/// Wraps the error code returned by an MPI function. The error code can be mapped to an error
+/// class and a descriptive string.
+///
+/// # Standard section(s)
+///
+/// 8.4
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Error(c_int);
+
+impl Error {
+    /// Construct an `Error` from the raw error code returned by an MPI function
+    pub fn from_raw(code: c_int) -> Error {
+        Error(code)
+    }
+
+    /// The raw error code
+    pub fn code(&self) -> c_int {
+        self.0
+    }
+
+    /// The error class this error code belongs to
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.4
+    pub fn class(&self) -> c_int {
+        let mut class: c_int = unsafe { mem::uninitialized() };
+        unsafe { ffi::MPI_Error_class(self.0, &mut class); }
+        class
+    }
+
+    /// A description of the error as provided by the MPI library
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.4
+    pub fn message(&self) -> String {
+        let bufsize = ffi::RSMPI_MAX_ERROR_STRING.value_as().expect(
+            &format!("MPI_MAX_ERROR_STRING ({}) cannot be expressed as a usize.",
+                ffi::RSMPI_MAX_ERROR_STRING)
+            );
+        let mut buf = vec![0u8; bufsize];
+        let mut len: c_int = 0;
+
+        unsafe { ffi::MPI_Error_string(self.0, buf.as_mut_ptr() as *mut c_char, &mut len); }
+        buf.truncate(len.value_as().expect(
+            &format!("Length of error string ({}) cannot be expressed as a usize.", len)));
+        String::from_utf8_lossy(&buf).into_owned()
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Error {{ code: {}, class: {}, message: {:?} }}",
+               self.code(), self.class(), self.message())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        "MPI error"
+    }
+}
+
+/// The type of the closure run by a user-defined `ErrorHandler`
+type HandlerFn = Fn(&ErrorCommunicator, Error) + Send + Sync;
+
+/// Handles errors raised on a communicator
+///
+/// Either one of the predefined error handlers or a user-defined handler wrapping a Rust closure.
+///
+/// # Examples
+///
+/// See `examples/error_handler.rs`
+///
+/// # Standard section(s)
+///
+/// 8.3
+pub struct ErrorHandler {
+    raw: MPI_Errhandler,
+    handler: Option<Arc<Box<HandlerFn>>>
+}
+
+impl ErrorHandler {
+    /// The predefined handler that aborts all processes of the communicator
+    ///
+    /// This is the default error handler of MPI, but not of this library.
+    pub fn errors_are_fatal() -> ErrorHandler {
+        ErrorHandler { raw: ffi::RSMPI_ERRORS_ARE_FATAL, handler: None }
+    }
+
+    /// The predefined handler that returns the error code to the caller
+    ///
+    /// This error handler is installed on the world communicator upon initialization.
+    pub fn errors_return() -> ErrorHandler {
+        ErrorHandler { raw: ffi::RSMPI_ERRORS_RETURN, handler: None }
+    }
+
+    /// Create a user-defined error handler.
+    ///
+    /// Whenever an error is raised on a communicator this handler is attached to, `f` is called
+    /// with the communicator and the error. If `f` returns, the MPI function that raised the
+    /// error returns the error to its caller. MPI may call `f` from any thread, so it has to be
+    /// `Send` and `Sync`. A panic in `f` aborts the program via `MPI_Abort()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.1
+    pub fn new<F>(f: F) -> Result<ErrorHandler, Error>
+        where F: Fn(&ErrorCommunicator, Error) + Send + Sync + 'static
+    {
+        let mut raw: MPI_Errhandler = unsafe { mem::uninitialized() };
+        // The handler function is a trampoline in the C shim which calls
+        // `rsmpi_comm_error_handler()`.
+        try!(check(unsafe { ffi::RSMPI_Comm_create_errhandler(&mut raw) }));
+        let f: Arc<Box<HandlerFn>> = Arc::new(Box::new(f));
+        handlers().lock().expect("Table of error handlers is poisoned.").insert(raw, f.clone());
+        Ok(ErrorHandler { raw: raw, handler: Some(f) })
+    }
+}
+
+impl AsRaw for ErrorHandler {
+    type Raw = MPI_Errhandler;
+    unsafe fn as_raw(&self) -> Self::Raw { self.raw }
+}
+
+impl Drop for ErrorHandler {
+    fn drop(&mut self) {
+        if self.handler.is_some() {
+            // Communicators the handler is attached to keep it alive.
+            unsafe { ffi::MPI_Errhandler_free(&mut self.raw); }
+            assert_eq!(self.raw, ffi::RSMPI_ERRHANDLER_NULL);
+        }
+    }
+}
+
+/// The communicator on which an error was raised
+///
+/// Passed to the closure of a user-defined `ErrorHandler`.
+pub struct ErrorCommunicator(MPI_Comm);
+
+impl AsRaw for ErrorCommunicator {
+    type Raw = MPI_Comm;
+    unsafe fn as_raw(&self) -> Self::Raw { self.0 }
+}
+
+impl RawCommunicator for ErrorCommunicator { }
+
+impl Communicator for ErrorCommunicator {
+    type Out = ErrorCommunicator;
+    fn communicator(&self) -> &Self::Out {
+        self
+    }
+}
+
+/// Error handling on communicators
+///
+/// # Standard section(s)
+///
+/// 8.3.1
+pub trait CommunicatorErrorHandling: RawCommunicator {
+    /// Attach an error handler to this communicator.
+    ///
+    /// Communicators created from this communicator inherit the error handler.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.1
+    fn set_error_handler(&self, handler: &ErrorHandler) -> Result<(), Error> {
+        check(unsafe { ffi::MPI_Comm_set_errhandler(self.as_raw(), handler.as_raw()) })
+    }
+
+    /// Invoke the error handler attached to this communicator with `error`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handler.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.4
+    fn call_error_handler(&self, error: Error) -> Result<(), Error> {
+        check(unsafe { ffi::MPI_Comm_call_errhandler(self.as_raw(), error.code()) })
+    }
+}
+
+impl<C: RawCommunicator> CommunicatorErrorHandling for C { }
+
+static mut HANDLERS: Option<Mutex<HashMap<MPI_Errhandler, Arc<Box<HandlerFn>>>>> = None;
+static HANDLERS_INIT: Once = ONCE_INIT;
+
+/// The closures of user-defined error handlers, keyed by the handle of the error handler
+///
+/// Communicators keep an error handler alive after its `ErrorHandler` has been dropped and share
+/// it with the communicators created from them, so a closure is only replaced once MPI hands out
+/// its handle for a new error handler.
+fn handlers() -> &'static Mutex<HashMap<MPI_Errhandler, Arc<Box<HandlerFn>>>> {
+    unsafe {
+        HANDLERS_INIT.call_once(|| { HANDLERS = Some(Mutex::new(HashMap::new())); });
+        HANDLERS.as_ref().unwrap()
+    }
+}
+
+/// Calls the closure of the user-defined error handler attached to `comm`
+///
+/// Called by the variadic error handler function of the C shim.
+#[no_mangle]
+unsafe extern "C" fn rsmpi_comm_error_handler(comm: *mut MPI_Comm, code: *mut c_int) {
+    let mut raw: MPI_Errhandler = mem::uninitialized();
+    if ffi::MPI_Comm_get_errhandler(*comm, &mut raw) != ffi::RSMPI_SUCCESS {
+        ffi::MPI_Abort(*comm, *code);
+        return;
+    }
+    let handler = handlers().lock().ok().and_then(|handlers| handlers.get(&raw).cloned());
+    ffi::MPI_Errhandler_free(&mut raw);
+
+    let completed = match handler {
+        // Unwinding across the `extern "C"` function is undefined behavior.
+        Some(f) => panic::catch_unwind(AssertUnwindSafe(|| {
+            f(&ErrorCommunicator(*comm), Error::from_raw(*code))
+        })).is_ok(),
+        // The error handler was not created by `ErrorHandler::new()`.
+        None => false
+    };
+    if !completed {
+        ffi::MPI_Abort(*comm, *code);
+    }
+}
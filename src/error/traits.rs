@@ -0,0 +1,2 @@
+//! Error handling traits
+pub use super::CommunicatorErrorHandling;
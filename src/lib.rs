@@ -41,10 +41,10 @@
 //!     match rank {
 //!         0 => {
 //!             let msg = vec![4.0f64, 8.0, 15.0];
-//!             world.process_at_rank(rank + 1).send(&msg[..]);
+//!             world.process_at_rank(rank + 1).send(&msg[..]).unwrap();
 //!         }
 //!         1 => {
-//!             let (msg, status) = world.receive_vec::<f64>();
+//!             let (msg, status) = world.receive_vec::<f64>().unwrap();
 //!             println!("Process {} got message {:?}.\nStatus is: {:?}", rank, msg, status);
 //!         }
 //!         _ => unreachable!()
@@ -60,24 +60,41 @@
 //!
 //! - **Groups, Contexts, Communicators**:
 //!   - Group and (Intra-)Communicator management from section 6 is mostly complete.
-//!   - no Inter-Communicators
-//!   - noprocess topologies
+//!   - Inter-Communicators
+//!   - Cartesian, graph and distributed graph process topologies
 //! - **Point to point communication**:
 //!   - standard, buffered, synchronous and ready mode send in blocking and non-blocking variants
 //!   - receive in blocking and non-blocking variants
 //!   - send-receive
 //!   - probe
 //!   - matched probe/receive
+//!   - completion of collections of requests (wait/test all, any or some)
+//!   - request scopes that complete all non-blocking operations before their buffers are released
+//!   - persistent requests for sending and receiving
+//!   - partitioned sending and receiving with MPI 4.0 libraries
+//!   - sending and receiving any `serde` serializable value with the `serde` feature
 //! - **Collective communication**:
-//!   - barrier in blocking and non-blocking variants
+//!   - barrier
 //!   - broadcast
 //!   - (all) gather
 //!   - scatter
 //!   - all to all
-//!   - no varying counts operations
-//!   - no reductions/scans
+//!   - neighborhood collectives on process topologies
+//!   - varying counts operations
+//!   - reductions/scans and reduce-scatter
+//!   - non-blocking variants of all of the above
+//!   - persistent barrier, broadcast, gather, scatter, all gather, all to all, reductions,
+//!     reduce-scatter and scans with MPI 4.0 libraries
+//!   - user-defined operations for reductions/scans from Rust closures
 //! - **Datatypes**: Bridging between Rust types and MPI basic types as well as custom MPI datatypes
-//! which can act as views into buffers.
+//! which can act as views into buffers. Besides numbers, `bool`, arrays and, with the
+//! `complex` feature, `num_complex::Complex` have equivalent datatypes, `char`s can be sent as
+//! their `u32` value. Slices, `Vec`s, strings and, with the `ndarray` feature, possibly strided
+//! `ndarray` arrays can be used as buffers.
+//! `EquivalentDatatype` can be derived for structs with the `derive` feature. Buffers can be
+//! packed in the native or the portable `external32` representation.
+//! - **Error handling**: Errors are returned as `Result`s instead of aborting the computation.
+//! User-defined error handlers can be attached to communicators.
 //!
 //! Not supported (yet):
 //!
@@ -88,6 +105,12 @@
 //!
 //! The sub-modules contain a more detailed description of which features are and are not
 //! supported.
+//!
+//! # Error handling
+//!
+//! Functions that communicate or create new MPI objects return a `Result` which carries an `Error`
+//! if the underlying MPI function reported one. Purely local queries on objects that are valid by
+//! construction (e.g. the size of a communicator) cannot fail and return their value directly.
 
 use std::mem;
 use std::string::{FromUtf8Error};
@@ -100,6 +123,25 @@ extern crate conv;
 
 use conv::ConvUtil;
 
+#[cfg(feature = "derive")]
+#[allow(unused_imports)]
+#[macro_use]
+extern crate mpi_derive;
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub use mpi_derive::*;
+
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "serde")]
+extern crate bincode;
+
+#[cfg(feature = "complex")]
+extern crate num_complex;
+
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
+
 /// The raw C language MPI API
 ///
 /// Documented in the [Message Passing Interface specification][spec]
@@ -110,6 +152,7 @@ pub mod ffi;
 
 pub mod collective;
 pub mod datatype;
+pub mod error;
 pub mod point_to_point;
 pub mod raw;
 pub mod request;
@@ -118,11 +161,11 @@ pub mod traits;
 
 #[doc(inline)]
 pub use topology::{initialize, initialize_with_threading, Threading};
+#[doc(inline)]
+pub use error::Error;
 
 use ffi::MPI_Aint;
 
-/// Encodes error values returned by MPI functions.
-pub type Error = c_int;
 /// Encodes number of values in multi-value messages.
 pub type Count = c_int;
 /// Can be used to tag messages on the sender side and match on the receiver side.
@@ -160,3 +203,22 @@ pub fn get_library_version() -> Result<String, FromUtf8Error> {
         &format!("Length of library version string ({}) cannot be expressed as a usize.", len)));
     String::from_utf8(buf)
 }
+
+/// Turns the return code of an MPI function into a `Result`.
+fn check(code: c_int) -> Result<(), Error> {
+    if code == ffi::RSMPI_SUCCESS {
+        Ok(())
+    } else {
+        Err(Error::from_raw(code))
+    }
+}
+
+/// Fails with `MPI_ERR_UNSUPPORTED_OPERATION` for functionality introduced in MPI 4.0 if the
+/// library implements an older version of the standard.
+fn check_version_4() -> Result<(), Error> {
+    if get_version().0 >= 4 {
+        Ok(())
+    } else {
+        Err(Error::from_raw(ffi::RSMPI_ERR_UNSUPPORTED_OPERATION))
+    }
+}
@@ -4,27 +4,42 @@
 //! `Destination` trait. Communication operations are implemented as traits that have blanket
 //! implementations for these two traits.
 //!
+//! Partitioned communication requires a library that implements version 4.0 of the MPI standard
+//! (see `get_version()`), with older libraries it returns an `Error` of class
+//! `MPI_ERR_UNSUPPORTED_OPERATION`.
+//!
 //! # Unfinished features
 //!
 //! - **3.2.6**: `MPI_STATUS_IGNORE`
 //! - **3.6**: Buffer usage, `MPI_Buffer_attach()`, `MPI_Buffer_detach()`
-//! - **3.9**: Persistent requests, `MPI_Send_init()`, `MPI_Bsend_init()`, `MPI_Ssend_init()`,
-//! `MPI_Rsend_init()`, `MPI_Recv_init()`, `MPI_Start()`, `MPI_Startall()`
 
 use std::{mem, fmt};
+#[cfg(feature = "serde")]
+use std::error;
+use std::string::FromUtf8Error;
 
 use libc::c_int;
 
 use conv::ConvUtil;
 
+#[cfg(feature = "serde")]
+use serde::Serialize;
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "serde")]
+use bincode;
+
 use super::{Error, Count, Tag};
+use super::{check, check_version_4};
 
 use ffi;
 use ffi::{MPI_Status, MPI_Message, MPI_Request};
 
+use datatype::MutView;
 use datatype::traits::*;
 use raw::traits::*;
-use request::{ReadRequest, WriteRequest};
+use request::{ReadRequest, WriteRequest, PersistentRequest, PartitionedSendRequest,
+    PartitionedReceiveRequest, Scope};
 use request::traits::*;
 use topology::{SystemCommunicator, UserCommunicator, Rank, Identifier};
 use topology::traits::*;
@@ -105,7 +120,7 @@ impl<'a, C: 'a + RawCommunicator> Destination for Identifier<'a, C> {
 /// let world = universe.world();
 ///
 /// let v = vec![ 1.0f64, 2.0, 3.0 ];
-/// world.process_at_rank(1).send(&v[..]);
+/// world.process_at_rank(1).send(&v[..]).unwrap();
 /// ```
 ///
 /// # Standard section(s)
@@ -113,23 +128,23 @@ impl<'a, C: 'a + RawCommunicator> Destination for Identifier<'a, C> {
 /// 3.2.1
 pub trait Send {
     /// Send the contents of a `Buffer` to the `Destination` `&self` and tag it.
-    fn send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag);
+    fn send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag) -> Result<(), Error>;
 
     /// Send the contents of a `Buffer` to the `Destination` `&self`.
     ///
     /// # Examples
     /// See `examples/send_receive.rs`
-    fn send<Buf: Buffer + ?Sized>(&self, buf: &Buf) {
+    fn send<Buf: Buffer + ?Sized>(&self, buf: &Buf) -> Result<(), Error> {
         self.send_with_tag(buf, Tag::default())
     }
 }
 
 impl<Dest: Destination> Send for Dest {
-    fn send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag) {
-        unsafe {
+    fn send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag) -> Result<(), Error> {
+        check(unsafe {
             ffi::MPI_Send(buf.pointer(), buf.count(), buf.datatype().as_raw(),
-                self.destination_rank(), tag, self.communicator().as_raw());
-        }
+                self.destination_rank(), tag, self.communicator().as_raw())
+        })
     }
 }
 
@@ -140,20 +155,20 @@ impl<Dest: Destination> Send for Dest {
 /// 3.4
 pub trait BufferedSend {
     /// Send the contents of a `Buffer` to the `Destination` `&self` and tag it.
-    fn buffered_send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag);
+    fn buffered_send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag) -> Result<(), Error>;
 
     /// Send the contents of a `Buffer` to the `Destination` `&self`.
-    fn buffered_send<Buf: Buffer + ?Sized>(&self, buf: &Buf) {
+    fn buffered_send<Buf: Buffer + ?Sized>(&self, buf: &Buf) -> Result<(), Error> {
         self.buffered_send_with_tag(buf, Tag::default())
     }
 }
 
 impl<Dest: Destination> BufferedSend for Dest {
-    fn buffered_send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag) {
-        unsafe {
+    fn buffered_send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag) -> Result<(), Error> {
+        check(unsafe {
             ffi::MPI_Bsend(buf.pointer(), buf.count(), buf.datatype().as_raw(),
-                self.destination_rank(), tag, self.communicator().as_raw());
-        }
+                self.destination_rank(), tag, self.communicator().as_raw())
+        })
     }
 }
 
@@ -166,22 +181,22 @@ pub trait SynchronousSend {
     /// Send the contents of a `Buffer` to the `Destination` `&self` and tag it.
     ///
     /// Completes only once the matching receive operation has started.
-    fn synchronous_send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag);
+    fn synchronous_send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag) -> Result<(), Error>;
 
     /// Send the contents of a `Buffer` to the `Destination` `&self`.
     ///
     /// Completes only once the matching receive operation has started.
-    fn synchronous_send<Buf: Buffer + ?Sized>(&self, buf: &Buf) {
+    fn synchronous_send<Buf: Buffer + ?Sized>(&self, buf: &Buf) -> Result<(), Error> {
         self.synchronous_send_with_tag(buf, Tag::default())
     }
 }
 
 impl<Dest: Destination> SynchronousSend for Dest {
-    fn synchronous_send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag) {
-        unsafe {
+    fn synchronous_send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag) -> Result<(), Error> {
+        check(unsafe {
             ffi::MPI_Ssend(buf.pointer(), buf.count(), buf.datatype().as_raw(),
-                self.destination_rank(), tag, self.communicator().as_raw());
-        }
+                self.destination_rank(), tag, self.communicator().as_raw())
+        })
     }
 }
 
@@ -194,22 +209,22 @@ pub trait ReadySend {
     /// Send the contents of a `Buffer` to the `Destination` `&self` and tag it.
     ///
     /// Fails if the matching receive operation has not been posted.
-    fn ready_send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag);
+    fn ready_send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag) -> Result<(), Error>;
 
     /// Send the contents of a `Buffer` to the `Destination` `&self`.
     ///
     /// Fails if the matching receive operation has not been posted.
-    fn ready_send<Buf: Buffer + ?Sized>(&self, buf: &Buf) {
+    fn ready_send<Buf: Buffer + ?Sized>(&self, buf: &Buf) -> Result<(), Error> {
         self.ready_send_with_tag(buf, Tag::default())
     }
 }
 
 impl<Dest: Destination> ReadySend for Dest {
-    fn ready_send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag) {
-        unsafe {
+    fn ready_send_with_tag<Buf: Buffer + ?Sized>(&self, buf: &Buf, tag: Tag) -> Result<(), Error> {
+        check(unsafe {
             ffi::MPI_Rsend(buf.pointer(), buf.count(), buf.datatype().as_raw(),
-                self.destination_rank(), tag, self.communicator().as_raw());
-        }
+                self.destination_rank(), tag, self.communicator().as_raw())
+        })
     }
 }
 
@@ -238,7 +253,7 @@ impl Status {
 
     /// An error code
     pub fn error(&self) -> Error {
-        self.0.MPI_ERROR
+        Error::from_raw(self.0.MPI_ERROR)
     }
 
     /// Number of instances of the type contained in the message
@@ -252,7 +267,7 @@ impl Status {
 impl fmt::Debug for Status {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         write!(f, "Status {{ source_rank: {}, tag: {}, error: {} }}",
-               self.source_rank(), self.tag(), self.error())
+               self.source_rank(), self.tag(), self.error().code())
     }
 }
 
@@ -267,22 +282,22 @@ impl fmt::Debug for Status {
 /// 3.8.1
 pub trait Probe {
     /// Probe `Source` `&self` for incoming messages with a certain tag.
-    fn probe_with_tag(&self, tag: Tag) -> Status;
+    fn probe_with_tag(&self, tag: Tag) -> Result<Status, Error>;
 
     /// Probe `Source` `&self` for incoming messages with any tag.
-    fn probe(&self) -> Status {
+    fn probe(&self) -> Result<Status, Error> {
         self.probe_with_tag(ffi::RSMPI_ANY_TAG)
     }
 }
 
 impl<Src: Source> Probe for Src {
-    fn probe_with_tag(&self, tag: Tag) -> Status {
+    fn probe_with_tag(&self, tag: Tag) -> Result<Status, Error> {
         let mut status: MPI_Status = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Probe(self.source_rank(), tag, self.communicator().as_raw(),
-                &mut status);
-        };
-        Status(status)
+                &mut status)
+        }));
+        Ok(Status(status))
     }
 }
 
@@ -330,23 +345,23 @@ impl Drop for Message {
 /// 3.8.2
 pub trait MatchedProbe {
     /// Probe `Source` `&self` for incoming messages with a certain tag.
-    fn matched_probe_with_tag(&self, tag: Tag) -> (Message, Status);
+    fn matched_probe_with_tag(&self, tag: Tag) -> Result<(Message, Status), Error>;
 
     /// Probe `Source` `&self` for incoming messages with any tag.
-    fn matched_probe(&self) -> (Message, Status) {
+    fn matched_probe(&self) -> Result<(Message, Status), Error> {
         self.matched_probe_with_tag(ffi::RSMPI_ANY_TAG)
     }
 }
 
 impl<Src: Source> MatchedProbe for Src {
-    fn matched_probe_with_tag(&self, tag: Tag) -> (Message, Status) {
+    fn matched_probe_with_tag(&self, tag: Tag) -> Result<(Message, Status), Error> {
         let mut message: MPI_Message = unsafe { mem::uninitialized() };
         let mut status: MPI_Status = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Mprobe(self.source_rank(), tag, self.communicator().as_raw(),
-                &mut message, &mut status);
-        }
-        (Message(message), Status(status))
+                &mut message, &mut status)
+        }));
+        Ok((Message(message), Status(status)))
     }
 }
 
@@ -358,18 +373,18 @@ impl<Src: Source> MatchedProbe for Src {
 pub trait MatchedReceive {
     /// Receives the message `&self` which contains a single instance of type `Msg` or None if
     /// receiving from the null process.
-    fn matched_receive<Msg: EquivalentDatatype>(self) -> (Option<Msg>, Status);
+    fn matched_receive<Msg: EquivalentDatatype>(self) -> Result<(Option<Msg>, Status), Error>;
 }
 
 impl MatchedReceive for Message {
-    fn matched_receive<Msg: EquivalentDatatype>(self) -> (Option<Msg>, Status) {
+    fn matched_receive<Msg: EquivalentDatatype>(self) -> Result<(Option<Msg>, Status), Error> {
         let is_no_proc = self.is_no_proc();
         let mut res: Msg = unsafe { mem::uninitialized() };
-        let status = self.matched_receive_into(&mut res);
+        let status = try!(self.matched_receive_into(&mut res));
         if is_no_proc {
-            (None, status)
+            Ok((None, status))
         } else {
-            (Some(res), status)
+            Ok((Some(res), status))
         }
     }
 }
@@ -383,18 +398,23 @@ pub trait MatchedReceiveInto {
     /// Receive the message `&self` with contents matching `buf`.
     ///
     /// Receiving from the null process leaves `buf` untouched.
-    fn matched_receive_into<Buf: BufferMut + ?Sized>(self, buf: &mut Buf) -> Status;
+    fn matched_receive_into<Buf: BufferMut + ?Sized>(self, buf: &mut Buf) -> Result<Status, Error>;
 }
 
 impl MatchedReceiveInto for Message {
-    fn matched_receive_into<Buf: BufferMut + ?Sized>(mut self, buf: &mut Buf) -> Status {
+    fn matched_receive_into<Buf: BufferMut + ?Sized>(mut self, buf: &mut Buf) -> Result<Status, Error> {
         let mut status: MPI_Status = unsafe { mem::uninitialized() };
-        unsafe {
+        let res = check(unsafe {
             ffi::MPI_Mrecv(buf.pointer_mut(), buf.count(), buf.datatype().as_raw(),
-                self.as_raw_mut(), &mut status);
-            assert_eq!(self.as_raw(), ffi::RSMPI_MESSAGE_NULL);
+                self.as_raw_mut(), &mut status)
+        });
+        if res.is_err() {
+            // The message is lost, do not trip the assertion in `drop()`.
+            mem::forget(self);
+        } else {
+            unsafe { assert_eq!(self.as_raw(), ffi::RSMPI_MESSAGE_NULL); }
         }
-        Status(status)
+        res.map(|_| Status(status))
     }
 }
 
@@ -406,22 +426,22 @@ impl MatchedReceiveInto for Message {
 pub trait MatchedReceiveVec {
     /// Receives the message `&self` which contains multiple instances of type `Msg` into a `Vec`
     /// or `None` if receiving from the null process.
-    fn matched_receive_vec<Msg: EquivalentDatatype>(self) -> (Option<Vec<Msg>>, Status);
+    fn matched_receive_vec<Msg: EquivalentDatatype>(self) -> Result<(Option<Vec<Msg>>, Status), Error>;
 }
 
 impl MatchedReceiveVec for (Message, Status) {
-    fn matched_receive_vec<Msg: EquivalentDatatype>(self) -> (Option<Vec<Msg>>, Status) {
+    fn matched_receive_vec<Msg: EquivalentDatatype>(self) -> Result<(Option<Vec<Msg>>, Status), Error> {
         let (message, status) = self;
         let is_no_proc = message.is_no_proc();
         let count = status.count(Msg::equivalent_datatype()).value_as().expect(
             "Message element count cannot be expressed as a usize.");
         let mut res = Vec::with_capacity(count);
         unsafe { res.set_len(count); }
-        let status = message.matched_receive_into(&mut res[..]);
+        let status = try!(message.matched_receive_into(&mut res[..]));
         if is_no_proc {
-            (None, status)
+            Ok((None, status))
         } else {
-            (Some(res), status)
+            Ok((Some(res), status))
         }
     }
 }
@@ -436,7 +456,7 @@ impl MatchedReceiveVec for (Message, Status) {
 /// let universe = mpi::initialize().unwrap();
 /// let world = universe.world();
 ///
-/// let x = world.receive::<f64>();
+/// let x = world.receive::<f64>().unwrap();
 /// ```
 ///
 /// # Standard section(s)
@@ -445,23 +465,23 @@ impl MatchedReceiveVec for (Message, Status) {
 pub trait Receive {
     /// Receive a message from `Source` `&self` tagged `tag` containing a single instance of type
     /// `Msg` or `None` if receiving from the null process.
-    fn receive_with_tag<Msg: EquivalentDatatype>(&self, tag: Tag) -> (Option<Msg>, Status);
+    fn receive_with_tag<Msg: EquivalentDatatype>(&self, tag: Tag) -> Result<(Option<Msg>, Status), Error>;
 
     /// Receive a message from `Source` `&self` containing a single instance of type `Msg` or
     /// `None` if receiving from the null process.
-    fn receive<Msg: EquivalentDatatype>(&self) -> (Option<Msg>, Status) {
+    fn receive<Msg: EquivalentDatatype>(&self) -> Result<(Option<Msg>, Status), Error> {
         self.receive_with_tag(ffi::RSMPI_ANY_TAG)
     }
 }
 
 impl<Src: Source> Receive for Src {
-    fn receive_with_tag<Msg: EquivalentDatatype>(&self, tag: Tag) -> (Option<Msg>, Status) {
+    fn receive_with_tag<Msg: EquivalentDatatype>(&self, tag: Tag) -> Result<(Option<Msg>, Status), Error> {
         let mut res: Msg = unsafe { mem::uninitialized() };
-        let status = self.receive_into_with_tag(&mut res, tag);
+        let status = try!(self.receive_into_with_tag(&mut res, tag));
         if self.source_rank() == ffi::RSMPI_PROC_NULL {
-            (None, status)
+            Ok((None, status))
         } else {
-            (Some(res), status)
+            Ok((Some(res), status))
         }
     }
 }
@@ -475,24 +495,24 @@ pub trait ReceiveInto {
     /// Receive a message from `Source` `&self` tagged `tag` into `Buffer` `buf`.
     ///
     /// Receiving from the null process leaves `buf` untouched.
-    fn receive_into_with_tag<Buf: BufferMut + ?Sized>(&self, buf: &mut Buf, tag: Tag) -> Status;
+    fn receive_into_with_tag<Buf: BufferMut + ?Sized>(&self, buf: &mut Buf, tag: Tag) -> Result<Status, Error>;
 
     /// Receive a message from `Source` `&self` into `Buffer` `buf`.
     ///
     /// Receiving from the null process leaves `buf` untouched.
-    fn receive_into<Buf: BufferMut + ?Sized>(&self, buf: &mut Buf) -> Status {
+    fn receive_into<Buf: BufferMut + ?Sized>(&self, buf: &mut Buf) -> Result<Status, Error> {
         self.receive_into_with_tag(buf, ffi::RSMPI_ANY_TAG)
     }
 }
 
 impl<Src: Source> ReceiveInto for Src {
-    fn receive_into_with_tag<Buf: BufferMut + ?Sized>(&self, buf: &mut Buf, tag: Tag) -> Status {
+    fn receive_into_with_tag<Buf: BufferMut + ?Sized>(&self, buf: &mut Buf, tag: Tag) -> Result<Status, Error> {
         let mut status: MPI_Status = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Recv(buf.pointer_mut(), buf.count(), buf.datatype().as_raw(),
-                self.source_rank(), tag, self.communicator().as_raw(), &mut status);
-        }
-        Status(status)
+                self.source_rank(), tag, self.communicator().as_raw(), &mut status)
+        }));
+        Ok(Status(status))
     }
 }
 
@@ -504,21 +524,165 @@ impl<Src: Source> ReceiveInto for Src {
 pub trait ReceiveVec {
     /// Receive a message from `Source` `&self` tagged `tag` containing multiple instances of type
     /// `Msg` into a `Vec` or `None` if receiving from the null process.
-    fn receive_vec_with_tag<Msg: EquivalentDatatype>(&self, tag: Tag) -> (Option<Vec<Msg>>, Status);
+    fn receive_vec_with_tag<Msg: EquivalentDatatype>(&self, tag: Tag) -> Result<(Option<Vec<Msg>>, Status), Error>;
 
     /// Receive a message from `Source` `&self` containing multiple instances of type `Msg` into a
     /// `Vec` or `None` if receiving from the null process.
     ///
     /// # Examples
     /// See `examples/send_receive.rs`
-    fn receive_vec<Msg: EquivalentDatatype>(&self) -> (Option<Vec<Msg>>, Status) {
+    fn receive_vec<Msg: EquivalentDatatype>(&self) -> Result<(Option<Vec<Msg>>, Status), Error> {
         self.receive_vec_with_tag(ffi::RSMPI_ANY_TAG)
     }
 }
 
 impl<Src: Source> ReceiveVec for Src {
-    fn receive_vec_with_tag<Msg: EquivalentDatatype>(&self, tag: Tag) -> (Option<Vec<Msg>>, Status) {
-        self.matched_probe_with_tag(tag).matched_receive_vec()
+    fn receive_vec_with_tag<Msg: EquivalentDatatype>(&self, tag: Tag) -> Result<(Option<Vec<Msg>>, Status), Error> {
+        try!(self.matched_probe_with_tag(tag)).matched_receive_vec()
+    }
+}
+
+/// Receive a message containing a UTF-8 encoded string into a `String`.
+///
+/// # Standard section(s)
+///
+/// 3.2.4
+pub trait ReceiveString {
+    /// Receive a message from `Source` `&self` tagged `tag` containing a string into a `String`
+    /// or `None` if receiving from the null process.
+    ///
+    /// If the message is not valid UTF-8, the `FromUtf8Error` which holds the received bytes is
+    /// returned instead of the `String`.
+    fn receive_string_with_tag(&self, tag: Tag) -> Result<(Option<Result<String, FromUtf8Error>>, Status), Error>;
+
+    /// Receive a message from `Source` `&self` containing a string into a `String` or `None` if
+    /// receiving from the null process.
+    ///
+    /// If the message is not valid UTF-8, the `FromUtf8Error` which holds the received bytes is
+    /// returned instead of the `String`.
+    ///
+    /// # Examples
+    /// See `examples/send_receive_string.rs`
+    fn receive_string(&self) -> Result<(Option<Result<String, FromUtf8Error>>, Status), Error> {
+        self.receive_string_with_tag(ffi::RSMPI_ANY_TAG)
+    }
+}
+
+impl<Src: Source> ReceiveString for Src {
+    fn receive_string_with_tag(&self, tag: Tag) -> Result<(Option<Result<String, FromUtf8Error>>, Status), Error> {
+        // Strings are sent with the datatype of `str` rather than that of `u8`, so the bytes have
+        // to be received as such.
+        let (message, status) = try!(self.matched_probe_with_tag(tag));
+        let is_no_proc = message.is_no_proc();
+        let datatype = "".datatype();
+        let count = status.count(datatype);
+        let mut bytes = vec![0u8; count.value_as().expect(
+            "Message element count cannot be expressed as a usize.")];
+        let status = try!(message.matched_receive_into(
+            &mut unsafe { MutView::with_count_and_datatype(&mut bytes[..], count, &datatype) }));
+        if is_no_proc {
+            Ok((None, status))
+        } else {
+            Ok((Some(String::from_utf8(bytes)), status))
+        }
+    }
+}
+
+/// Send a value of any type that can be serialized via `serde`.
+///
+/// The value is encoded in a compact binary format and sent as a message of bytes.
+///
+/// # Standard section(s)
+///
+/// 3.2.1
+#[cfg(feature = "serde")]
+pub trait SendSerialized {
+    /// Serialize `msg`, send it to the `Destination` `&self` and tag it.
+    ///
+    /// Nothing is sent if `msg` cannot be serialized.
+    fn send_serialized_with_tag<T: Serialize + ?Sized>(&self, msg: &T, tag: Tag) -> Result<(), SerializationError>;
+
+    /// Serialize `msg` and send it to the `Destination` `&self`.
+    ///
+    /// Nothing is sent if `msg` cannot be serialized.
+    ///
+    /// # Examples
+    /// See `examples/serialized.rs`
+    fn send_serialized<T: Serialize + ?Sized>(&self, msg: &T) -> Result<(), SerializationError> {
+        self.send_serialized_with_tag(msg, Tag::default())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<Dest: Destination> SendSerialized for Dest {
+    fn send_serialized_with_tag<T: Serialize + ?Sized>(&self, msg: &T, tag: Tag) -> Result<(), SerializationError> {
+        let bytes = try!(bincode::serialize(msg).map_err(SerializationError::Serialize));
+        self.send_with_tag(&bytes[..], tag).map_err(SerializationError::Mpi)
+    }
+}
+
+/// An error that occurred while sending a value via `SendSerialized`
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum SerializationError {
+    /// The value could not be serialized.
+    Serialize(bincode::Error),
+    /// The MPI library reported an error while sending the serialized value.
+    Mpi(Error)
+}
+
+#[cfg(feature = "serde")]
+impl fmt::Display for SerializationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            SerializationError::Serialize(ref e) => write!(f, "Cannot serialize message: {}", e),
+            SerializationError::Mpi(ref e) => write!(f, "{}", e)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl error::Error for SerializationError {
+    fn description(&self) -> &str {
+        "Error sending a serialized message"
+    }
+}
+
+/// Receive a value sent via `SendSerialized`.
+///
+/// The size of the incoming message is determined via a matched probe before it is received, so
+/// messages of any length can be received.
+///
+/// # Standard section(s)
+///
+/// 3.2.4, 3.8.2, 3.8.3
+#[cfg(feature = "serde")]
+pub trait ReceiveDeserialized {
+    /// Receive a message from `Source` `&self` tagged `tag` and deserialize it into an instance of
+    /// type `T` or `None` if receiving from the null process.
+    ///
+    /// If the message cannot be deserialized into an instance of type `T`, the error of the
+    /// deserializer is returned instead of the value.
+    fn receive_deserialized_with_tag<T: DeserializeOwned>(&self, tag: Tag) -> Result<(Option<Result<T, bincode::Error>>, Status), Error>;
+
+    /// Receive a message from `Source` `&self` and deserialize it into an instance of type `T` or
+    /// `None` if receiving from the null process.
+    ///
+    /// If the message cannot be deserialized into an instance of type `T`, the error of the
+    /// deserializer is returned instead of the value.
+    ///
+    /// # Examples
+    /// See `examples/serialized.rs`
+    fn receive_deserialized<T: DeserializeOwned>(&self) -> Result<(Option<Result<T, bincode::Error>>, Status), Error> {
+        self.receive_deserialized_with_tag(ffi::RSMPI_ANY_TAG)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<Src: Source> ReceiveDeserialized for Src {
+    fn receive_deserialized_with_tag<T: DeserializeOwned>(&self, tag: Tag) -> Result<(Option<Result<T, bincode::Error>>, Status), Error> {
+        let (bytes, status) = try!(try!(self.matched_probe_with_tag(tag)).matched_receive_vec::<u8>());
+        Ok((bytes.map(|bytes| bincode::deserialize(&bytes[..])), status))
     }
 }
 
@@ -540,7 +704,7 @@ pub trait SendReceive {
                                     sendtag: Tag,
                                     source: Rank,
                                     receivetag: Tag)
-                                    -> (Option<R>, Status)
+                                    -> Result<(Option<R>, Status), Error>
         where S: EquivalentDatatype,
               R: EquivalentDatatype;
 
@@ -553,7 +717,7 @@ pub trait SendReceive {
                           msg: &S,
                           destination: Rank,
                           source: Rank)
-                          -> (Option<R>, Status)
+                          -> Result<(Option<R>, Status), Error>
         where S: EquivalentDatatype,
               R: EquivalentDatatype
     {
@@ -568,18 +732,18 @@ impl<T: SendReceiveInto> SendReceive for T {
                                     sendtag: Tag,
                                     source: Rank,
                                     receivetag: Tag)
-                                    -> (Option<R>, Status)
+                                    -> Result<(Option<R>, Status), Error>
         where S: EquivalentDatatype,
               R: EquivalentDatatype
     {
         let mut res: R = unsafe { mem::uninitialized() };
-        let status = self.send_receive_into_with_tags(
+        let status = try!(self.send_receive_into_with_tags(
             msg, destination, sendtag,
-            &mut res, source, receivetag);
+            &mut res, source, receivetag));
         if source == ffi::RSMPI_PROC_NULL {
-            (None, status)
+            Ok((None, status))
         } else {
-            (Some(res), status)
+            Ok((Some(res), status))
         }
     }
 }
@@ -602,7 +766,7 @@ pub trait SendReceiveInto {
                                                          receivebuf: &mut R,
                                                          source: Rank,
                                                          receivetag: Tag)
-                                                         -> Status
+                                                         -> Result<Status, Error>
         where S: Buffer,
               R: BufferMut;
 
@@ -616,7 +780,7 @@ pub trait SendReceiveInto {
                                                destination: Rank,
                                                receivebuf: &mut R,
                                                source: Rank)
-                                               -> Status
+                                               -> Result<Status, Error>
         where S: Buffer,
               R: BufferMut
     {
@@ -632,18 +796,18 @@ impl<C: RawCommunicator> SendReceiveInto for C {
                                                          receivebuf: &mut R,
                                                          source: Rank,
                                                          receivetag: Tag)
-                                                         -> Status
+                                                         -> Result<Status, Error>
         where S: Buffer,
               R: BufferMut
     {
         let mut status: MPI_Status = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Sendrecv(
                 sendbuf.pointer(), sendbuf.count(), sendbuf.datatype().as_raw(), destination, sendtag,
                 receivebuf.pointer_mut(), receivebuf.count(), receivebuf.datatype().as_raw(), source, receivetag,
-                self.as_raw(), &mut status);
-        }
-        Status(status)
+                self.as_raw(), &mut status)
+        }));
+        Ok(Status(status))
     }
 }
 
@@ -664,7 +828,7 @@ pub trait SendReceiveReplaceInto {
                                                       sendtag: Tag,
                                                       source: Rank,
                                                       receivetag: Tag)
-                                                      -> Status
+                                                      -> Result<Status, Error>
         where B: BufferMut;
 
     /// Sends the contents of `buf` to `Rank` `destination` and
@@ -676,7 +840,7 @@ pub trait SendReceiveReplaceInto {
                                             buf: &mut B,
                                             destination: Rank,
                                             source: Rank)
-                                            -> Status
+                                            -> Result<Status, Error>
         where B: BufferMut
     {
         self.send_receive_replace_into_with_tags(buf, destination, Tag::default(), source, Tag::default())
@@ -690,21 +854,21 @@ impl<C: RawCommunicator> SendReceiveReplaceInto for C {
                                                       sendtag: Tag,
                                                       source: Rank,
                                                       receivetag: Tag)
-                                                      -> Status
+                                                      -> Result<Status, Error>
         where B: BufferMut
     {
         let mut status: MPI_Status = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Sendrecv_replace(
                 buf.pointer_mut(), buf.count(), buf.datatype().as_raw(), destination, sendtag,
-                source, receivetag, self.as_raw(), &mut status);
-        }
-        Status(status)
+                source, receivetag, self.as_raw(), &mut status)
+        }));
+        Ok(Status(status))
     }
 }
 
 /// A request object associated with a send operation
-pub type SendRequest<'b, Buf> = ReadRequest<'b, Buf>;
+pub type SendRequest<'s, Buf> = ReadRequest<'s, Buf>;
 
 /// Initiate an immediate (non-blocking) standard mode send operation.
 ///
@@ -716,23 +880,23 @@ pub type SendRequest<'b, Buf> = ReadRequest<'b, Buf>;
 /// 3.7.2
 pub trait ImmediateSend {
     /// Initiate sending the data in `buf` in standard mode and tag it.
-    fn immediate_send_with_tag<'b, Buf: 'b + Buffer + ?Sized>(&self, buf: &'b Buf, tag: Tag) -> SendRequest<'b, Buf>;
+    fn immediate_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a Buf, tag: Tag) -> Result<SendRequest<'s, Buf>, Error>;
 
     /// Initiate sending the data in `buf` in standard mode.
-    fn immediate_send<'b, Buf: 'b + Buffer + ?Sized>(&self, buf: &'b Buf) -> SendRequest<'b, Buf> {
-        self.immediate_send_with_tag(buf, Tag::default())
+    fn immediate_send<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a Buf) -> Result<SendRequest<'s, Buf>, Error> {
+        self.immediate_send_with_tag(scope, buf, Tag::default())
     }
 }
 
 impl<Dest: Destination> ImmediateSend for Dest {
-    fn immediate_send_with_tag<'b, Buf: 'b + Buffer + ?Sized>(&self, buf: &'b Buf, tag: Tag) -> SendRequest<'b, Buf> {
+    fn immediate_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a Buf, tag: Tag) -> Result<SendRequest<'s, Buf>, Error> {
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Isend(buf.pointer(), buf.count(), buf.datatype().as_raw(),
                 self.destination_rank(), tag, self.communicator().as_raw(),
-                &mut request);
-        }
-        SendRequest::from_raw(request, buf)
+                &mut request)
+        }));
+        Ok(SendRequest::from_raw(request, scope, buf))
     }
 }
 
@@ -743,23 +907,23 @@ impl<Dest: Destination> ImmediateSend for Dest {
 /// 3.7.2
 pub trait ImmediateBufferedSend {
     /// Initiate sending the data in `buf` in buffered mode and tag it.
-    fn immediate_buffered_send_with_tag<'b, Buf: 'b + Buffer + ?Sized>(&self, buf: &'b Buf, tag: Tag) -> SendRequest<'b, Buf>;
+    fn immediate_buffered_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a Buf, tag: Tag) -> Result<SendRequest<'s, Buf>, Error>;
 
     /// Initiate sending the data in `buf` in buffered mode.
-    fn immediate_buffered_send<'b, Buf: 'b + Buffer + ?Sized>(&self, buf: &'b Buf) -> SendRequest<'b, Buf> {
-        self.immediate_buffered_send_with_tag(buf, Tag::default())
+    fn immediate_buffered_send<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a Buf) -> Result<SendRequest<'s, Buf>, Error> {
+        self.immediate_buffered_send_with_tag(scope, buf, Tag::default())
     }
 }
 
 impl<Dest: Destination> ImmediateBufferedSend for Dest {
-    fn immediate_buffered_send_with_tag<'b, Buf: 'b + Buffer + ?Sized>(&self, buf: &'b Buf, tag: Tag) -> SendRequest<'b, Buf> {
+    fn immediate_buffered_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a Buf, tag: Tag) -> Result<SendRequest<'s, Buf>, Error> {
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Ibsend(buf.pointer(), buf.count(), buf.datatype().as_raw(),
                 self.destination_rank(), tag, self.communicator().as_raw(),
-                &mut request);
-        }
-        SendRequest::from_raw(request, buf)
+                &mut request)
+        }));
+        Ok(SendRequest::from_raw(request, scope, buf))
     }
 }
 
@@ -770,23 +934,23 @@ impl<Dest: Destination> ImmediateBufferedSend for Dest {
 /// 3.7.2
 pub trait ImmediateSynchronousSend {
     /// Initiate sending the data in `buf` in synchronous mode and tag it.
-    fn immediate_synchronous_send_with_tag<'b, Buf: 'b + Buffer + ?Sized>(&self, buf: &'b Buf, tag: Tag) -> SendRequest<'b, Buf>;
+    fn immediate_synchronous_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a Buf, tag: Tag) -> Result<SendRequest<'s, Buf>, Error>;
 
     /// Initiate sending the data in `buf` in synchronous mode.
-    fn immediate_synchronous_send<'b, Buf: 'b + Buffer + ?Sized>(&self, buf: &'b Buf) -> SendRequest<'b, Buf> {
-        self.immediate_synchronous_send_with_tag(buf, Tag::default())
+    fn immediate_synchronous_send<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a Buf) -> Result<SendRequest<'s, Buf>, Error> {
+        self.immediate_synchronous_send_with_tag(scope, buf, Tag::default())
     }
 }
 
 impl<Dest: Destination> ImmediateSynchronousSend for Dest {
-    fn immediate_synchronous_send_with_tag<'b, Buf: 'b + Buffer + ?Sized>(&self, buf: &'b Buf, tag: Tag) -> SendRequest<'b, Buf> {
+    fn immediate_synchronous_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a Buf, tag: Tag) -> Result<SendRequest<'s, Buf>, Error> {
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Issend(buf.pointer(), buf.count(), buf.datatype().as_raw(),
                 self.destination_rank(), tag, self.communicator().as_raw(),
-                &mut request);
-        }
-        SendRequest::from_raw(request, buf)
+                &mut request)
+        }));
+        Ok(SendRequest::from_raw(request, scope, buf))
     }
 }
 
@@ -801,28 +965,28 @@ impl<Dest: Destination> ImmediateSynchronousSend for Dest {
 /// 3.7.2
 pub trait ImmediateReadySend {
     /// Initiate sending the data in `buf` in ready mode and tag it.
-    fn immediate_ready_send_with_tag<'b, Buf: 'b + Buffer + ?Sized>(&self, buf: &'b Buf, tag: Tag) -> SendRequest<'b, Buf>;
+    fn immediate_ready_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a Buf, tag: Tag) -> Result<SendRequest<'s, Buf>, Error>;
 
     /// Initiate sending the data in `buf` in ready mode.
-    fn immediate_ready_send<'b, Buf: 'b + Buffer + ?Sized>(&self, buf: &'b Buf) -> SendRequest<'b, Buf> {
-        self.immediate_ready_send_with_tag(buf, Tag::default())
+    fn immediate_ready_send<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a Buf) -> Result<SendRequest<'s, Buf>, Error> {
+        self.immediate_ready_send_with_tag(scope, buf, Tag::default())
     }
 }
 
 impl<Dest: Destination> ImmediateReadySend for Dest {
-    fn immediate_ready_send_with_tag<'b, Buf: 'b + Buffer + ?Sized>(&self, buf: &'b Buf, tag: Tag) -> SendRequest<'b, Buf> {
+    fn immediate_ready_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a Buf, tag: Tag) -> Result<SendRequest<'s, Buf>, Error> {
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Irsend(buf.pointer(), buf.count(), buf.datatype().as_raw(),
                 self.destination_rank(), tag, self.communicator().as_raw(),
-                &mut request);
-        }
-        SendRequest::from_raw(request, buf)
+                &mut request)
+        }));
+        Ok(SendRequest::from_raw(request, scope, buf))
     }
 }
 
 /// A request object associated with a receive operation
-pub type ReceiveRequest<'b, Buf> = WriteRequest<'b, Buf>;
+pub type ReceiveRequest<'s, Buf> = WriteRequest<'s, Buf>;
 
 /// Initiate an immediate (non-blocking) receive operation.
 ///
@@ -834,23 +998,23 @@ pub type ReceiveRequest<'b, Buf> = WriteRequest<'b, Buf>;
 /// 3.7.2
 pub trait ImmediateReceiveInto {
     /// Initiate receiving a message matching `tag` into `buf`.
-    fn immediate_receive_into_with_tag<'b, Buf: 'b + BufferMut + ?Sized>(&self, buf: &'b mut Buf, tag: Tag) -> ReceiveRequest<'b, Buf>;
+    fn immediate_receive_into_with_tag<'a, 's, Buf: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf, tag: Tag) -> Result<ReceiveRequest<'s, Buf>, Error>;
 
     /// Initiate receiving a message into `buf`.
-    fn immediate_receive_into<'b, Buf: 'b + BufferMut + ?Sized>(&self, buf: &'b mut Buf) -> ReceiveRequest<'b, Buf> {
-        self.immediate_receive_into_with_tag(buf, ffi::RSMPI_ANY_TAG)
+    fn immediate_receive_into<'a, 's, Buf: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf) -> Result<ReceiveRequest<'s, Buf>, Error> {
+        self.immediate_receive_into_with_tag(scope, buf, ffi::RSMPI_ANY_TAG)
     }
 }
 
 impl<Src:Source> ImmediateReceiveInto for Src {
-    fn immediate_receive_into_with_tag<'b, Buf: 'b + BufferMut + ?Sized>(&self, buf: &'b mut Buf, tag: Tag) -> ReceiveRequest<'b, Buf> {
+    fn immediate_receive_into_with_tag<'a, 's, Buf: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf, tag: Tag) -> Result<ReceiveRequest<'s, Buf>, Error> {
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Irecv(buf.pointer_mut(), buf.count(), buf.datatype().as_raw(),
                 self.source_rank(), tag, self.communicator().as_raw(),
-                &mut request);
-        }
-        ReceiveRequest::from_raw(request, buf)
+                &mut request)
+        }));
+        Ok(ReceiveRequest::from_raw(request, scope, buf))
     }
 }
 
@@ -877,12 +1041,12 @@ pub struct ReceiveFuture<T> {
 
 impl<T> ReceiveFuture<T> {
     /// Wait for the receive operation to finish and return the received data.
-    pub fn get(self) -> (Option<T>, Status) {
-        let status = self.req.wait();
+    pub fn get(self) -> Result<(Option<T>, Status), Error> {
+        let status = try!(self.req.wait());
         if status.source_rank() == ffi::RSMPI_PROC_NULL {
-            (None, status)
+            Ok((None, status))
         } else {
-            (Some(*self.val), status)
+            Ok((Some(*self.val), status))
         }
     }
 
@@ -890,21 +1054,21 @@ impl<T> ReceiveFuture<T> {
     ///
     /// If the operation has finished, the data received is returned. Otherwise the future itself
     /// is returned.
-    pub fn try(mut self) -> Result<(Option<T>, Status), Self> {
-        match self.req.test() {
+    pub fn try(mut self) -> Result<Result<(Option<T>, Status), Self>, Error> {
+        match try!(self.req.test()) {
             Ok(status) => {
-                Ok((
+                Ok(Ok((
                     if status.source_rank() == ffi::RSMPI_PROC_NULL {
                         None
                     } else {
                         Some(*self.val)
                     },
                     status
-                ))
+                )))
             }
             Err(request) => {
                 self.req = request;
-                Err(self)
+                Ok(Err(self))
             }
         }
     }
@@ -920,28 +1084,28 @@ impl<T> ReceiveFuture<T> {
 /// 3.7.2
 pub trait ImmediateReceive {
     /// Initiate a non-blocking receive operation for messages matching tag `tag`.
-    fn immediate_receive_with_tag<Msg: EquivalentDatatype>(&self, tag: Tag) -> ReceiveFuture<Msg>;
+    fn immediate_receive_with_tag<Msg: EquivalentDatatype>(&self, tag: Tag) -> Result<ReceiveFuture<Msg>, Error>;
 
     /// Initiate a non-blocking receive operation.
-    fn immediate_receive<Msg: EquivalentDatatype>(&self) -> ReceiveFuture<Msg> {
+    fn immediate_receive<Msg: EquivalentDatatype>(&self) -> Result<ReceiveFuture<Msg>, Error> {
         self.immediate_receive_with_tag(ffi::RSMPI_ANY_TAG)
     }
 }
 
 impl<Src: Source> ImmediateReceive for Src {
-    fn immediate_receive_with_tag<Msg: EquivalentDatatype>(&self, tag: Tag) -> ReceiveFuture<Msg> {
+    fn immediate_receive_with_tag<Msg: EquivalentDatatype>(&self, tag: Tag) -> Result<ReceiveFuture<Msg>, Error> {
         let mut res: ReceiveFuture<Msg> = ReceiveFuture {
             val: Box::new( unsafe { mem::uninitialized() }),
             req: PlainRequest(unsafe { mem::uninitialized() })
         };
 
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Irecv((&mut *(res.val)).pointer_mut(), res.val.count(),
                 Msg::equivalent_datatype().as_raw(), self.source_rank(), tag,
-                self.communicator().as_raw(), res.req.as_raw_mut());
-        }
+                self.communicator().as_raw(), res.req.as_raw_mut())
+        }));
 
-        res
+        Ok(res)
     }
 }
 
@@ -954,26 +1118,26 @@ impl<Src: Source> ImmediateReceive for Src {
 /// 3.8.1
 pub trait ImmediateProbe {
     /// Asynchronously probe `Source` `&self` for incoming messages with a certain tag.
-    fn immediate_probe_with_tag(&self, tag: Tag) -> Option<Status>;
+    fn immediate_probe_with_tag(&self, tag: Tag) -> Result<Option<Status>, Error>;
 
     /// Asynchronously probe `Source` `&self` for incoming messages with any tag.
-    fn immediate_probe(&self) -> Option<Status> {
+    fn immediate_probe(&self) -> Result<Option<Status>, Error> {
         self.immediate_probe_with_tag(ffi::RSMPI_ANY_TAG)
     }
 }
 
 impl<Src: Source> ImmediateProbe for Src {
-    fn immediate_probe_with_tag(&self, tag: Tag) -> Option<Status> {
+    fn immediate_probe_with_tag(&self, tag: Tag) -> Result<Option<Status>, Error> {
         let mut status: MPI_Status = unsafe { mem::uninitialized() };
         let mut flag: c_int = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Iprobe(self.source_rank(), tag, self.communicator().as_raw(),
-                &mut flag, &mut status);
-        };
+                &mut flag, &mut status)
+        }));
         if flag != 0 {
-            Some(Status(status))
+            Ok(Some(Status(status)))
         } else {
-            None
+            Ok(None)
         }
     }
 }
@@ -988,27 +1152,27 @@ impl<Src: Source> ImmediateProbe for Src {
 /// 3.8.2
 pub trait ImmediateMatchedProbe {
     /// Asynchronously probe `Source` `&self` for incoming messages with a certain tag.
-    fn immediate_matched_probe_with_tag(&self, tag: Tag) -> Option<(Message, Status)>;
+    fn immediate_matched_probe_with_tag(&self, tag: Tag) -> Result<Option<(Message, Status)>, Error>;
 
     /// Asynchronously probe `Source` `&self` for incoming messages with any tag.
-    fn immediate_matched_probe(&self) -> Option<(Message, Status)> {
+    fn immediate_matched_probe(&self) -> Result<Option<(Message, Status)>, Error> {
         self.immediate_matched_probe_with_tag(ffi::RSMPI_ANY_TAG)
     }
 }
 
 impl<Src: Source> ImmediateMatchedProbe for Src {
-    fn immediate_matched_probe_with_tag(&self, tag: Tag) -> Option<(Message, Status)> {
+    fn immediate_matched_probe_with_tag(&self, tag: Tag) -> Result<Option<(Message, Status)>, Error> {
         let mut message: MPI_Message = unsafe { mem::uninitialized() };
         let mut status: MPI_Status = unsafe { mem::uninitialized() };
         let mut flag: c_int = unsafe { mem::uninitialized() };
-        unsafe {
+        try!(check(unsafe {
             ffi::MPI_Improbe(self.source_rank(), tag, self.communicator().as_raw(),
-                &mut flag, &mut message, &mut status);
-        }
+                &mut flag, &mut message, &mut status)
+        }));
         if flag != 0 {
-            Some((Message(message), Status(status)))
+            Ok(Some((Message(message), Status(status))))
         } else {
-            None
+            Ok(None)
         }
     }
 }
@@ -1022,17 +1186,265 @@ pub trait ImmediateMatchedReceiveInto {
     /// Asynchronously receive the message `&self` with contents matching `buf`.
     ///
     /// Receiving from the null process leaves `buf` untouched.
-    fn immediate_matched_receive_into<'b, Buf: 'b + BufferMut + ?Sized>(self, buf: &'b mut Buf) -> ReceiveRequest<'b, Buf>;
+    fn immediate_matched_receive_into<'a, 's, Buf: 'a + BufferMut + ?Sized>(self, scope: &'s Scope<'a>, buf: &'a mut Buf) -> Result<ReceiveRequest<'s, Buf>, Error>;
 }
 
 impl ImmediateMatchedReceiveInto for Message {
-    fn immediate_matched_receive_into<'b, Buf: 'b + BufferMut + ?Sized>(mut self, buf: &'b mut Buf) -> ReceiveRequest<'b, Buf> {
+    fn immediate_matched_receive_into<'a, 's, Buf: 'a + BufferMut + ?Sized>(mut self, scope: &'s Scope<'a>, buf: &'a mut Buf) -> Result<ReceiveRequest<'s, Buf>, Error> {
         let mut request: MPI_Request = unsafe { mem::uninitialized() };
-        unsafe {
+        let res = check(unsafe {
             ffi::MPI_Imrecv(buf.pointer_mut(), buf.count(), buf.datatype().as_raw(),
-                self.as_raw_mut(), &mut request);
-            assert_eq!(self.as_raw(), ffi::RSMPI_MESSAGE_NULL);
+                self.as_raw_mut(), &mut request)
+        });
+        if res.is_err() {
+            // The message is lost, do not trip the assertion in `drop()`.
+            mem::forget(self);
+        } else {
+            unsafe { assert_eq!(self.as_raw(), ffi::RSMPI_MESSAGE_NULL); }
         }
-        ReceiveRequest::from_raw(request, buf)
+        res.map(move |_| ReceiveRequest::from_raw(request, scope, buf))
+    }
+}
+
+/// Create a persistent request for a standard mode send operation.
+///
+/// # Examples
+///
+/// See `examples/persistent.rs`
+///
+/// # Standard section(s)
+///
+/// 3.9
+pub trait PersistentSend {
+    /// Create a request for sending the data in `buf` in standard mode and tagging it.
+    fn persistent_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf, tag: Tag) -> Result<PersistentRequest<'s, Buf>, Error>;
+
+    /// Create a request for sending the data in `buf` in standard mode.
+    fn persistent_send<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf) -> Result<PersistentRequest<'s, Buf>, Error> {
+        self.persistent_send_with_tag(scope, buf, Tag::default())
+    }
+}
+
+impl<Dest: Destination> PersistentSend for Dest {
+    fn persistent_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf, tag: Tag) -> Result<PersistentRequest<'s, Buf>, Error> {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Send_init(buf.pointer(), buf.count(), buf.datatype().as_raw(),
+                self.destination_rank(), tag, self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(PersistentRequest::from_raw(request, scope, buf))
+    }
+}
+
+/// Create a persistent request for a buffered mode send operation.
+///
+/// # Standard section(s)
+///
+/// 3.9
+pub trait PersistentBufferedSend {
+    /// Create a request for sending the data in `buf` in buffered mode and tagging it.
+    fn persistent_buffered_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf, tag: Tag) -> Result<PersistentRequest<'s, Buf>, Error>;
+
+    /// Create a request for sending the data in `buf` in buffered mode.
+    fn persistent_buffered_send<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf) -> Result<PersistentRequest<'s, Buf>, Error> {
+        self.persistent_buffered_send_with_tag(scope, buf, Tag::default())
+    }
+}
+
+impl<Dest: Destination> PersistentBufferedSend for Dest {
+    fn persistent_buffered_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf, tag: Tag) -> Result<PersistentRequest<'s, Buf>, Error> {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Bsend_init(buf.pointer(), buf.count(), buf.datatype().as_raw(),
+                self.destination_rank(), tag, self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(PersistentRequest::from_raw(request, scope, buf))
+    }
+}
+
+/// Create a persistent request for a synchronous mode send operation.
+///
+/// # Standard section(s)
+///
+/// 3.9
+pub trait PersistentSynchronousSend {
+    /// Create a request for sending the data in `buf` in synchronous mode and tagging it.
+    fn persistent_synchronous_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf, tag: Tag) -> Result<PersistentRequest<'s, Buf>, Error>;
+
+    /// Create a request for sending the data in `buf` in synchronous mode.
+    fn persistent_synchronous_send<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf) -> Result<PersistentRequest<'s, Buf>, Error> {
+        self.persistent_synchronous_send_with_tag(scope, buf, Tag::default())
+    }
+}
+
+impl<Dest: Destination> PersistentSynchronousSend for Dest {
+    fn persistent_synchronous_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf, tag: Tag) -> Result<PersistentRequest<'s, Buf>, Error> {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Ssend_init(buf.pointer(), buf.count(), buf.datatype().as_raw(),
+                self.destination_rank(), tag, self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(PersistentRequest::from_raw(request, scope, buf))
+    }
+}
+
+/// Create a persistent request for a ready mode send operation.
+///
+/// # Standard section(s)
+///
+/// 3.9
+pub trait PersistentReadySend {
+    /// Create a request for sending the data in `buf` in ready mode and tagging it.
+    fn persistent_ready_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf, tag: Tag) -> Result<PersistentRequest<'s, Buf>, Error>;
+
+    /// Create a request for sending the data in `buf` in ready mode.
+    fn persistent_ready_send<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf) -> Result<PersistentRequest<'s, Buf>, Error> {
+        self.persistent_ready_send_with_tag(scope, buf, Tag::default())
+    }
+}
+
+impl<Dest: Destination> PersistentReadySend for Dest {
+    fn persistent_ready_send_with_tag<'a, 's, Buf: 'a + Buffer + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf, tag: Tag) -> Result<PersistentRequest<'s, Buf>, Error> {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Rsend_init(buf.pointer(), buf.count(), buf.datatype().as_raw(),
+                self.destination_rank(), tag, self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(PersistentRequest::from_raw(request, scope, buf))
+    }
+}
+
+/// Create a persistent request for a receive operation.
+///
+/// # Examples
+///
+/// See `examples/persistent.rs`
+///
+/// # Standard section(s)
+///
+/// 3.9
+pub trait PersistentReceiveInto {
+    /// Create a request for receiving a message matching `tag` into `buf`.
+    fn persistent_receive_into_with_tag<'a, 's, Buf: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf, tag: Tag) -> Result<PersistentRequest<'s, Buf>, Error>;
+
+    /// Create a request for receiving a message into `buf`.
+    fn persistent_receive_into<'a, 's, Buf: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf) -> Result<PersistentRequest<'s, Buf>, Error> {
+        self.persistent_receive_into_with_tag(scope, buf, ffi::RSMPI_ANY_TAG)
+    }
+}
+
+impl<Src: Source> PersistentReceiveInto for Src {
+    fn persistent_receive_into_with_tag<'a, 's, Buf: 'a + BufferMut + ?Sized>(&self, scope: &'s Scope<'a>, buf: &'a mut Buf, tag: Tag) -> Result<PersistentRequest<'s, Buf>, Error> {
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Recv_init(buf.pointer_mut(), buf.count(), buf.datatype().as_raw(),
+                self.source_rank(), tag, self.communicator().as_raw(),
+                &mut request)
+        }));
+        Ok(PersistentRequest::from_raw(request, scope, buf))
+    }
+}
+
+/// Checks that `buf` can be divided into `partitions` partitions of equal size and returns the
+/// number of elements per partition, otherwise an `Error` of class `MPI_ERR_ARG`.
+fn partition_count<T>(buf: &[T], partitions: usize) -> Result<ffi::MPI_Count, Error> {
+    if partitions == 0 || buf.len() % partitions != 0 {
+        return Err(Error::from_raw(ffi::RSMPI_ERR_ARG));
+    }
+    (buf.len() / partitions).value_as().map_err(|_| Error::from_raw(ffi::RSMPI_ERR_ARG))
+}
+
+/// Create a request for a partitioned send operation.
+///
+/// Requires a library that implements version 4.0 of the MPI standard, with older libraries an
+/// `Error` of class `MPI_ERR_UNSUPPORTED_OPERATION` is returned.
+///
+/// # Examples
+///
+/// See `examples/partitioned.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2.1 (MPI 4.0)
+pub trait PartitionedSend {
+    /// Create a request for sending the data in `buf` divided into `partitions` partitions of
+    /// equal size and tagging it.
+    ///
+    /// If the length of `buf` is not a multiple of `partitions`, an `Error` of class
+    /// `MPI_ERR_ARG` is returned.
+    fn partitioned_send_with_tag<'a, 's, T: 'a + EquivalentDatatype>(&self, scope: &'s Scope<'a>, buf: &'a mut [T], partitions: usize, tag: Tag) -> Result<PartitionedSendRequest<'s, T>, Error>;
+
+    /// Create a request for sending the data in `buf` divided into `partitions` partitions of
+    /// equal size.
+    ///
+    /// If the length of `buf` is not a multiple of `partitions`, an `Error` of class
+    /// `MPI_ERR_ARG` is returned.
+    fn partitioned_send<'a, 's, T: 'a + EquivalentDatatype>(&self, scope: &'s Scope<'a>, buf: &'a mut [T], partitions: usize) -> Result<PartitionedSendRequest<'s, T>, Error> {
+        self.partitioned_send_with_tag(scope, buf, partitions, Tag::default())
+    }
+}
+
+impl<Dest: Destination> PartitionedSend for Dest {
+    fn partitioned_send_with_tag<'a, 's, T: 'a + EquivalentDatatype>(&self, scope: &'s Scope<'a>, buf: &'a mut [T], partitions: usize, tag: Tag) -> Result<PartitionedSendRequest<'s, T>, Error> {
+        try!(check_version_4());
+        let count = try!(partition_count(buf, partitions));
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Psend_init(buf.pointer(), partitions.value_as().expect(
+                    "Number of partitions cannot be expressed as a c_int."),
+                count, buf.datatype().as_raw(), self.destination_rank(), tag,
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PartitionedSendRequest::from_raw(request, scope, buf, partitions))
+    }
+}
+
+/// Create a request for a partitioned receive operation.
+///
+/// Partitioned communication does not match wildcards, so the operation is only available on an
+/// `Identifier` of a specific process. Requires a library that implements version 4.0 of the MPI standard, with older
+/// libraries an `Error` of class `MPI_ERR_UNSUPPORTED_OPERATION` is returned.
+///
+/// # Examples
+///
+/// See `examples/partitioned.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2.1 (MPI 4.0)
+pub trait PartitionedReceiveInto {
+    /// Create a request for receiving a message matching `tag` into `buf` divided into
+    /// `partitions` partitions of equal size.
+    ///
+    /// If the length of `buf` is not a multiple of `partitions`, an `Error` of class
+    /// `MPI_ERR_ARG` is returned.
+    fn partitioned_receive_into_with_tag<'a, 's, T: 'a + EquivalentDatatype>(&self, scope: &'s Scope<'a>, buf: &'a mut [T], partitions: usize, tag: Tag) -> Result<PartitionedReceiveRequest<'s, T>, Error>;
+
+    /// Create a request for receiving a message with the default tag into `buf` divided into
+    /// `partitions` partitions of equal size.
+    ///
+    /// If the length of `buf` is not a multiple of `partitions`, an `Error` of class
+    /// `MPI_ERR_ARG` is returned.
+    fn partitioned_receive_into<'a, 's, T: 'a + EquivalentDatatype>(&self, scope: &'s Scope<'a>, buf: &'a mut [T], partitions: usize) -> Result<PartitionedReceiveRequest<'s, T>, Error> {
+        self.partitioned_receive_into_with_tag(scope, buf, partitions, Tag::default())
+    }
+}
+
+impl<'b, C: 'b + RawCommunicator> PartitionedReceiveInto for Identifier<'b, C> {
+    fn partitioned_receive_into_with_tag<'a, 's, T: 'a + EquivalentDatatype>(&self, scope: &'s Scope<'a>, buf: &'a mut [T], partitions: usize, tag: Tag) -> Result<PartitionedReceiveRequest<'s, T>, Error> {
+        try!(check_version_4());
+        let count = try!(partition_count(buf, partitions));
+        let mut request: MPI_Request = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::RSMPI_Precv_init(buf.pointer_mut(), partitions.value_as().expect(
+                    "Number of partitions cannot be expressed as a c_int."),
+                count, buf.datatype().as_raw(), self.source_rank(), tag,
+                self.communicator().as_raw(), &mut request)
+        }));
+        Ok(PartitionedReceiveRequest::from_raw(request, scope, buf, partitions))
     }
 }
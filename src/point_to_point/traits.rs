@@ -1,8 +1,12 @@
 //! Point to point communication traits
 pub use super::{Source, Destination, Send, BufferedSend, SynchronousSend, ReadySend, Probe,
                 MatchedReceive, MatchedReceiveInto, MatchedReceiveVec, MatchedProbe,
-                Receive, ReceiveInto, ReceiveVec,
+                Receive, ReceiveInto, ReceiveVec, ReceiveString,
                 SendReceive, SendReceiveInto, SendReceiveReplaceInto,
                 ImmediateSend, ImmediateBufferedSend, ImmediateSynchronousSend, ImmediateReadySend,
                 ImmediateReceive, ImmediateReceiveInto, ImmediateProbe, ImmediateMatchedProbe,
-                ImmediateMatchedReceiveInto};
+                ImmediateMatchedReceiveInto, PersistentSend, PersistentBufferedSend,
+                PersistentSynchronousSend, PersistentReadySend, PersistentReceiveInto,
+                PartitionedSend, PartitionedReceiveInto};
+#[cfg(feature = "serde")]
+pub use super::{SendSerialized, ReceiveDeserialized};
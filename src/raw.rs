@@ -48,6 +48,17 @@ pub trait RawRequest: AsRaw<Raw = MPI_Request> + AsRawMut {
             self.as_raw() == ffi::RSMPI_REQUEST_NULL
         }
     }
+
+    /// Returns true for a persistent request, which is not set to the null request handle when
+    /// its operation completes but becomes inactive instead.
+    fn is_persistent(&self) -> bool {
+        false
+    }
+
+    /// Returns true for a persistent request that has not been started.
+    fn is_inactive(&self) -> bool {
+        false
+    }
 }
 
 /// A type that can identify as an `MPI_Op`
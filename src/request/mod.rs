@@ -1,21 +1,38 @@
+//! Request objects for non-blocking operations
+//!
+//! Non-blocking operations return a request object that can be completed via `wait()` or
+//! `test()`. Operations that borrow buffers have to be started inside a `Scope` which is created
+//! by `scope()`. The scope waits for all of its requests that are still outstanding when it ends,
+//! so a buffer can never be freed or reused while an operation might still access it. Requests
+//! that do not borrow buffers have to be completed before they are dropped. Many requests can be
+//! completed together by collecting them in a `RequestCollection`. Persistent requests bind the
+//! arguments of an operation once and can then be started repeatedly. Partitioned requests are
+//! persistent requests whose buffer is transferred in partitions that become ready and arrive
+//! individually.
+//!
 //! # Unfinished features
 //!
 //! - **3.7**: Nonblocking mode:
-//!   - Completion, `MPI_Waitany()`, `MPI_Waitall()`, `MPI_Waitsome()`,
-//!   `MPI_Testany()`, `MPI_Testall()`, `MPI_Testsome()`, `MPI_Request_get_status()`
+//!   - Completion, `MPI_Request_get_status()`
 //! - **3.8**:
 //!   - Cancellation, `MPI_Cancel()`, `MPI_Test_cancelled()`
 
+use std::cell::{Cell, RefCell};
 use std::mem;
 use std::marker::PhantomData;
 
 use libc::c_int;
 
+use conv::ConvUtil;
+
+use super::Error;
+use super::check;
 use ffi;
 use ffi::{MPI_Request, MPI_Status};
 
 use datatype::traits::*;
 use point_to_point::{Status};
+use topology::Threading;
 use raw::traits::*;
 
 pub mod traits;
@@ -31,14 +48,14 @@ pub mod traits;
 /// 3.7.3
 pub trait Wait: RawRequest + Sized {
     /// Will block execution of the calling thread until the associated operation has finished.
-    fn wait(mut self) -> Status {
+    ///
+    /// A persistent request becomes inactive and is freed by the `Scope` it belongs to.
+    fn wait(mut self) -> Result<Status, Error> {
         let mut status: MPI_Status = unsafe { mem::uninitialized() };
-        unsafe {
-            ffi::MPI_Wait(self.as_raw_mut(), &mut status);
-        }
-        assert!(self.is_null());
+        let res = check(unsafe { ffi::MPI_Wait(self.as_raw_mut(), &mut status) });
+        assert!(res.is_err() || self.is_null() || self.is_persistent());
         mem::forget(self);
-        Status::from_raw(status)
+        res.map(|_| Status::from_raw(status))
     }
 }
 
@@ -56,18 +73,20 @@ impl<R: RawRequest + Sized> Wait for R { }
 pub trait Test: RawRequest + Sized {
     /// If the operation has finished returns the `Status` otherwise returns the unfinished
     /// `Request`.
-    fn test(mut self) -> Result<Status, Self> {
+    fn test(mut self) -> Result<Result<Status, Self>, Error> {
         let mut status: MPI_Status = unsafe { mem::uninitialized() };
         let mut flag: c_int = 0;
-        unsafe {
-            ffi::MPI_Test(self.as_raw_mut(), &mut flag, &mut status);
+        let res = check(unsafe { ffi::MPI_Test(self.as_raw_mut(), &mut flag, &mut status) });
+        if let Err(e) = res {
+            mem::forget(self);
+            return Err(e);
         }
-        assert!(flag == 0 || self.is_null());
+        assert!(flag == 0 || self.is_null() || self.is_persistent());
         if flag != 0 {
             mem::forget(self);
-            Result::Ok(Status::from_raw(status))
+            Ok(Ok(Status::from_raw(status)))
         } else {
-            Result::Err(self)
+            Ok(Err(self))
         }
     }
 }
@@ -85,13 +104,12 @@ impl<R: RawRequest + Sized> Test for R { }
 /// 3.8.4
 pub trait Cancel: RawRequest + Sized {
     /// Cancel an operation.
-    fn cancel(mut self) {
-        unsafe {
-            ffi::MPI_Cancel(self.as_raw_mut());
-            ffi::MPI_Request_free(self.as_raw_mut());
-        }
-        assert!(self.is_null());
+    fn cancel(mut self) -> Result<(), Error> {
+        let res = check(unsafe { ffi::MPI_Cancel(self.as_raw_mut()) })
+            .and_then(|_| check(unsafe { ffi::MPI_Request_free(self.as_raw_mut()) }));
+        assert!(res.is_err() || self.is_null());
         mem::forget(self);
+        res
     }
 }
 
@@ -135,6 +153,9 @@ impl Drop for Request {
 
 /// A request object for a non-blocking operation that holds a reference to an immutable buffer
 ///
+/// The request handle lives in the `Scope` the operation was started in, which completes the
+/// operation when it ends if that has not happened before.
+///
 /// # Examples
 ///
 /// See `examples/immediate.rs`
@@ -143,105 +164,869 @@ impl Drop for Request {
 ///
 /// 3.7.1
 #[must_use]
-pub struct ReadRequest<'b, Buf: 'b + Buffer + ?Sized>(MPI_Request, PhantomData<&'b Buf>);
+pub struct ReadRequest<'s, Buf: 's + ?Sized>(&'s Cell<MPI_Request>, PhantomData<&'s Buf>);
 
-impl<'b, Buf: 'b + Buffer + ?Sized> ReadRequest<'b, Buf> {
-    /// Construct a request object from the raw MPI type
-    pub fn from_raw(request: MPI_Request, _: &'b Buf) -> ReadRequest<'b, Buf> {
-        ReadRequest(request, PhantomData)
+impl<'s, Buf: 's + ?Sized> ReadRequest<'s, Buf> {
+    /// Construct a request object from the raw MPI type and register it with `scope`
+    pub fn from_raw<'a>(request: MPI_Request, scope: &'s Scope<'a>, _: &'a Buf) -> ReadRequest<'s, Buf> {
+        ReadRequest(scope.register(request), PhantomData)
     }
 }
 
-impl<'b, Buf: 'b + Buffer + ?Sized> AsRaw for ReadRequest<'b, Buf> {
+impl<'s, Buf: 's + ?Sized> AsRaw for ReadRequest<'s, Buf> {
     type Raw = MPI_Request;
-    unsafe fn as_raw(&self) -> Self::Raw { self.0 }
+    unsafe fn as_raw(&self) -> Self::Raw { self.0.get() }
 }
 
-impl<'b, Buf: 'b + Buffer + ?Sized> AsRawMut for ReadRequest<'b, Buf> {
-    unsafe fn as_raw_mut(&mut self) -> *mut <Self as AsRaw>::Raw { &mut (self.0) }
+impl<'s, Buf: 's + ?Sized> AsRawMut for ReadRequest<'s, Buf> {
+    unsafe fn as_raw_mut(&mut self) -> *mut <Self as AsRaw>::Raw { self.0.as_ptr() }
 }
 
-impl<'b, Buf: 'b + Buffer + ?Sized> RawRequest for ReadRequest<'b, Buf> { }
+impl<'s, Buf: 's + ?Sized> RawRequest for ReadRequest<'s, Buf> { }
 
-impl<'b, Buf: 'b + Buffer + ?Sized> Drop for ReadRequest<'b, Buf> {
-    fn drop(&mut self) {
-        assert!(self.is_null(), "read request dropped without ascertaining completion.");
+/// A request object for a non-blocking operation that holds a reference to a mutable buffer
+///
+/// The request handle lives in the `Scope` the operation was started in, which completes the
+/// operation when it ends if that has not happened before.
+///
+/// # Examples
+///
+/// See `examples/immediate.rs`
+///
+/// # Standard section(s)
+///
+/// 3.7.1
+#[must_use]
+pub struct WriteRequest<'s, Buf: 's + ?Sized>(&'s Cell<MPI_Request>, PhantomData<&'s mut Buf>);
+
+impl<'s, Buf: 's + ?Sized> WriteRequest<'s, Buf> {
+    /// Construct a request object from the raw MPI type and register it with `scope`
+    pub fn from_raw<'a>(request: MPI_Request, scope: &'s Scope<'a>, _: &'a Buf) -> WriteRequest<'s, Buf> {
+        WriteRequest(scope.register(request), PhantomData)
     }
 }
 
-/// A request object for a non-blocking operation that holds a reference to a mutable buffer
+impl<'s, Buf: 's + ?Sized> AsRaw for WriteRequest<'s, Buf> {
+    type Raw = MPI_Request;
+    unsafe fn as_raw(&self) -> Self::Raw { self.0.get() }
+}
+
+impl<'s, Buf: 's + ?Sized> AsRawMut for WriteRequest<'s, Buf> {
+    unsafe fn as_raw_mut(&mut self) -> *mut <Self as AsRaw>::Raw { self.0.as_ptr() }
+}
+
+impl<'s, Buf: 's + ?Sized> RawRequest for WriteRequest<'s, Buf> { }
+
+/// A request object for a non-blocking operation that holds a reference to a mutable and an
+/// immutable buffer
+///
+/// The request handle lives in the `Scope` the operation was started in, which completes the
+/// operation when it ends if that has not happened before.
 ///
 /// # Examples
 ///
-/// See `examples/immediate.rs`
+/// See `examples/immediate_gather.rs`
 ///
 /// # Standard section(s)
 ///
 /// 3.7.1
 #[must_use]
-pub struct WriteRequest<'b, Buf: 'b + BufferMut + ?Sized>(MPI_Request, PhantomData<&'b mut Buf>);
+pub struct ReadWriteRequest<'s, S: 's + ?Sized, R: 's + ?Sized>(&'s Cell<MPI_Request>, PhantomData<&'s S>, PhantomData<&'s mut R>);
 
-impl<'b, Buf: 'b + BufferMut + ?Sized> WriteRequest<'b, Buf> {
-    /// Construct a request object from the raw MPI type
-    pub fn from_raw(request: MPI_Request, _: &'b Buf) -> WriteRequest<'b, Buf> {
-        WriteRequest(request, PhantomData)
+impl<'s, S: 's + ?Sized, R: 's + ?Sized> ReadWriteRequest<'s, S, R> {
+    /// Construct a request object from the raw MPI type and register it with `scope`
+    pub fn from_raw<'a>(request: MPI_Request, scope: &'s Scope<'a>, _: &'a S, _: &'a R) -> ReadWriteRequest<'s, S, R> {
+        ReadWriteRequest(scope.register(request), PhantomData, PhantomData)
     }
 }
 
-impl<'b, Buf: 'b + BufferMut + ?Sized> AsRaw for WriteRequest<'b, Buf> {
+impl<'s, S: 's + ?Sized, R: 's + ?Sized> AsRaw for ReadWriteRequest<'s, S, R> {
     type Raw = MPI_Request;
-    unsafe fn as_raw(&self) -> Self::Raw { self.0 }
+    unsafe fn as_raw(&self) -> Self::Raw { self.0.get() }
 }
 
-impl<'b, Buf: 'b + BufferMut + ?Sized> AsRawMut for WriteRequest<'b, Buf> {
-    unsafe fn as_raw_mut(&mut self) -> *mut <Self as AsRaw>::Raw { &mut (self.0) }
+impl<'s, S: 's + ?Sized, R: 's + ?Sized> AsRawMut for ReadWriteRequest<'s, S, R> {
+    unsafe fn as_raw_mut(&mut self) -> *mut <Self as AsRaw>::Raw { self.0.as_ptr() }
+}
+
+impl<'s, S: 's + ?Sized, R: 's + ?Sized> RawRequest for ReadWriteRequest<'s, S, R> { }
+
+/// An inactive persistent request for a communication operation on a buffer
+///
+/// Persistent requests bind the arguments of an operation once and can then be started any number
+/// of times. While inactive, the request gives access to its buffer so that it can be refilled or
+/// read between operations. Starting it yields an `ActivePersistentRequest` which turns back into
+/// a `PersistentRequest` once it has completed.
+///
+/// The request handle lives in the `Scope` the request was created in, which completes the
+/// operation if it is still active and frees the request when it ends.
+///
+/// # Examples
+///
+/// See `examples/persistent.rs`
+///
+/// # Standard section(s)
+///
+/// 3.9
+#[must_use]
+pub struct PersistentRequest<'s, Buf: 's + ?Sized> {
+    request: &'s Cell<MPI_Request>,
+    buffer: &'s mut Buf
+}
+
+impl<'s, Buf: 's + ?Sized> PersistentRequest<'s, Buf> {
+    /// Construct a request object from the raw MPI type and register it with `scope`
+    pub fn from_raw<'a>(request: MPI_Request, scope: &'s Scope<'a>, buffer: &'a mut Buf) -> PersistentRequest<'s, Buf> {
+        PersistentRequest { request: scope.register(request), buffer: buffer }
+    }
+
+    /// The buffer of the operation
+    pub fn buffer(&self) -> &Buf {
+        self.buffer
+    }
+
+    /// Starts the operation.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    pub fn start(self) -> Result<ActivePersistentRequest<'s, Buf>, Error> {
+        try!(check(unsafe { ffi::MPI_Start(self.request.as_ptr()) }));
+        Ok(ActivePersistentRequest { request: self.request, buffer: self.buffer })
+    }
+}
+
+impl<'s, T: EquivalentDatatype> PersistentRequest<'s, T> {
+    /// The buffer of the operation, for modification before it is started again
+    pub fn buffer_mut(&mut self) -> &mut T {
+        self.buffer
+    }
+}
+
+impl<'s, T: EquivalentDatatype> PersistentRequest<'s, [T]> {
+    /// The buffer of the operation, for modification before it is started again
+    pub fn buffer_mut(&mut self) -> &mut [T] {
+        self.buffer
+    }
+}
+
+impl<'s, Buf: 's + ?Sized> AsRaw for PersistentRequest<'s, Buf> {
+    type Raw = MPI_Request;
+    unsafe fn as_raw(&self) -> Self::Raw { self.request.get() }
+}
+
+impl<'s, Buf: 's + ?Sized> AsRawMut for PersistentRequest<'s, Buf> {
+    unsafe fn as_raw_mut(&mut self) -> *mut <Self as AsRaw>::Raw { self.request.as_ptr() }
+}
+
+impl<'s, Buf: 's + ?Sized> RawRequest for PersistentRequest<'s, Buf> {
+    fn is_persistent(&self) -> bool { true }
+    fn is_inactive(&self) -> bool { true }
+}
+
+/// A persistent request for an operation that has been started but not completed yet
+///
+/// # Examples
+///
+/// See `examples/persistent.rs`
+///
+/// # Standard section(s)
+///
+/// 3.9
+#[must_use]
+pub struct ActivePersistentRequest<'s, Buf: 's + ?Sized> {
+    request: &'s Cell<MPI_Request>,
+    buffer: &'s mut Buf
+}
+
+impl<'s, Buf: 's + ?Sized> ActivePersistentRequest<'s, Buf> {
+    /// Blocks until the operation has finished and returns the inactive request along with the
+    /// `Status` of the operation.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn wait(self) -> Result<(PersistentRequest<'s, Buf>, Status), Error> {
+        let mut status: MPI_Status = unsafe { mem::uninitialized() };
+        try!(check(unsafe { ffi::MPI_Wait(self.request.as_ptr(), &mut status) }));
+        Ok((PersistentRequest { request: self.request, buffer: self.buffer },
+            Status::from_raw(status)))
+    }
+
+    /// If the operation has finished returns the inactive request along with the `Status` of the
+    /// operation, otherwise returns the active request.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn test(self) -> Result<Result<(PersistentRequest<'s, Buf>, Status), Self>, Error> {
+        let mut status: MPI_Status = unsafe { mem::uninitialized() };
+        let mut flag: c_int = 0;
+        try!(check(unsafe { ffi::MPI_Test(self.request.as_ptr(), &mut flag, &mut status) }));
+        if flag != 0 {
+            Ok(Ok((PersistentRequest { request: self.request, buffer: self.buffer },
+                Status::from_raw(status))))
+        } else {
+            Ok(Err(self))
+        }
+    }
+}
+
+impl<'s, Buf: 's + ?Sized> AsRaw for ActivePersistentRequest<'s, Buf> {
+    type Raw = MPI_Request;
+    unsafe fn as_raw(&self) -> Self::Raw { self.request.get() }
+}
+
+impl<'s, Buf: 's + ?Sized> AsRawMut for ActivePersistentRequest<'s, Buf> {
+    unsafe fn as_raw_mut(&mut self) -> *mut <Self as AsRaw>::Raw { self.request.as_ptr() }
+}
+
+impl<'s, Buf: 's + ?Sized> RawRequest for ActivePersistentRequest<'s, Buf> {
+    fn is_persistent(&self) -> bool { true }
+}
+
+/// An inactive persistent request for a collective operation
+///
+/// Like a `PersistentRequest`, but for collective operations which can have a send and a receive
+/// buffer. Operations that only use one of the buffers leave the other one as an empty `[()]`. The
+/// buffer of a broadcast is its receive buffer, on the root process as well. As with
+/// `PersistentRequest`, buffers can only be modified between executions if they are a single value
+/// or a slice, which cannot be reallocated.
+///
+/// # Examples
+///
+/// See `examples/persistent_collective.rs`
+///
+/// # Standard section(s)
+///
+/// 6.13 (MPI 4.0)
+#[must_use]
+pub struct PersistentCollective<'s, S: 's + ?Sized, R: 's + ?Sized> {
+    request: &'s Cell<MPI_Request>,
+    sendbuf: &'s mut S,
+    recvbuf: &'s mut R
+}
+
+impl<'s, S: 's + ?Sized, R: 's + ?Sized> PersistentCollective<'s, S, R> {
+    /// Construct a request object from the raw MPI type and register it with `scope`
+    pub fn from_raw<'a>(request: MPI_Request, scope: &'s Scope<'a>, sendbuf: &'a mut S, recvbuf: &'a mut R) -> PersistentCollective<'s, S, R> {
+        PersistentCollective { request: scope.register(request), sendbuf: sendbuf, recvbuf: recvbuf }
+    }
+
+    /// The send buffer of the operation
+    pub fn send_buffer(&self) -> &S {
+        self.sendbuf
+    }
+
+    /// The receive buffer of the operation
+    pub fn receive_buffer(&self) -> &R {
+        self.recvbuf
+    }
+
+    /// Starts the operation.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    pub fn start(self) -> Result<ActivePersistentCollective<'s, S, R>, Error> {
+        try!(check(unsafe { ffi::MPI_Start(self.request.as_ptr()) }));
+        Ok(ActivePersistentCollective {
+            request: self.request, sendbuf: self.sendbuf, recvbuf: self.recvbuf
+        })
+    }
+}
+
+impl<'s, T: EquivalentDatatype, R: 's + ?Sized> PersistentCollective<'s, T, R> {
+    /// The send buffer of the operation, for modification before it is started again
+    pub fn send_buffer_mut(&mut self) -> &mut T {
+        self.sendbuf
+    }
+}
+
+impl<'s, T: EquivalentDatatype, R: 's + ?Sized> PersistentCollective<'s, [T], R> {
+    /// The send buffer of the operation, for modification before it is started again
+    pub fn send_buffer_mut(&mut self) -> &mut [T] {
+        self.sendbuf
+    }
+}
+
+impl<'s, S: 's + ?Sized, T: EquivalentDatatype> PersistentCollective<'s, S, T> {
+    /// The receive buffer of the operation, for modification before it is started again
+    pub fn receive_buffer_mut(&mut self) -> &mut T {
+        self.recvbuf
+    }
+}
+
+impl<'s, S: 's + ?Sized, T: EquivalentDatatype> PersistentCollective<'s, S, [T]> {
+    /// The receive buffer of the operation, for modification before it is started again
+    pub fn receive_buffer_mut(&mut self) -> &mut [T] {
+        self.recvbuf
+    }
+}
+
+impl<'s, S: 's + ?Sized, R: 's + ?Sized> AsRaw for PersistentCollective<'s, S, R> {
+    type Raw = MPI_Request;
+    unsafe fn as_raw(&self) -> Self::Raw { self.request.get() }
+}
+
+impl<'s, S: 's + ?Sized, R: 's + ?Sized> AsRawMut for PersistentCollective<'s, S, R> {
+    unsafe fn as_raw_mut(&mut self) -> *mut <Self as AsRaw>::Raw { self.request.as_ptr() }
+}
+
+impl<'s, S: 's + ?Sized, R: 's + ?Sized> RawRequest for PersistentCollective<'s, S, R> {
+    fn is_persistent(&self) -> bool { true }
+    fn is_inactive(&self) -> bool { true }
+}
+
+/// A persistent request for a collective operation that has been started but not completed yet
+///
+/// # Examples
+///
+/// See `examples/persistent_collective.rs`
+///
+/// # Standard section(s)
+///
+/// 6.13 (MPI 4.0)
+#[must_use]
+pub struct ActivePersistentCollective<'s, S: 's + ?Sized, R: 's + ?Sized> {
+    request: &'s Cell<MPI_Request>,
+    sendbuf: &'s mut S,
+    recvbuf: &'s mut R
+}
+
+impl<'s, S: 's + ?Sized, R: 's + ?Sized> ActivePersistentCollective<'s, S, R> {
+    /// Blocks until the operation has finished and returns the inactive request.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn wait(self) -> Result<PersistentCollective<'s, S, R>, Error> {
+        try!(check(unsafe { ffi::MPI_Wait(self.request.as_ptr(), ffi::RSMPI_STATUS_IGNORE) }));
+        Ok(PersistentCollective {
+            request: self.request, sendbuf: self.sendbuf, recvbuf: self.recvbuf
+        })
+    }
+
+    /// If the operation has finished returns the inactive request, otherwise returns the active
+    /// request.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn test(self) -> Result<Result<PersistentCollective<'s, S, R>, Self>, Error> {
+        let mut flag: c_int = 0;
+        try!(check(unsafe {
+            ffi::MPI_Test(self.request.as_ptr(), &mut flag, ffi::RSMPI_STATUS_IGNORE)
+        }));
+        if flag != 0 {
+            Ok(Ok(PersistentCollective {
+                request: self.request, sendbuf: self.sendbuf, recvbuf: self.recvbuf
+            }))
+        } else {
+            Ok(Err(self))
+        }
+    }
+}
+
+impl<'s, S: 's + ?Sized, R: 's + ?Sized> AsRaw for ActivePersistentCollective<'s, S, R> {
+    type Raw = MPI_Request;
+    unsafe fn as_raw(&self) -> Self::Raw { self.request.get() }
+}
+
+impl<'s, S: 's + ?Sized, R: 's + ?Sized> AsRawMut for ActivePersistentCollective<'s, S, R> {
+    unsafe fn as_raw_mut(&mut self) -> *mut <Self as AsRaw>::Raw { self.request.as_ptr() }
+}
+
+impl<'s, S: 's + ?Sized, R: 's + ?Sized> RawRequest for ActivePersistentCollective<'s, S, R> {
+    fn is_persistent(&self) -> bool { true }
+}
+
+/// An inactive request for a partitioned send operation
+///
+/// The send buffer is divided into partitions of equal size. Every time the operation is started,
+/// each partition has to be marked as ready before the operation can complete, which allows the
+/// partitions to be filled by different threads and transferred as soon as they are ready. While
+/// inactive, the request gives access to the whole buffer.
+///
+/// # Examples
+///
+/// See `examples/partitioned.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2.1 (MPI 4.0)
+#[must_use]
+pub struct PartitionedSendRequest<'s, T: 's> {
+    request: &'s Cell<MPI_Request>,
+    buffer: &'s mut [T],
+    partitions: usize
+}
+
+impl<'s, T: 's> PartitionedSendRequest<'s, T> {
+    /// Construct a request object from the raw MPI type and register it with `scope`
+    pub fn from_raw<'a>(request: MPI_Request, scope: &'s Scope<'a>, buffer: &'a mut [T], partitions: usize) -> PartitionedSendRequest<'s, T> {
+        PartitionedSendRequest { request: scope.register(request), buffer: buffer, partitions: partitions }
+    }
+
+    /// The number of partitions the buffer is divided into
+    pub fn num_partitions(&self) -> usize {
+        self.partitions
+    }
+
+    /// The buffer of the operation
+    pub fn buffer(&self) -> &[T] {
+        self.buffer
+    }
+
+    /// The buffer of the operation, for modification before it is started again
+    pub fn buffer_mut(&mut self) -> &mut [T] {
+        self.buffer
+    }
+
+    /// Starts the operation.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    pub fn start(self) -> Result<ActivePartitionedSendRequest<'s, T>, Error> {
+        try!(check(unsafe { ffi::MPI_Start(self.request.as_ptr()) }));
+        Ok(ActivePartitionedSendRequest {
+            request: self.request, buffer: self.buffer, partitions: self.partitions,
+            handed_out: false
+        })
+    }
+}
+
+impl<'s, T: 's> AsRaw for PartitionedSendRequest<'s, T> {
+    type Raw = MPI_Request;
+    unsafe fn as_raw(&self) -> Self::Raw { self.request.get() }
+}
+
+/// A partitioned send operation that has been started but not completed yet
+///
+/// The partitions of the buffer are handed out by `partitions()` and the operation completes once
+/// all of them have been marked as ready. If the request is dropped, partitions that have not been
+/// handed out are marked as ready so that the `Scope` can complete the operation.
+/// The request cannot be completed through `Wait`, `Test` or a `RequestCollection`, which could
+/// not mark the remaining partitions as ready.
+///
+/// # Examples
+///
+/// See `examples/partitioned.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2.1 (MPI 4.0)
+#[must_use]
+pub struct ActivePartitionedSendRequest<'s, T: 's> {
+    request: &'s Cell<MPI_Request>,
+    buffer: &'s mut [T],
+    partitions: usize,
+    handed_out: bool
+}
+
+impl<'s, T: 's> ActivePartitionedSendRequest<'s, T> {
+    /// The number of partitions the buffer is divided into
+    pub fn num_partitions(&self) -> usize {
+        self.partitions
+    }
+
+    /// Hands out the partitions of the buffer along with a handle that marks them as ready.
+    ///
+    /// Each `SendPartition` gives mutable access to its part of the buffer until it is passed to
+    /// `PartitionReady::ready()`. Partitions are `Send` and the handle is `Sync`, so different
+    /// threads can fill their partitions and mark them as ready. This requires MPI to support
+    /// `Threading::Multiple`, otherwise an error is returned and the buffer has to be filled
+    /// before the operation is started. The partitions are handed out once per operation, calling
+    /// this method again returns no partitions.
+    pub fn partitions(&mut self) -> Result<(PartitionReady, Vec<SendPartition<T>>), Error> {
+        let mut level: c_int = unsafe { mem::uninitialized() };
+        try!(check(unsafe { ffi::MPI_Query_thread(&mut level) }));
+        if Threading::from(level) != Threading::Multiple {
+            return Err(Error::from_raw(ffi::RSMPI_ERR_UNSUPPORTED_OPERATION));
+        }
+        Ok(self.hand_out())
+    }
+
+    fn ready_remaining(&mut self) -> Result<(), Error> {
+        let (ready, partitions) = self.hand_out();
+        for partition in partitions {
+            try!(ready.ready(partition));
+        }
+        Ok(())
+    }
+
+    fn hand_out(&mut self) -> (PartitionReady, Vec<SendPartition<T>>) {
+        let mut partitions = Vec::new();
+        if !self.handed_out {
+            self.handed_out = true;
+            let size = self.buffer.len() / self.partitions;
+            let mut rest: &mut [T] = self.buffer;
+            for index in 0..self.partitions {
+                let (buffer, tail) = mem::replace(&mut rest, &mut []).split_at_mut(size);
+                rest = tail;
+                partitions.push(SendPartition {
+                    request: self.request.get(), index: index, buffer: buffer
+                });
+            }
+        }
+        (PartitionReady { request: self.request.get(), phantom: PhantomData }, partitions)
+    }
+
+    /// Blocks until the operation has finished and returns the inactive request along with the
+    /// `Status` of the operation.
+    ///
+    /// Partitions that have been handed out by `partitions()` are marked as ready at the latest
+    /// when they are dropped, partitions that have not been handed out are marked as ready here.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn wait(mut self) -> Result<(PartitionedSendRequest<'s, T>, Status), Error> {
+        try!(self.ready_remaining());
+        let mut status: MPI_Status = unsafe { mem::uninitialized() };
+        try!(check(unsafe { ffi::MPI_Wait(self.request.as_ptr(), &mut status) }));
+        Ok((PartitionedSendRequest {
+            request: self.request, buffer: mem::replace(&mut self.buffer, &mut []),
+            partitions: self.partitions
+        }, Status::from_raw(status)))
+    }
+
+    /// If the operation has finished returns the inactive request along with the `Status` of the
+    /// operation, otherwise returns the active request.
+    ///
+    /// Partitions that have not been handed out by `partitions()` are marked as ready first.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn test(mut self) -> Result<Result<(PartitionedSendRequest<'s, T>, Status), Self>, Error> {
+        try!(self.ready_remaining());
+        let mut status: MPI_Status = unsafe { mem::uninitialized() };
+        let mut flag: c_int = 0;
+        try!(check(unsafe { ffi::MPI_Test(self.request.as_ptr(), &mut flag, &mut status) }));
+        if flag != 0 {
+            Ok(Ok((PartitionedSendRequest {
+                request: self.request, buffer: mem::replace(&mut self.buffer, &mut []),
+                partitions: self.partitions
+            }, Status::from_raw(status))))
+        } else {
+            Ok(Err(self))
+        }
+    }
 }
 
-impl<'b, Buf: 'b + BufferMut + ?Sized> RawRequest for WriteRequest<'b, Buf> { }
+impl<'s, T: 's> AsRaw for ActivePartitionedSendRequest<'s, T> {
+    type Raw = MPI_Request;
+    unsafe fn as_raw(&self) -> Self::Raw { self.request.get() }
+}
 
-impl<'b, Buf: 'b + BufferMut + ?Sized> Drop for WriteRequest<'b, Buf> {
+impl<'s, T: 's> Drop for ActivePartitionedSendRequest<'s, T> {
     fn drop(&mut self) {
-        assert!(self.is_null(), "write request dropped without ascertaining completion.");
+        let _ = self.ready_remaining();
     }
 }
 
-/// A request object for a non-blocking operation that holds a reference to a mutable and an
-/// immutable buffer
+/// A handle for marking the partitions of a partitioned send operation as ready
+///
+/// The handle can be shared among threads, each of which marks its own partitions as ready.
 ///
 /// # Examples
 ///
-/// See `examples/immediate_gather.rs`
+/// See `examples/partitioned.rs`
 ///
 /// # Standard section(s)
 ///
-/// 3.7.1
+/// 4.2.2 (MPI 4.0)
+pub struct PartitionReady<'p> {
+    request: MPI_Request,
+    phantom: PhantomData<&'p ()>
+}
+
+// The handle only ever passes the request to `MPI_Pready()` and is only handed out by
+// `partitions()` under `MPI_THREAD_MULTIPLE`.
+unsafe impl<'p> Send for PartitionReady<'p> { }
+unsafe impl<'p> Sync for PartitionReady<'p> { }
+
+impl<'p> PartitionReady<'p> {
+    /// Marks `partition` as ready to be transferred, after which its part of the buffer can no
+    /// longer be accessed.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2 (MPI 4.0)
+    pub fn ready<T>(&self, partition: SendPartition<'p, T>) -> Result<(), Error> {
+        assert!(partition.request == self.request,
+            "Partition belongs to a different partitioned send operation.");
+        let res = partition.pready();
+        mem::forget(partition);
+        res
+    }
+}
+
+/// One partition of the buffer of an active partitioned send operation
+///
+/// A partition that is dropped without having been passed to `PartitionReady::ready()` is marked
+/// as ready when it is dropped, ignoring any errors, so that the operation can still complete.
+///
+/// # Examples
+///
+/// See `examples/partitioned.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2.2 (MPI 4.0)
+pub struct SendPartition<'p, T: 'p> {
+    request: MPI_Request,
+    index: usize,
+    buffer: &'p mut [T]
+}
+
+// A partition is the only way to access its part of the buffer until it is marked as ready and is
+// only handed out by `partitions()` under `MPI_THREAD_MULTIPLE`.
+unsafe impl<'p, T: 'p + Send> Send for SendPartition<'p, T> { }
+
+impl<'p, T: 'p> SendPartition<'p, T> {
+    /// The index of the partition
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The part of the buffer that belongs to the partition
+    pub fn buffer(&self) -> &[T] {
+        self.buffer
+    }
+
+    /// The part of the buffer that belongs to the partition, for modification before it is
+    /// marked as ready
+    pub fn buffer_mut(&mut self) -> &mut [T] {
+        self.buffer
+    }
+
+    fn pready(&self) -> Result<(), Error> {
+        let index = self.index.value_as().expect("Partition index cannot be expressed as a c_int.");
+        check(unsafe { ffi::RSMPI_Pready(index, self.request) })
+    }
+}
+
+impl<'p, T: 'p> Drop for SendPartition<'p, T> {
+    fn drop(&mut self) {
+        let _ = self.pready();
+    }
+}
+
+/// An inactive request for a partitioned receive operation
+///
+/// The receive buffer is divided into partitions of equal size. While the operation is active,
+/// partitions that have arrived can be read before the whole operation has completed. While
+/// inactive, the request gives access to the whole buffer.
+///
+/// # Examples
+///
+/// See `examples/partitioned.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2.1 (MPI 4.0)
 #[must_use]
-pub struct ReadWriteRequest<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized>(MPI_Request, PhantomData<&'s S>, PhantomData<&'r mut R>);
+pub struct PartitionedReceiveRequest<'s, T: 's> {
+    request: &'s Cell<MPI_Request>,
+    buffer: &'s mut [T],
+    partitions: usize
+}
 
-impl<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized> ReadWriteRequest<'s, 'r, S, R> {
-    /// Construct a request object from the raw MPI type
-    pub fn from_raw(request: MPI_Request, _: &'s S, _: &'r R) -> ReadWriteRequest<'s, 'r, S, R> {
-        ReadWriteRequest(request, PhantomData, PhantomData)
+impl<'s, T: 's> PartitionedReceiveRequest<'s, T> {
+    /// Construct a request object from the raw MPI type and register it with `scope`
+    pub fn from_raw<'a>(request: MPI_Request, scope: &'s Scope<'a>, buffer: &'a mut [T], partitions: usize) -> PartitionedReceiveRequest<'s, T> {
+        PartitionedReceiveRequest { request: scope.register(request), buffer: buffer, partitions: partitions }
+    }
+
+    /// The number of partitions the buffer is divided into
+    pub fn num_partitions(&self) -> usize {
+        self.partitions
+    }
+
+    /// The buffer of the operation
+    pub fn buffer(&self) -> &[T] {
+        self.buffer
+    }
+
+    /// The buffer of the operation, for modification before it is started again
+    pub fn buffer_mut(&mut self) -> &mut [T] {
+        self.buffer
+    }
+
+    /// Starts the operation.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    pub fn start(self) -> Result<ActivePartitionedReceiveRequest<'s, T>, Error> {
+        try!(check(unsafe { ffi::MPI_Start(self.request.as_ptr()) }));
+        Ok(ActivePartitionedReceiveRequest {
+            request: self.request, buffer: self.buffer, partitions: self.partitions
+        })
     }
 }
 
-impl<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized> AsRaw for ReadWriteRequest<'s, 'r, S, R> {
+impl<'s, T: 's> AsRaw for PartitionedReceiveRequest<'s, T> {
     type Raw = MPI_Request;
-    unsafe fn as_raw(&self) -> Self::Raw { self.0 }
+    unsafe fn as_raw(&self) -> Self::Raw { self.request.get() }
 }
 
-impl<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized> AsRawMut for ReadWriteRequest<'s, 'r, S, R> {
-    unsafe fn as_raw_mut(&mut self) -> *mut <Self as AsRaw>::Raw { &mut (self.0) }
+/// A partitioned receive operation that has been started but not completed yet
+///
+/// # Examples
+///
+/// See `examples/partitioned.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2.1 (MPI 4.0)
+#[must_use]
+pub struct ActivePartitionedReceiveRequest<'s, T: 's> {
+    request: &'s Cell<MPI_Request>,
+    buffer: &'s mut [T],
+    partitions: usize
 }
 
-impl<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized> RawRequest for ReadWriteRequest<'s, 'r, S, R> { }
+impl<'s, T: 's> ActivePartitionedReceiveRequest<'s, T> {
+    /// The number of partitions the buffer is divided into
+    pub fn num_partitions(&self) -> usize {
+        self.partitions
+    }
+
+    /// Tests whether `partition` has arrived.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2 (MPI 4.0)
+    pub fn arrived(&self, partition: usize) -> Result<bool, Error> {
+        assert!(partition < self.partitions, "Partition {} does not exist, there are only {}.",
+            partition, self.partitions);
+        let index = partition.value_as().expect("Partition index cannot be expressed as a c_int.");
+        let mut flag: c_int = 0;
+        try!(check(unsafe { ffi::RSMPI_Parrived(self.request.get(), index, &mut flag) }));
+        Ok(flag != 0)
+    }
+
+    /// Returns the part of the buffer that belongs to `partition` if it has arrived.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2 (MPI 4.0)
+    pub fn partition(&self, partition: usize) -> Result<Option<&[T]>, Error> {
+        if try!(self.arrived(partition)) {
+            let size = self.buffer.len() / self.partitions;
+            Ok(Some(&self.buffer[partition * size..(partition + 1) * size]))
+        } else {
+            Ok(None)
+        }
+    }
 
-impl<'s, 'r, S: 's + Buffer + ?Sized, R: 'r + BufferMut + ?Sized> Drop for ReadWriteRequest<'s, 'r, S, R> {
+    /// Blocks until the operation has finished and returns the inactive request along with the
+    /// `Status` of the operation.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn wait(self) -> Result<(PartitionedReceiveRequest<'s, T>, Status), Error> {
+        let mut status: MPI_Status = unsafe { mem::uninitialized() };
+        try!(check(unsafe { ffi::MPI_Wait(self.request.as_ptr(), &mut status) }));
+        Ok((PartitionedReceiveRequest {
+            request: self.request, buffer: self.buffer, partitions: self.partitions
+        }, Status::from_raw(status)))
+    }
+
+    /// If the operation has finished returns the inactive request along with the `Status` of the
+    /// operation, otherwise returns the active request.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn test(self) -> Result<Result<(PartitionedReceiveRequest<'s, T>, Status), Self>, Error> {
+        let mut status: MPI_Status = unsafe { mem::uninitialized() };
+        let mut flag: c_int = 0;
+        try!(check(unsafe { ffi::MPI_Test(self.request.as_ptr(), &mut flag, &mut status) }));
+        if flag != 0 {
+            Ok(Ok((PartitionedReceiveRequest {
+                request: self.request, buffer: self.buffer, partitions: self.partitions
+            }, Status::from_raw(status))))
+        } else {
+            Ok(Err(self))
+        }
+    }
+}
+
+impl<'s, T: 's> AsRaw for ActivePartitionedReceiveRequest<'s, T> {
+    type Raw = MPI_Request;
+    unsafe fn as_raw(&self) -> Self::Raw { self.request.get() }
+}
+
+impl<'s, T: 's> AsRawMut for ActivePartitionedReceiveRequest<'s, T> {
+    unsafe fn as_raw_mut(&mut self) -> *mut <Self as AsRaw>::Raw { self.request.as_ptr() }
+}
+
+impl<'s, T: 's> RawRequest for ActivePartitionedReceiveRequest<'s, T> {
+    fn is_persistent(&self) -> bool { true }
+}
+
+/// A region of the program in which non-blocking operations may borrow buffers
+///
+/// Operations that borrow buffers for the lifetime `'a` register their requests with a `Scope<'a>`
+/// and the scope waits for all requests that are still outstanding when it ends. The buffers are
+/// therefore never released while an operation might still access them, even if a request object
+/// is dropped or leaked via `mem::forget()` without having been completed.
+///
+/// # Examples
+///
+/// See `examples/scope.rs`
+pub struct Scope<'a> {
+    requests: RefCell<Vec<Box<Cell<MPI_Request>>>>,
+    phantom: PhantomData<Cell<&'a ()>>
+}
+
+impl<'a> Scope<'a> {
+    fn register<'s>(&'s self, request: MPI_Request) -> &'s Cell<MPI_Request> {
+        let slot = Box::new(Cell::new(request));
+        let ptr: *const Cell<MPI_Request> = &*slot;
+        self.requests.borrow_mut().push(slot);
+        // The slot is boxed and only freed when the scope is dropped.
+        unsafe { &*ptr }
+    }
+}
+
+impl<'a> Drop for Scope<'a> {
     fn drop(&mut self) {
-        assert!(self.is_null(), "read-write request dropped without ascertaining completion.");
+        for slot in self.requests.borrow().iter() {
+            if slot.get() != ffi::RSMPI_REQUEST_NULL {
+                unsafe {
+                    ffi::MPI_Wait(slot.as_ptr(), ffi::RSMPI_STATUS_IGNORE);
+                }
+            }
+            // Persistent requests stay allocated after they have completed.
+            if slot.get() != ffi::RSMPI_REQUEST_NULL {
+                unsafe {
+                    ffi::MPI_Request_free(slot.as_ptr());
+                }
+            }
+        }
     }
 }
 
+/// Runs `f` with a new `Scope` that non-blocking operations can be started in.
+///
+/// All requests registered with the scope that have not completed when `f` returns (or unwinds)
+/// are waited on and persistent requests are freed before `scope()` returns.
+///
+/// # Examples
+///
+/// See `examples/scope.rs`
+pub fn scope<'a, F, R>(f: F) -> R where F: FnOnce(&Scope<'a>) -> R {
+    let scope = Scope { requests: RefCell::new(Vec::new()), phantom: PhantomData };
+    f(&scope)
+}
+
 /// Guard object that waits for the completion of an operation when it is dropped
 ///
 /// # Examples
@@ -255,7 +1040,7 @@ impl<Req: RawRequest> Drop for WaitGuard<Req> {
             unsafe {
                 ffi::MPI_Wait(req.as_raw_mut(), ffi::RSMPI_STATUS_IGNORE);
             }
-            assert!(req.is_null());
+            assert!(req.is_null() || req.is_persistent());
             mem::forget(req);
         });
     }
@@ -292,3 +1077,290 @@ impl<Req: RawRequest> From<Req> for CancelGuard<Req> {
         CancelGuard(Some(req))
     }
 }
+
+/// A collection of request objects that can be completed together
+///
+/// Requests of any type can be added to the collection as long as they do not outlive the
+/// collection. Requests are identified by the index at which they were added. Once a request has
+/// completed, it holds a null request handle or, if it is persistent, an inactive request handle
+/// which the completion functions skip.
+///
+/// # Examples
+///
+/// See `examples/wait_all.rs`, `examples/wait_any_some.rs`
+///
+/// # Standard section(s)
+///
+/// 3.7.5
+#[must_use]
+pub struct RequestCollection<'a> {
+    requests: Vec<Box<RawRequest + 'a>>,
+    completed: Vec<bool>
+}
+
+impl<'a> RequestCollection<'a> {
+    /// An empty collection
+    pub fn new() -> RequestCollection<'a> {
+        RequestCollection::with_capacity(0)
+    }
+
+    /// An empty collection with room for `capacity` requests
+    pub fn with_capacity(capacity: usize) -> RequestCollection<'a> {
+        RequestCollection {
+            requests: Vec::with_capacity(capacity), completed: Vec::with_capacity(capacity)
+        }
+    }
+
+    /// Adds `request` to the collection and returns its index.
+    pub fn push<Req: RawRequest + 'a>(&mut self, request: Req) -> usize {
+        self.completed.push(request.is_inactive());
+        self.requests.push(Box::new(request));
+        self.requests.len() - 1
+    }
+
+    /// Starts all requests in the collection together, all of which have to be persistent
+    /// requests that are not active.
+    ///
+    /// Persistent requests of different types can be started together this way and restarted
+    /// once the collection has completed them.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    pub fn start_all(&mut self) -> Result<(), Error> {
+        assert!(self.requests.iter().all(|r| r.is_persistent()),
+            "Only persistent requests can be started.");
+        assert_eq!(0, self.incomplete(), "Requests cannot be started while they are active.");
+        let count = self.count();
+        let mut raw = self.raw_requests();
+        try!(check(unsafe { ffi::MPI_Startall(count, raw.as_mut_ptr()) }));
+        self.update(&raw);
+        for completed in self.completed.iter_mut() {
+            *completed = false;
+        }
+        Ok(())
+    }
+
+    /// The number of requests that were added to the collection
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Whether no requests were added to the collection
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// The number of requests in the collection that have not completed yet
+    pub fn incomplete(&self) -> usize {
+        self.requests.iter().zip(self.completed.iter()).filter(|&(r, &c)| !c && !r.is_null())
+            .count()
+    }
+
+    /// Blocks until all requests in the collection have completed and returns their `Status`es
+    /// in the order in which the requests were added.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/wait_all.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.5
+    pub fn wait_all(&mut self) -> Result<Vec<Status>, Error> {
+        let count = self.count();
+        let mut raw = self.raw_requests();
+        let mut statuses: Vec<MPI_Status> = Vec::with_capacity(self.requests.len());
+        unsafe { statuses.set_len(self.requests.len()); }
+        try!(check(unsafe {
+            ffi::MPI_Waitall(count, raw.as_mut_ptr(), statuses.as_mut_ptr())
+        }));
+        self.update(&raw);
+        self.complete_all();
+        Ok(statuses.into_iter().map(Status::from_raw).collect())
+    }
+
+    /// Tests whether all requests in the collection have completed and if so returns their
+    /// `Status`es in the order in which the requests were added.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/wait_all.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.5
+    pub fn test_all(&mut self) -> Result<Option<Vec<Status>>, Error> {
+        let count = self.count();
+        let mut raw = self.raw_requests();
+        let mut flag: c_int = 0;
+        let mut statuses: Vec<MPI_Status> = Vec::with_capacity(self.requests.len());
+        unsafe { statuses.set_len(self.requests.len()); }
+        try!(check(unsafe {
+            ffi::MPI_Testall(count, raw.as_mut_ptr(), &mut flag, statuses.as_mut_ptr())
+        }));
+        self.update(&raw);
+        if flag != 0 {
+            self.complete_all();
+            Ok(Some(statuses.into_iter().map(Status::from_raw).collect()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Blocks until any of the requests in the collection has completed and returns its index
+    /// along with its `Status` or `None` if all requests have already completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/wait_any_some.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.5
+    pub fn wait_any(&mut self) -> Result<Option<(usize, Status)>, Error> {
+        let count = self.count();
+        let mut raw = self.raw_requests();
+        let mut index: c_int = 0;
+        let mut status: MPI_Status = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Waitany(count, raw.as_mut_ptr(), &mut index, &mut status)
+        }));
+        self.update(&raw);
+        if index == ffi::RSMPI_UNDEFINED {
+            Ok(None)
+        } else {
+            self.completed[index as usize] = true;
+            Ok(Some((index as usize, Status::from_raw(status))))
+        }
+    }
+
+    /// Tests whether any of the requests in the collection has completed and if so returns its
+    /// index along with its `Status`.
+    ///
+    /// Also returns `None` if all requests have already completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/wait_any_some.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.5
+    pub fn test_any(&mut self) -> Result<Option<(usize, Status)>, Error> {
+        let count = self.count();
+        let mut raw = self.raw_requests();
+        let mut index: c_int = 0;
+        let mut flag: c_int = 0;
+        let mut status: MPI_Status = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Testany(count, raw.as_mut_ptr(), &mut index, &mut flag,
+                &mut status)
+        }));
+        self.update(&raw);
+        if flag != 0 && index != ffi::RSMPI_UNDEFINED {
+            self.completed[index as usize] = true;
+            Ok(Some((index as usize, Status::from_raw(status))))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Blocks until at least one of the requests in the collection has completed and returns the
+    /// indices of all requests that have completed along with their `Status`es or `None` if all
+    /// requests have already completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/wait_any_some.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.5
+    pub fn wait_some(&mut self) -> Result<Option<Vec<(usize, Status)>>, Error> {
+        let count = self.count();
+        let mut raw = self.raw_requests();
+        let mut outcount: c_int = 0;
+        let mut indices: Vec<c_int> = vec![0; self.requests.len()];
+        let mut statuses: Vec<MPI_Status> = Vec::with_capacity(self.requests.len());
+        unsafe { statuses.set_len(self.requests.len()); }
+        try!(check(unsafe {
+            ffi::MPI_Waitsome(count, raw.as_mut_ptr(), &mut outcount,
+                indices.as_mut_ptr(), statuses.as_mut_ptr())
+        }));
+        self.update(&raw);
+        if outcount == ffi::RSMPI_UNDEFINED {
+            Ok(None)
+        } else {
+            Ok(Some(self.complete(indices, statuses, outcount)))
+        }
+    }
+
+    /// Tests which of the requests in the collection have completed and returns their indices
+    /// along with their `Status`es.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/wait_any_some.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.5
+    pub fn test_some(&mut self) -> Result<Vec<(usize, Status)>, Error> {
+        let count = self.count();
+        let mut raw = self.raw_requests();
+        let mut outcount: c_int = 0;
+        let mut indices: Vec<c_int> = vec![0; self.requests.len()];
+        let mut statuses: Vec<MPI_Status> = Vec::with_capacity(self.requests.len());
+        unsafe { statuses.set_len(self.requests.len()); }
+        try!(check(unsafe {
+            ffi::MPI_Testsome(count, raw.as_mut_ptr(), &mut outcount,
+                indices.as_mut_ptr(), statuses.as_mut_ptr())
+        }));
+        self.update(&raw);
+        if outcount == ffi::RSMPI_UNDEFINED {
+            Ok(Vec::new())
+        } else {
+            Ok(self.complete(indices, statuses, outcount))
+        }
+    }
+
+    fn count(&self) -> c_int {
+        self.requests.len().value_as().expect(
+            "Number of requests cannot be expressed as a c_int.")
+    }
+
+    fn raw_requests(&self) -> Vec<MPI_Request> {
+        self.requests.iter().map(|r| unsafe { r.as_raw() }).collect()
+    }
+
+    /// Stores the request handles that MPI updated back into the request objects.
+    fn update(&mut self, raw: &[MPI_Request]) {
+        for (request, &handle) in self.requests.iter_mut().zip(raw.iter()) {
+            unsafe { *request.as_raw_mut() = handle; }
+        }
+    }
+
+    /// Records that all requests have completed, persistent requests keep their handle.
+    fn complete_all(&mut self) {
+        for completed in self.completed.iter_mut() {
+            *completed = true;
+        }
+    }
+
+    /// Records the first `outcount` indices returned by `MPI_Waitsome()` or `MPI_Testsome()` as
+    /// completed and pairs them with their statuses.
+    fn complete(&mut self, indices: Vec<c_int>, statuses: Vec<MPI_Status>, outcount: c_int) -> Vec<(usize, Status)> {
+        let completed = &mut self.completed;
+        indices.into_iter().zip(statuses.into_iter()).take(outcount as usize)
+            .map(|(index, status)| {
+                completed[index as usize] = true;
+                (index as usize, Status::from_raw(status))
+            })
+            .collect()
+    }
+}
@@ -3,6 +3,8 @@
 const MPI_Datatype RSMPI_FLOAT = MPI_FLOAT;
 const MPI_Datatype RSMPI_DOUBLE = MPI_DOUBLE;
 
+const MPI_Datatype RSMPI_CHAR = MPI_CHAR;
+
 const MPI_Datatype RSMPI_INT8_T = MPI_INT8_T;
 const MPI_Datatype RSMPI_INT16_T = MPI_INT16_T;
 const MPI_Datatype RSMPI_INT32_T = MPI_INT32_T;
@@ -13,8 +15,46 @@ const MPI_Datatype RSMPI_UINT16_T = MPI_UINT16_T;
 const MPI_Datatype RSMPI_UINT32_T = MPI_UINT32_T;
 const MPI_Datatype RSMPI_UINT64_T = MPI_UINT64_T;
 
+const MPI_Datatype RSMPI_C_BOOL = MPI_C_BOOL;
+
+const MPI_Datatype RSMPI_C_FLOAT_COMPLEX = MPI_C_FLOAT_COMPLEX;
+const MPI_Datatype RSMPI_C_DOUBLE_COMPLEX = MPI_C_DOUBLE_COMPLEX;
+
+const MPI_Datatype RSMPI_PACKED = MPI_PACKED;
+const MPI_Datatype RSMPI_BYTE = MPI_BYTE;
+
+const MPI_Datatype RSMPI_FLOAT_INT = MPI_FLOAT_INT;
+const MPI_Datatype RSMPI_DOUBLE_INT = MPI_DOUBLE_INT;
+const MPI_Datatype RSMPI_SHORT_INT = MPI_SHORT_INT;
+const MPI_Datatype RSMPI_2INT = MPI_2INT;
+
 const MPI_Datatype RSMPI_DATATYPE_NULL = MPI_DATATYPE_NULL;
 
+const int RSMPI_ORDER_C = MPI_ORDER_C;
+const int RSMPI_ORDER_FORTRAN = MPI_ORDER_FORTRAN;
+
+const int RSMPI_DISTRIBUTE_BLOCK = MPI_DISTRIBUTE_BLOCK;
+const int RSMPI_DISTRIBUTE_CYCLIC = MPI_DISTRIBUTE_CYCLIC;
+const int RSMPI_DISTRIBUTE_NONE = MPI_DISTRIBUTE_NONE;
+const int RSMPI_DISTRIBUTE_DFLT_DARG = MPI_DISTRIBUTE_DFLT_DARG;
+
+const int RSMPI_COMBINER_NAMED = MPI_COMBINER_NAMED;
+const int RSMPI_COMBINER_DUP = MPI_COMBINER_DUP;
+const int RSMPI_COMBINER_CONTIGUOUS = MPI_COMBINER_CONTIGUOUS;
+const int RSMPI_COMBINER_VECTOR = MPI_COMBINER_VECTOR;
+const int RSMPI_COMBINER_HVECTOR = MPI_COMBINER_HVECTOR;
+const int RSMPI_COMBINER_INDEXED = MPI_COMBINER_INDEXED;
+const int RSMPI_COMBINER_HINDEXED = MPI_COMBINER_HINDEXED;
+const int RSMPI_COMBINER_INDEXED_BLOCK = MPI_COMBINER_INDEXED_BLOCK;
+const int RSMPI_COMBINER_HINDEXED_BLOCK = MPI_COMBINER_HINDEXED_BLOCK;
+const int RSMPI_COMBINER_STRUCT = MPI_COMBINER_STRUCT;
+const int RSMPI_COMBINER_SUBARRAY = MPI_COMBINER_SUBARRAY;
+const int RSMPI_COMBINER_DARRAY = MPI_COMBINER_DARRAY;
+const int RSMPI_COMBINER_F90_REAL = MPI_COMBINER_F90_REAL;
+const int RSMPI_COMBINER_F90_COMPLEX = MPI_COMBINER_F90_COMPLEX;
+const int RSMPI_COMBINER_F90_INTEGER = MPI_COMBINER_F90_INTEGER;
+const int RSMPI_COMBINER_RESIZED = MPI_COMBINER_RESIZED;
+
 const MPI_Comm RSMPI_COMM_WORLD = MPI_COMM_WORLD;
 const MPI_Comm RSMPI_COMM_NULL = MPI_COMM_NULL;
 const MPI_Comm RSMPI_COMM_SELF = MPI_COMM_SELF;
@@ -24,6 +64,7 @@ const MPI_Group RSMPI_GROUP_NULL = MPI_GROUP_NULL;
 const int RSMPI_UNDEFINED = MPI_UNDEFINED;
 
 const int RSMPI_PROC_NULL = MPI_PROC_NULL;
+const int RSMPI_ROOT = MPI_ROOT;
 const int RSMPI_ANY_SOURCE = MPI_ANY_SOURCE;
 const int RSMPI_ANY_TAG = MPI_ANY_TAG;
 
@@ -34,11 +75,20 @@ const MPI_Request RSMPI_REQUEST_NULL = MPI_REQUEST_NULL;
 
 MPI_Status* const RSMPI_STATUS_IGNORE = MPI_STATUS_IGNORE;
 
+const MPI_Info RSMPI_INFO_NULL = MPI_INFO_NULL;
+
+int* const RSMPI_UNWEIGHTED = MPI_UNWEIGHTED;
+int* const RSMPI_WEIGHTS_EMPTY = MPI_WEIGHTS_EMPTY;
+
 const int RSMPI_IDENT = MPI_IDENT;
 const int RSMPI_CONGRUENT = MPI_CONGRUENT;
 const int RSMPI_SIMILAR = MPI_SIMILAR;
 const int RSMPI_UNEQUAL = MPI_UNEQUAL;
 
+const int RSMPI_GRAPH = MPI_GRAPH;
+const int RSMPI_CART = MPI_CART;
+const int RSMPI_DIST_GRAPH = MPI_DIST_GRAPH;
+
 const int RSMPI_THREAD_SINGLE = MPI_THREAD_SINGLE;
 const int RSMPI_THREAD_FUNNELED = MPI_THREAD_FUNNELED;
 const int RSMPI_THREAD_SERIALIZED = MPI_THREAD_SERIALIZED;
@@ -46,6 +96,8 @@ const int RSMPI_THREAD_MULTIPLE = MPI_THREAD_MULTIPLE;
 
 const int RSMPI_MAX_LIBRARY_VERSION_STRING = MPI_MAX_LIBRARY_VERSION_STRING;
 const int RSMPI_MAX_PROCESSOR_NAME = MPI_MAX_PROCESSOR_NAME;
+const int RSMPI_MAX_ERROR_STRING = MPI_MAX_ERROR_STRING;
+const int RSMPI_MAX_OBJECT_NAME = MPI_MAX_OBJECT_NAME;
 
 const MPI_Op RSMPI_MAX = MPI_MAX;
 const MPI_Op RSMPI_MIN = MPI_MIN;
@@ -57,6 +109,20 @@ const MPI_Op RSMPI_LOR = MPI_LOR;
 const MPI_Op RSMPI_BOR = MPI_BOR;
 const MPI_Op RSMPI_LXOR = MPI_LXOR;
 const MPI_Op RSMPI_BXOR = MPI_BXOR;
+const MPI_Op RSMPI_MINLOC = MPI_MINLOC;
+const MPI_Op RSMPI_MAXLOC = MPI_MAXLOC;
+const MPI_Op RSMPI_REPLACE = MPI_REPLACE;
+const MPI_Op RSMPI_NO_OP = MPI_NO_OP;
+const MPI_Op RSMPI_OP_NULL = MPI_OP_NULL;
+
+const int RSMPI_SUCCESS = MPI_SUCCESS;
+const int RSMPI_ERR_ARG = MPI_ERR_ARG;
+const int RSMPI_ERR_OTHER = MPI_ERR_OTHER;
+const int RSMPI_ERR_OP = MPI_ERR_OP;
+const int RSMPI_ERR_UNSUPPORTED_OPERATION = MPI_ERR_UNSUPPORTED_OPERATION;
+const MPI_Errhandler RSMPI_ERRORS_ARE_FATAL = MPI_ERRORS_ARE_FATAL;
+const MPI_Errhandler RSMPI_ERRORS_RETURN = MPI_ERRORS_RETURN;
+const MPI_Errhandler RSMPI_ERRHANDLER_NULL = MPI_ERRHANDLER_NULL;
 
 double RSMPI_Wtime() {
   return MPI_Wtime();
@@ -65,3 +131,186 @@ double RSMPI_Wtime() {
 double RSMPI_Wtick() {
   return MPI_Wtick();
 }
+
+/* Implemented in Rust, see `src/error/mod.rs` */
+void rsmpi_comm_error_handler(MPI_Comm* comm, int* code);
+
+/* The error handler function takes trailing variadic arguments that are reserved for the
+ * implementation and which a Rust function cannot accept, so it forwards to one that does not. */
+static void RSMPI_Comm_errhandler_trampoline(MPI_Comm* comm, int* code, ...) {
+  rsmpi_comm_error_handler(comm, code);
+}
+
+int RSMPI_Comm_create_errhandler(MPI_Errhandler* errhandler) {
+  return MPI_Comm_create_errhandler(RSMPI_Comm_errhandler_trampoline, errhandler);
+}
+
+/* Persistent collective operations were introduced in MPI 4.0. The wrappers exist regardless of
+ * the version of the library so that the bindings can be generated from any version. */
+#if MPI_VERSION >= 4
+int RSMPI_Barrier_init(MPI_Comm comm, MPI_Request* request) {
+  return MPI_Barrier_init(comm, MPI_INFO_NULL, request);
+}
+
+int RSMPI_Bcast_init(void* buffer, int count, MPI_Datatype datatype, int root, MPI_Comm comm,
+    MPI_Request* request) {
+  return MPI_Bcast_init(buffer, count, datatype, root, comm, MPI_INFO_NULL, request);
+}
+
+int RSMPI_Allgather_init(const void* sendbuf, int sendcount, MPI_Datatype sendtype, void* recvbuf,
+    int recvcount, MPI_Datatype recvtype, MPI_Comm comm, MPI_Request* request) {
+  return MPI_Allgather_init(sendbuf, sendcount, sendtype, recvbuf, recvcount, recvtype, comm,
+      MPI_INFO_NULL, request);
+}
+
+int RSMPI_Alltoall_init(const void* sendbuf, int sendcount, MPI_Datatype sendtype, void* recvbuf,
+    int recvcount, MPI_Datatype recvtype, MPI_Comm comm, MPI_Request* request) {
+  return MPI_Alltoall_init(sendbuf, sendcount, sendtype, recvbuf, recvcount, recvtype, comm,
+      MPI_INFO_NULL, request);
+}
+
+int RSMPI_Reduce_init(const void* sendbuf, void* recvbuf, int count, MPI_Datatype datatype,
+    MPI_Op op, int root, MPI_Comm comm, MPI_Request* request) {
+  return MPI_Reduce_init(sendbuf, recvbuf, count, datatype, op, root, comm, MPI_INFO_NULL,
+      request);
+}
+
+int RSMPI_Allreduce_init(const void* sendbuf, void* recvbuf, int count, MPI_Datatype datatype,
+    MPI_Op op, MPI_Comm comm, MPI_Request* request) {
+  return MPI_Allreduce_init(sendbuf, recvbuf, count, datatype, op, comm, MPI_INFO_NULL, request);
+}
+
+int RSMPI_Gather_init(const void* sendbuf, int sendcount, MPI_Datatype sendtype, void* recvbuf,
+    int recvcount, MPI_Datatype recvtype, int root, MPI_Comm comm, MPI_Request* request) {
+  return MPI_Gather_init(sendbuf, sendcount, sendtype, recvbuf, recvcount, recvtype, root, comm,
+      MPI_INFO_NULL, request);
+}
+
+int RSMPI_Scatter_init(const void* sendbuf, int sendcount, MPI_Datatype sendtype, void* recvbuf,
+    int recvcount, MPI_Datatype recvtype, int root, MPI_Comm comm, MPI_Request* request) {
+  return MPI_Scatter_init(sendbuf, sendcount, sendtype, recvbuf, recvcount, recvtype, root, comm,
+      MPI_INFO_NULL, request);
+}
+
+int RSMPI_Reduce_scatter_block_init(const void* sendbuf, void* recvbuf, int recvcount,
+    MPI_Datatype datatype, MPI_Op op, MPI_Comm comm, MPI_Request* request) {
+  return MPI_Reduce_scatter_block_init(sendbuf, recvbuf, recvcount, datatype, op, comm,
+      MPI_INFO_NULL, request);
+}
+
+int RSMPI_Reduce_scatter_init(const void* sendbuf, void* recvbuf, const int recvcounts[],
+    MPI_Datatype datatype, MPI_Op op, MPI_Comm comm, MPI_Request* request) {
+  return MPI_Reduce_scatter_init(sendbuf, recvbuf, recvcounts, datatype, op, comm, MPI_INFO_NULL,
+      request);
+}
+
+int RSMPI_Scan_init(const void* sendbuf, void* recvbuf, int count, MPI_Datatype datatype,
+    MPI_Op op, MPI_Comm comm, MPI_Request* request) {
+  return MPI_Scan_init(sendbuf, recvbuf, count, datatype, op, comm, MPI_INFO_NULL, request);
+}
+
+int RSMPI_Exscan_init(const void* sendbuf, void* recvbuf, int count, MPI_Datatype datatype,
+    MPI_Op op, MPI_Comm comm, MPI_Request* request) {
+  return MPI_Exscan_init(sendbuf, recvbuf, count, datatype, op, comm, MPI_INFO_NULL, request);
+}
+#else
+int RSMPI_Barrier_init(MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Bcast_init(void* buffer, int count, MPI_Datatype datatype, int root, MPI_Comm comm,
+    MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Allgather_init(const void* sendbuf, int sendcount, MPI_Datatype sendtype, void* recvbuf,
+    int recvcount, MPI_Datatype recvtype, MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Alltoall_init(const void* sendbuf, int sendcount, MPI_Datatype sendtype, void* recvbuf,
+    int recvcount, MPI_Datatype recvtype, MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Reduce_init(const void* sendbuf, void* recvbuf, int count, MPI_Datatype datatype,
+    MPI_Op op, int root, MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Allreduce_init(const void* sendbuf, void* recvbuf, int count, MPI_Datatype datatype,
+    MPI_Op op, MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Gather_init(const void* sendbuf, int sendcount, MPI_Datatype sendtype, void* recvbuf,
+    int recvcount, MPI_Datatype recvtype, int root, MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Scatter_init(const void* sendbuf, int sendcount, MPI_Datatype sendtype, void* recvbuf,
+    int recvcount, MPI_Datatype recvtype, int root, MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Reduce_scatter_block_init(const void* sendbuf, void* recvbuf, int recvcount,
+    MPI_Datatype datatype, MPI_Op op, MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Reduce_scatter_init(const void* sendbuf, void* recvbuf, const int recvcounts[],
+    MPI_Datatype datatype, MPI_Op op, MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Scan_init(const void* sendbuf, void* recvbuf, int count, MPI_Datatype datatype,
+    MPI_Op op, MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Exscan_init(const void* sendbuf, void* recvbuf, int count, MPI_Datatype datatype,
+    MPI_Op op, MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+#endif
+
+/* Partitioned point-to-point communication was introduced in MPI 4.0 as well. */
+#if MPI_VERSION >= 4
+int RSMPI_Psend_init(const void* buf, int partitions, MPI_Count count, MPI_Datatype datatype,
+    int dest, int tag, MPI_Comm comm, MPI_Request* request) {
+  return MPI_Psend_init(buf, partitions, count, datatype, dest, tag, comm, MPI_INFO_NULL,
+      request);
+}
+
+int RSMPI_Precv_init(void* buf, int partitions, MPI_Count count, MPI_Datatype datatype,
+    int source, int tag, MPI_Comm comm, MPI_Request* request) {
+  return MPI_Precv_init(buf, partitions, count, datatype, source, tag, comm, MPI_INFO_NULL,
+      request);
+}
+
+int RSMPI_Pready(int partition, MPI_Request request) {
+  return MPI_Pready(partition, request);
+}
+
+int RSMPI_Parrived(MPI_Request request, int partition, int* flag) {
+  return MPI_Parrived(request, partition, flag);
+}
+#else
+int RSMPI_Psend_init(const void* buf, int partitions, MPI_Count count, MPI_Datatype datatype,
+    int dest, int tag, MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Precv_init(void* buf, int partitions, MPI_Count count, MPI_Datatype datatype,
+    int source, int tag, MPI_Comm comm, MPI_Request* request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Pready(int partition, MPI_Request request) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+
+int RSMPI_Parrived(MPI_Request request, int partition, int* flag) {
+  return MPI_ERR_UNSUPPORTED_OPERATION;
+}
+#endif
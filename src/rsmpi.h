@@ -5,6 +5,8 @@
 extern const MPI_Datatype RSMPI_FLOAT;
 extern const MPI_Datatype RSMPI_DOUBLE;
 
+extern const MPI_Datatype RSMPI_CHAR;
+
 extern const MPI_Datatype RSMPI_INT8_T;
 extern const MPI_Datatype RSMPI_INT16_T;
 extern const MPI_Datatype RSMPI_INT32_T;
@@ -15,8 +17,46 @@ extern const MPI_Datatype RSMPI_UINT16_T;
 extern const MPI_Datatype RSMPI_UINT32_T;
 extern const MPI_Datatype RSMPI_UINT64_T;
 
+extern const MPI_Datatype RSMPI_C_BOOL;
+
+extern const MPI_Datatype RSMPI_C_FLOAT_COMPLEX;
+extern const MPI_Datatype RSMPI_C_DOUBLE_COMPLEX;
+
+extern const MPI_Datatype RSMPI_PACKED;
+extern const MPI_Datatype RSMPI_BYTE;
+
+extern const MPI_Datatype RSMPI_FLOAT_INT;
+extern const MPI_Datatype RSMPI_DOUBLE_INT;
+extern const MPI_Datatype RSMPI_SHORT_INT;
+extern const MPI_Datatype RSMPI_2INT;
+
 extern const MPI_Datatype RSMPI_DATATYPE_NULL;
 
+extern const int RSMPI_ORDER_C;
+extern const int RSMPI_ORDER_FORTRAN;
+
+extern const int RSMPI_DISTRIBUTE_BLOCK;
+extern const int RSMPI_DISTRIBUTE_CYCLIC;
+extern const int RSMPI_DISTRIBUTE_NONE;
+extern const int RSMPI_DISTRIBUTE_DFLT_DARG;
+
+extern const int RSMPI_COMBINER_NAMED;
+extern const int RSMPI_COMBINER_DUP;
+extern const int RSMPI_COMBINER_CONTIGUOUS;
+extern const int RSMPI_COMBINER_VECTOR;
+extern const int RSMPI_COMBINER_HVECTOR;
+extern const int RSMPI_COMBINER_INDEXED;
+extern const int RSMPI_COMBINER_HINDEXED;
+extern const int RSMPI_COMBINER_INDEXED_BLOCK;
+extern const int RSMPI_COMBINER_HINDEXED_BLOCK;
+extern const int RSMPI_COMBINER_STRUCT;
+extern const int RSMPI_COMBINER_SUBARRAY;
+extern const int RSMPI_COMBINER_DARRAY;
+extern const int RSMPI_COMBINER_F90_REAL;
+extern const int RSMPI_COMBINER_F90_COMPLEX;
+extern const int RSMPI_COMBINER_F90_INTEGER;
+extern const int RSMPI_COMBINER_RESIZED;
+
 extern const MPI_Comm RSMPI_COMM_WORLD;
 extern const MPI_Comm RSMPI_COMM_NULL;
 extern const MPI_Comm RSMPI_COMM_SELF;
@@ -26,6 +66,7 @@ extern const MPI_Group RSMPI_GROUP_NULL;
 extern const int RSMPI_UNDEFINED;
 
 extern const int RSMPI_PROC_NULL;
+extern const int RSMPI_ROOT;
 extern const int RSMPI_ANY_SOURCE;
 extern const int RSMPI_ANY_TAG;
 
@@ -36,11 +77,20 @@ extern const MPI_Request RSMPI_REQUEST_NULL;
 
 extern MPI_Status* const RSMPI_STATUS_IGNORE;
 
+extern const MPI_Info RSMPI_INFO_NULL;
+
+extern int* const RSMPI_UNWEIGHTED;
+extern int* const RSMPI_WEIGHTS_EMPTY;
+
 extern const int RSMPI_IDENT;
 extern const int RSMPI_CONGRUENT;
 extern const int RSMPI_SIMILAR;
 extern const int RSMPI_UNEQUAL;
 
+extern const int RSMPI_GRAPH;
+extern const int RSMPI_CART;
+extern const int RSMPI_DIST_GRAPH;
+
 extern const int RSMPI_THREAD_SINGLE;
 extern const int RSMPI_THREAD_FUNNELED;
 extern const int RSMPI_THREAD_SERIALIZED;
@@ -48,6 +98,8 @@ extern const int RSMPI_THREAD_MULTIPLE;
 
 extern const int RSMPI_MAX_LIBRARY_VERSION_STRING;
 extern const int RSMPI_MAX_PROCESSOR_NAME;
+extern const int RSMPI_MAX_ERROR_STRING;
+extern const int RSMPI_MAX_OBJECT_NAME;
 
 extern const MPI_Op RSMPI_MAX;
 extern const MPI_Op RSMPI_MIN;
@@ -59,7 +111,54 @@ extern const MPI_Op RSMPI_LOR;
 extern const MPI_Op RSMPI_BOR;
 extern const MPI_Op RSMPI_LXOR;
 extern const MPI_Op RSMPI_BXOR;
+extern const MPI_Op RSMPI_MINLOC;
+extern const MPI_Op RSMPI_MAXLOC;
+extern const MPI_Op RSMPI_REPLACE;
+extern const MPI_Op RSMPI_NO_OP;
+extern const MPI_Op RSMPI_OP_NULL;
+
+extern const int RSMPI_SUCCESS;
+extern const int RSMPI_ERR_ARG;
+extern const int RSMPI_ERR_OTHER;
+extern const int RSMPI_ERR_OP;
+extern const int RSMPI_ERR_UNSUPPORTED_OPERATION;
+extern const MPI_Errhandler RSMPI_ERRORS_ARE_FATAL;
+extern const MPI_Errhandler RSMPI_ERRORS_RETURN;
+extern const MPI_Errhandler RSMPI_ERRHANDLER_NULL;
 
 double RSMPI_Wtime();
 double RSMPI_Wtick();
+
+int RSMPI_Comm_create_errhandler(MPI_Errhandler* errhandler);
+
+int RSMPI_Barrier_init(MPI_Comm comm, MPI_Request* request);
+int RSMPI_Bcast_init(void* buffer, int count, MPI_Datatype datatype, int root, MPI_Comm comm,
+    MPI_Request* request);
+int RSMPI_Allgather_init(const void* sendbuf, int sendcount, MPI_Datatype sendtype, void* recvbuf,
+    int recvcount, MPI_Datatype recvtype, MPI_Comm comm, MPI_Request* request);
+int RSMPI_Alltoall_init(const void* sendbuf, int sendcount, MPI_Datatype sendtype, void* recvbuf,
+    int recvcount, MPI_Datatype recvtype, MPI_Comm comm, MPI_Request* request);
+int RSMPI_Reduce_init(const void* sendbuf, void* recvbuf, int count, MPI_Datatype datatype,
+    MPI_Op op, int root, MPI_Comm comm, MPI_Request* request);
+int RSMPI_Allreduce_init(const void* sendbuf, void* recvbuf, int count, MPI_Datatype datatype,
+    MPI_Op op, MPI_Comm comm, MPI_Request* request);
+int RSMPI_Gather_init(const void* sendbuf, int sendcount, MPI_Datatype sendtype, void* recvbuf,
+    int recvcount, MPI_Datatype recvtype, int root, MPI_Comm comm, MPI_Request* request);
+int RSMPI_Scatter_init(const void* sendbuf, int sendcount, MPI_Datatype sendtype, void* recvbuf,
+    int recvcount, MPI_Datatype recvtype, int root, MPI_Comm comm, MPI_Request* request);
+int RSMPI_Reduce_scatter_block_init(const void* sendbuf, void* recvbuf, int recvcount,
+    MPI_Datatype datatype, MPI_Op op, MPI_Comm comm, MPI_Request* request);
+int RSMPI_Reduce_scatter_init(const void* sendbuf, void* recvbuf, const int recvcounts[],
+    MPI_Datatype datatype, MPI_Op op, MPI_Comm comm, MPI_Request* request);
+int RSMPI_Scan_init(const void* sendbuf, void* recvbuf, int count, MPI_Datatype datatype,
+    MPI_Op op, MPI_Comm comm, MPI_Request* request);
+int RSMPI_Exscan_init(const void* sendbuf, void* recvbuf, int count, MPI_Datatype datatype,
+    MPI_Op op, MPI_Comm comm, MPI_Request* request);
+
+int RSMPI_Psend_init(const void* buf, int partitions, MPI_Count count, MPI_Datatype datatype,
+    int dest, int tag, MPI_Comm comm, MPI_Request* request);
+int RSMPI_Precv_init(void* buf, int partitions, MPI_Count count, MPI_Datatype datatype,
+    int source, int tag, MPI_Comm comm, MPI_Request* request);
+int RSMPI_Pready(int partition, MPI_Request request);
+int RSMPI_Parrived(MPI_Request request, int partition, int* flag);
 #endif
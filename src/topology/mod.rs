@@ -16,9 +16,14 @@
 //!     `MPI_Comm_split_type()`
 //!   - **6.4.4**: Info, `MPI_Comm_set_info()`, `MPI_Comm_get_info()`
 //! - **6.6**: Inter-communication
+//!   - **6.6.2**: Inter-communicator operations, `MPI_Comm_split()` on inter-communicators
+//!     returns a `UserCommunicator`
 //! - **6.7**: Caching
 //! - **6.8**: Naming objects
 //! - **7**: Process topologies
+//!   - **7.5.1**: Cartesian convenience function, `MPI_Dims_create()` with non-zero constraints
+//!   - **7.5.5**: Topology inquiry functions, `MPI_Topo_test()`
+//!   - **7.5.8**: Low-level topology functions, `MPI_Cart_map()`, `MPI_Graph_map()`
 //! - **Parts of sections**: 8, 10, 12
 use std::{mem, ptr};
 use std::cmp::Ordering;
@@ -29,7 +34,8 @@ use libc::{c_char, c_int, c_double};
 
 use conv::ConvUtil;
 
-use super::Tag;
+use super::{Count, Error, Tag};
+use super::check;
 use ffi;
 use ffi::{MPI_Comm, MPI_Group};
 
@@ -189,7 +195,10 @@ fn is_initialized() -> bool {
 ///
 /// If the MPI library has not been initialized so far, initializes and returns a representation
 /// of the MPI communication `Universe` which provides access to additional functions.
-/// Otherwise returns `None`.
+/// Otherwise, or if the initialization fails, returns `None`.
+///
+/// The `MPI_ERRORS_RETURN` error handler is installed on the world communicator and
+/// `MPI_COMM_SELF` so that errors in subsequent calls are reported as `Err` values.
 ///
 /// Equivalent to: `initialize_with_threading(Threading::Single)`
 ///
@@ -209,7 +218,7 @@ pub fn initialize() -> Option<Universe> {
 /// If the MPI library has not been initialized so far, tries to initialize with the desired level
 /// of multithreading support and returns the MPI communication `Universe` with access to
 /// additional functions as well as the level of multithreading actually supported by the
-/// implementation. Otherwise returns `None`.
+/// implementation. Otherwise, or if the initialization fails, returns `None`.
 ///
 /// # Examples
 /// See `examples/init_with_threading.rs`
@@ -222,11 +231,19 @@ pub fn initialize_with_threading(threading: Threading) -> Option<(Universe, Thre
         None
     } else {
         let mut provided: c_int = unsafe { mem::uninitialized() };
-        unsafe {
+        let initialized = unsafe {
             ffi::MPI_Init_thread(ptr::null_mut(), ptr::null_mut(), threading.as_raw(),
-                &mut provided);
+                &mut provided) == ffi::RSMPI_SUCCESS
+            && ffi::MPI_Comm_set_errhandler(ffi::RSMPI_COMM_WORLD, ffi::RSMPI_ERRORS_RETURN)
+                == ffi::RSMPI_SUCCESS
+            && ffi::MPI_Comm_set_errhandler(ffi::RSMPI_COMM_SELF, ffi::RSMPI_ERRORS_RETURN)
+                == ffi::RSMPI_SUCCESS
+        };
+        if initialized {
+            Some((Universe(PhantomData), provided.into()))
+        } else {
+            None
         }
-        Some((Universe(PhantomData), provided.into()))
     }
 }
 
@@ -259,6 +276,20 @@ impl Communicator for SystemCommunicator {
 /// 6.4
 pub struct UserCommunicator(MPI_Comm);
 
+impl UserCommunicator {
+    /// Wraps a raw communicator handle returned by a communicator constructor.
+    ///
+    /// Constructors return `MPI_COMM_NULL` to processes that are not members of the new
+    /// communicator, in which case `None` is returned.
+    fn from_raw(raw: MPI_Comm) -> Option<UserCommunicator> {
+        if raw == ffi::RSMPI_COMM_NULL {
+            None
+        } else {
+            Some(UserCommunicator(raw))
+        }
+    }
+}
+
 impl Communicator for UserCommunicator {
     type Out = UserCommunicator;
     fn communicator(&self) -> &Self::Out {
@@ -280,6 +311,535 @@ impl Drop for UserCommunicator {
     }
 }
 
+/// An inter-communicator
+///
+/// Connects two disjoint groups of processes, the local group containing the calling process and
+/// the remote group. `Rank`s used to address other processes refer to the remote group, i.e.
+/// `process_at_rank()` identifies processes of the remote group while `rank()` and `size()` are
+/// those of the local group. Consequently, `this_process()` cannot be used as a destination or
+/// source on an inter-communicator.
+///
+/// In rooted collective operations (e.g. broadcast, gather, scatter and reduce) the root process
+/// of one group takes part via `root_process()`, the other processes in its group via
+/// `null_process()`, and all processes of the remote group via `process_at_rank()` with the rank
+/// of the root in its group.
+///
+/// # Examples
+///
+/// See `examples/intercommunicator.rs`
+///
+/// # Standard section(s)
+///
+/// 6.6
+pub struct InterCommunicator(MPI_Comm);
+
+impl InterCommunicator {
+    /// Number of processes in the remote group
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.6.1
+    pub fn remote_size(&self) -> Rank {
+        let mut res: Rank = unsafe { mem::uninitialized() };
+        unsafe { ffi::MPI_Comm_remote_size(self.0, &mut res); }
+        res
+    }
+
+    /// The remote group
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.6.1
+    pub fn remote_group(&self) -> Result<UserGroup, Error> {
+        let mut group: MPI_Group = unsafe { mem::uninitialized() };
+        try!(check(unsafe { ffi::MPI_Comm_remote_group(self.0, &mut group) }));
+        Ok(UserGroup(group))
+    }
+
+    /// Duplicate the inter-communicator.
+    ///
+    /// Unlike `CommunicatorExt::duplicate()`, this keeps the remote group accessible through the
+    /// type of the new communicator.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/intercommunicator.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.2
+    pub fn duplicate(&self) -> Result<InterCommunicator, Error> {
+        let mut newcomm: MPI_Comm = unsafe { mem::uninitialized() };
+        try!(check(unsafe { ffi::MPI_Comm_dup(self.0, &mut newcomm) }));
+        Ok(InterCommunicator(newcomm))
+    }
+
+    /// Merge the local and the remote group into an intra-communicator.
+    ///
+    /// The processes of the group that passes `high = false` are ordered before the processes of
+    /// the group that passes `high = true`. If both groups pass the same value, the order is
+    /// arbitrary.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/intercommunicator.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.6.2
+    pub fn merge(&self, high: bool) -> Result<UserCommunicator, Error> {
+        let mut newcomm: MPI_Comm = unsafe { mem::uninitialized() };
+        try!(check(unsafe { ffi::MPI_Intercomm_merge(self.0, high as c_int, &mut newcomm) }));
+        Ok(UserCommunicator(newcomm))
+    }
+
+    /// Identifies the calling process as the root of a rooted collective operation.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/intercommunicator.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.2.2
+    pub fn root_process(&self) -> Identifier<InterCommunicator> {
+        Identifier { comm: self, rank: ffi::RSMPI_ROOT }
+    }
+}
+
+impl Communicator for InterCommunicator {
+    type Out = InterCommunicator;
+    fn communicator(&self) -> &Self::Out {
+        self
+    }
+}
+
+impl AsRaw for InterCommunicator {
+    type Raw = MPI_Comm;
+    unsafe fn as_raw(&self) -> Self::Raw { self.0 }
+}
+
+impl RawCommunicator for InterCommunicator { }
+
+impl Drop for InterCommunicator {
+    fn drop(&mut self) {
+        unsafe { ffi::MPI_Comm_free(&mut self.0); }
+        assert_eq!(self.0, ffi::RSMPI_COMM_NULL);
+    }
+}
+
+/// A communicator with a Cartesian process topology
+///
+/// Processes are arranged in a grid with `num_dimensions()` dimensions, each of which may be
+/// periodic. Processes are addressed by their coordinates in the grid.
+///
+/// # Examples
+///
+/// See `examples/cartesian.rs`
+///
+/// # Standard section(s)
+///
+/// 7.5.1
+pub struct CartesianCommunicator(MPI_Comm);
+
+impl CartesianCommunicator {
+    /// Wraps a raw communicator handle returned by a topology constructor.
+    ///
+    /// Processes that are not part of the new grid receive `MPI_COMM_NULL` in which case `None`
+    /// is returned.
+    fn from_raw(raw: MPI_Comm) -> Option<CartesianCommunicator> {
+        if raw == ffi::RSMPI_COMM_NULL {
+            None
+        } else {
+            Some(CartesianCommunicator(raw))
+        }
+    }
+
+    /// Number of dimensions of the grid
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.5
+    pub fn num_dimensions(&self) -> Count {
+        let mut res: Count = unsafe { mem::uninitialized() };
+        unsafe { ffi::MPI_Cartdim_get(self.0, &mut res); }
+        res
+    }
+
+    /// The extents and periodicity of the grid as well as the coordinates of the calling process
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.5
+    pub fn layout(&self) -> CartesianLayout {
+        let n = self.num_dimensions();
+        let len = n.value_as().expect(
+            &format!("Number of dimensions ({}) cannot be expressed as a usize.", n));
+        let mut dims = vec![0; len];
+        let mut periods: Vec<c_int> = vec![0; len];
+        let mut coords = vec![0; len];
+        unsafe {
+            ffi::MPI_Cart_get(self.0, n, dims.as_mut_ptr(), periods.as_mut_ptr(),
+                coords.as_mut_ptr());
+        }
+        CartesianLayout {
+            dims: dims,
+            periods: periods.into_iter().map(|p| p != 0).collect(),
+            coords: coords
+        }
+    }
+
+    /// The coordinates of the calling process in the grid
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.5
+    pub fn coordinates(&self) -> Vec<Count> {
+        self.layout().coords
+    }
+
+    /// The coordinates of the process at rank `rank` in the grid
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.5
+    pub fn coordinates_of(&self, rank: Rank) -> Result<Vec<Count>, Error> {
+        let n = self.num_dimensions();
+        let mut coords = vec![0; n.value_as().expect(
+            &format!("Number of dimensions ({}) cannot be expressed as a usize.", n))];
+        try!(check(unsafe { ffi::MPI_Cart_coords(self.0, rank, n, coords.as_mut_ptr()) }));
+        Ok(coords)
+    }
+
+    /// The rank of the process at `coords` in the grid
+    ///
+    /// Coordinates in periodic dimensions are wrapped around, coordinates outside of
+    /// non-periodic dimensions are erroneous.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.5
+    pub fn rank_at_coordinates(&self, coords: &[Count]) -> Result<Rank, Error> {
+        assert_eq!(self.num_dimensions(), coords.count());
+        let mut res: Rank = unsafe { mem::uninitialized() };
+        try!(check(unsafe { ffi::MPI_Cart_rank(self.0, coords.as_ptr(), &mut res) }));
+        Ok(res)
+    }
+
+    /// The neighbors of the calling process along dimension `dimension` at distance
+    /// `displacement`
+    ///
+    /// Returns a tuple of `(source, destination)` where `destination` is found by moving
+    /// `displacement` steps in positive direction and `source` by moving in negative direction.
+    /// Across the boundary of a non-periodic dimension, the null process is returned.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/cartesian.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.6
+    pub fn shift(&self, dimension: Count, displacement: Count) -> Result<(Identifier<CartesianCommunicator>, Identifier<CartesianCommunicator>), Error> {
+        let mut source: Rank = unsafe { mem::uninitialized() };
+        let mut destination: Rank = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Cart_shift(self.0, dimension, displacement, &mut source, &mut destination)
+        }));
+        Ok((Identifier { comm: self, rank: source }, Identifier { comm: self, rank: destination }))
+    }
+
+    /// Partition the grid into lower-dimensional subgrids.
+    ///
+    /// Dimensions for which `retain` is `true` are kept in the subgrid, the others are dropped.
+    /// Returns the subgrid the calling process belongs to.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/cartesian.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.7
+    pub fn sub(&self, retain: &[bool]) -> Result<CartesianCommunicator, Error> {
+        let retain = retain.iter().map(|&r| r as c_int).collect::<Vec<_>>();
+        assert_eq!(self.num_dimensions(), retain.count());
+        let mut newcomm: MPI_Comm = unsafe { mem::uninitialized() };
+        try!(check(unsafe { ffi::MPI_Cart_sub(self.0, retain.as_ptr(), &mut newcomm) }));
+        Ok(CartesianCommunicator(newcomm))
+    }
+}
+
+impl Communicator for CartesianCommunicator {
+    type Out = CartesianCommunicator;
+    fn communicator(&self) -> &Self::Out {
+        self
+    }
+}
+
+impl AsRaw for CartesianCommunicator {
+    type Raw = MPI_Comm;
+    unsafe fn as_raw(&self) -> Self::Raw { self.0 }
+}
+
+impl RawCommunicator for CartesianCommunicator { }
+
+impl Drop for CartesianCommunicator {
+    fn drop(&mut self) {
+        unsafe { ffi::MPI_Comm_free(&mut self.0); }
+        assert_eq!(self.0, ffi::RSMPI_COMM_NULL);
+    }
+}
+
+/// The layout of a Cartesian process grid
+///
+/// # Standard section(s)
+///
+/// 7.5.5
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CartesianLayout {
+    /// Number of processes along each dimension
+    pub dims: Vec<Count>,
+    /// Whether each dimension is periodic
+    pub periods: Vec<bool>,
+    /// Coordinates of the calling process
+    pub coords: Vec<Count>
+}
+
+/// Balance the number of processes `nnodes` across the dimensions of a Cartesian grid.
+///
+/// Returns as many extents as `ndims` such that their product is `nnodes` and they are as close
+/// to each other as possible.
+///
+/// # Examples
+///
+/// See `examples/cartesian.rs`
+///
+/// # Standard section(s)
+///
+/// 7.5.2
+pub fn dims_create(nnodes: Rank, ndims: Count) -> Result<Vec<Count>, Error> {
+    let mut dims = vec![0; ndims.value_as().expect(
+        &format!("Number of dimensions ({}) cannot be expressed as a usize.", ndims))];
+    try!(check(unsafe { ffi::MPI_Dims_create(nnodes, ndims, dims.as_mut_ptr()) }));
+    Ok(dims)
+}
+
+/// A communicator with a graph process topology
+///
+/// The whole graph is specified by all processes.
+///
+/// # Examples
+///
+/// See `examples/graph.rs`
+///
+/// # Standard section(s)
+///
+/// 7.5.3
+pub struct GraphCommunicator(MPI_Comm);
+
+impl GraphCommunicator {
+    /// Wraps a raw communicator handle returned by a topology constructor.
+    ///
+    /// Processes that are not part of the new graph receive `MPI_COMM_NULL` in which case `None`
+    /// is returned.
+    fn from_raw(raw: MPI_Comm) -> Option<GraphCommunicator> {
+        if raw == ffi::RSMPI_COMM_NULL {
+            None
+        } else {
+            Some(GraphCommunicator(raw))
+        }
+    }
+
+    /// The neighbors of the calling process
+    ///
+    /// # Examples
+    ///
+    /// See `examples/graph.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.5
+    pub fn neighbors(&self) -> Vec<Rank> {
+        let rank = self.rank();
+        self.neighbors_of(rank).expect("Failed to query the neighbors of the calling process.")
+    }
+
+    /// The neighbors of the process at rank `rank`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.5
+    pub fn neighbors_of(&self, rank: Rank) -> Result<Vec<Rank>, Error> {
+        let mut n: Count = unsafe { mem::uninitialized() };
+        try!(check(unsafe { ffi::MPI_Graph_neighbors_count(self.0, rank, &mut n) }));
+        let mut neighbors = vec![0; n.value_as().expect(
+            &format!("Number of neighbors ({}) cannot be expressed as a usize.", n))];
+        try!(check(unsafe {
+            ffi::MPI_Graph_neighbors(self.0, rank, n, neighbors.as_mut_ptr())
+        }));
+        Ok(neighbors)
+    }
+}
+
+impl Communicator for GraphCommunicator {
+    type Out = GraphCommunicator;
+    fn communicator(&self) -> &Self::Out {
+        self
+    }
+}
+
+impl AsRaw for GraphCommunicator {
+    type Raw = MPI_Comm;
+    unsafe fn as_raw(&self) -> Self::Raw { self.0 }
+}
+
+impl RawCommunicator for GraphCommunicator { }
+
+impl Drop for GraphCommunicator {
+    fn drop(&mut self) {
+        unsafe { ffi::MPI_Comm_free(&mut self.0); }
+        assert_eq!(self.0, ffi::RSMPI_COMM_NULL);
+    }
+}
+
+/// The weight of an edge in a distributed graph
+pub type Weight = c_int;
+
+/// A communicator with a distributed graph process topology
+///
+/// Every process specifies only a part of the graph. Edges are directed, so processes have
+/// separate sets of sources and destinations.
+///
+/// # Examples
+///
+/// See `examples/graph.rs`
+///
+/// # Standard section(s)
+///
+/// 7.5.4
+pub struct DistGraphCommunicator(MPI_Comm);
+
+impl DistGraphCommunicator {
+    /// The processes from which edges lead to the calling process
+    ///
+    /// # Examples
+    ///
+    /// See `examples/graph.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.5
+    pub fn sources(&self) -> Result<Adjacency, Error> {
+        self.adjacencies().map(|(sources, _)| sources)
+    }
+
+    /// The processes to which edges lead from the calling process
+    ///
+    /// # Examples
+    ///
+    /// See `examples/graph.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.5
+    pub fn destinations(&self) -> Result<Adjacency, Error> {
+        self.adjacencies().map(|(_, destinations)| destinations)
+    }
+
+    /// Returns the `(sources, destinations)` of the calling process.
+    fn adjacencies(&self) -> Result<(Adjacency, Adjacency), Error> {
+        let mut indegree: Count = unsafe { mem::uninitialized() };
+        let mut outdegree: Count = unsafe { mem::uninitialized() };
+        let mut weighted: c_int = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Dist_graph_neighbors_count(self.0, &mut indegree, &mut outdegree,
+                &mut weighted)
+        }));
+        let weighted = weighted != 0;
+
+        let mut sources = vec![0; indegree.value_as().expect(
+            &format!("In-degree ({}) cannot be expressed as a usize.", indegree))];
+        let mut source_weights = vec![0; if weighted { sources.len() } else { 0 }];
+        let mut destinations = vec![0; outdegree.value_as().expect(
+            &format!("Out-degree ({}) cannot be expressed as a usize.", outdegree))];
+        let mut destination_weights = vec![0; if weighted { destinations.len() } else { 0 }];
+        try!(check(unsafe {
+            ffi::MPI_Dist_graph_neighbors(self.0,
+                indegree, sources.as_mut_ptr(),
+                if weighted { source_weights.as_mut_ptr() } else { ffi::RSMPI_UNWEIGHTED },
+                outdegree, destinations.as_mut_ptr(),
+                if weighted { destination_weights.as_mut_ptr() } else { ffi::RSMPI_UNWEIGHTED })
+        }));
+
+        Ok((Adjacency {
+                ranks: sources,
+                weights: if weighted { Some(source_weights) } else { None }
+            },
+            Adjacency {
+                ranks: destinations,
+                weights: if weighted { Some(destination_weights) } else { None }
+            }))
+    }
+}
+
+impl Communicator for DistGraphCommunicator {
+    type Out = DistGraphCommunicator;
+    fn communicator(&self) -> &Self::Out {
+        self
+    }
+}
+
+impl AsRaw for DistGraphCommunicator {
+    type Raw = MPI_Comm;
+    unsafe fn as_raw(&self) -> Self::Raw { self.0 }
+}
+
+impl RawCommunicator for DistGraphCommunicator { }
+
+impl Drop for DistGraphCommunicator {
+    fn drop(&mut self) {
+        unsafe { ffi::MPI_Comm_free(&mut self.0); }
+        assert_eq!(self.0, ffi::RSMPI_COMM_NULL);
+    }
+}
+
+/// The processes adjacent to the calling process in a distributed graph
+///
+/// # Standard section(s)
+///
+/// 7.5.5
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Adjacency {
+    /// Ranks of the adjacent processes, one entry per edge
+    pub ranks: Vec<Rank>,
+    /// Weights of the edges, if the graph is weighted
+    pub weights: Option<Vec<Weight>>
+}
+
+/// Turns optional edge weights into the pointer expected by the distributed graph constructors.
+fn weights_as_ptr(weights: Option<&[Weight]>) -> *const Weight {
+    match weights {
+        Some(weights) if weights.is_empty() => ffi::RSMPI_WEIGHTS_EMPTY,
+        Some(weights) => weights.as_ptr(),
+        None => ffi::RSMPI_UNWEIGHTED
+    }
+}
+
+/// A communicator with a Cartesian, graph or distributed graph process topology
+///
+/// Neighborhood collective operations are only available on these communicators.
+///
+/// # Standard section(s)
+///
+/// 7.6
+pub trait TopologyCommunicator: Communicator { }
+
+impl TopologyCommunicator for CartesianCommunicator { }
+impl TopologyCommunicator for GraphCommunicator { }
+impl TopologyCommunicator for DistGraphCommunicator { }
+impl<'a, C: 'a + RawCommunicator + TopologyCommunicator> TopologyCommunicator for &'a C { }
+
 /// A color used in a communicator split
 pub struct Color(c_int);
 
@@ -336,12 +896,42 @@ pub trait CommunicatorExt: Sized + RawCommunicator {
         res
     }
 
+    /// Whether this is an inter-communicator
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.6.1
+    fn is_intercommunicator(&self) -> bool {
+        let mut res: c_int = unsafe { mem::uninitialized() };
+        unsafe { ffi::MPI_Comm_test_inter(self.as_raw(), &mut res); }
+        res != 0
+    }
+
+    /// Number of processes that can be addressed via this communicator
+    ///
+    /// The size of the remote group for inter-communicators, otherwise the same as `size()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.6.1
+    fn target_size(&self) -> Rank {
+        if self.is_intercommunicator() {
+            let mut res: Rank = unsafe { mem::uninitialized() };
+            unsafe { ffi::MPI_Comm_remote_size(self.as_raw(), &mut res); }
+            res
+        } else {
+            self.size()
+        }
+    }
+
     /// Bundles a reference to this communicator with a specific `Rank` into an `Identifier`.
     ///
+    /// On an inter-communicator, `r` is a rank in the remote group.
+    ///
     /// # Examples
     /// See `examples/broadcast.rs` `examples/gather.rs` `examples/send_receive.rs`
     fn process_at_rank(&self, r: Rank) -> Identifier<Self> {
-        assert!(0 <= r && r < self.size());
+        assert!(0 <= r && r < self.target_size());
         Identifier { comm: self, rank: r }
     }
 
@@ -388,10 +978,10 @@ pub trait CommunicatorExt: Sized + RawCommunicator {
     /// # Standard section(s)
     ///
     /// 6.4.2
-    fn duplicate(&self) -> UserCommunicator {
+    fn duplicate(&self) -> Result<UserCommunicator, Error> {
         let mut newcomm: MPI_Comm = unsafe { mem::uninitialized() };
-        unsafe { ffi::MPI_Comm_dup(self.as_raw(), &mut newcomm); }
-        UserCommunicator(newcomm)
+        try!(check(unsafe { ffi::MPI_Comm_dup(self.as_raw(), &mut newcomm) }));
+        Ok(UserCommunicator(newcomm))
     }
 
     /// Split a communicator by color.
@@ -407,7 +997,7 @@ pub trait CommunicatorExt: Sized + RawCommunicator {
     /// # Standard section(s)
     ///
     /// 6.4.2
-    fn split_by_color(&self, color: Color) -> Option<UserCommunicator> {
+    fn split_by_color(&self, color: Color) -> Result<Option<UserCommunicator>, Error> {
         self.split_by_color_with_key(color, Key::default())
     }
 
@@ -419,14 +1009,12 @@ pub trait CommunicatorExt: Sized + RawCommunicator {
     /// # Standard section(s)
     ///
     /// 6.4.2
-    fn split_by_color_with_key(&self, color: Color, key: Key) -> Option<UserCommunicator> {
+    fn split_by_color_with_key(&self, color: Color, key: Key) -> Result<Option<UserCommunicator>, Error> {
         let mut newcomm: MPI_Comm = unsafe { mem::uninitialized() };
-        unsafe { ffi::MPI_Comm_split(self.as_raw(), color.as_raw(), key, &mut newcomm); }
-        if newcomm == ffi::RSMPI_COMM_NULL {
-            None
-        } else {
-            Some(UserCommunicator(newcomm))
-        }
+        try!(check(unsafe {
+            ffi::MPI_Comm_split(self.as_raw(), color.as_raw(), key, &mut newcomm)
+        }));
+        Ok(UserCommunicator::from_raw(newcomm))
     }
 
     /// Split a communicator collectively by subgroup.
@@ -447,14 +1035,10 @@ pub trait CommunicatorExt: Sized + RawCommunicator {
     /// # Standard section(s)
     ///
     /// 6.4.2
-    fn split_by_subgroup_collective<G: ?Sized + RawGroup>(&self, group: &G) -> Option<UserCommunicator> {
+    fn split_by_subgroup_collective<G: ?Sized + RawGroup>(&self, group: &G) -> Result<Option<UserCommunicator>, Error> {
         let mut newcomm: MPI_Comm = unsafe { mem::uninitialized() };
-        unsafe { ffi::MPI_Comm_create(self.as_raw(), group.as_raw(), &mut newcomm); }
-        if newcomm == ffi::RSMPI_COMM_NULL {
-            None
-        } else {
-            Some(UserCommunicator(newcomm))
-        }
+        try!(check(unsafe { ffi::MPI_Comm_create(self.as_raw(), group.as_raw(), &mut newcomm) }));
+        Ok(UserCommunicator::from_raw(newcomm))
     }
 
     /// Split a communicator by subgroup.
@@ -468,7 +1052,7 @@ pub trait CommunicatorExt: Sized + RawCommunicator {
     /// # Standard section(s)
     ///
     /// 6.4.2
-    fn split_by_subgroup<G: ?Sized + RawGroup>(&self, group: &G) -> Option<UserCommunicator> {
+    fn split_by_subgroup<G: ?Sized + RawGroup>(&self, group: &G) -> Result<Option<UserCommunicator>, Error> {
         self.split_by_subgroup_with_tag(group, Tag::default())
     }
 
@@ -480,27 +1064,179 @@ pub trait CommunicatorExt: Sized + RawCommunicator {
     /// # Standard section(s)
     ///
     /// 6.4.2
-    fn split_by_subgroup_with_tag<G: ?Sized + RawGroup>(&self, group: &G, tag: Tag) -> Option<UserCommunicator> {
+    fn split_by_subgroup_with_tag<G: ?Sized + RawGroup>(&self, group: &G, tag: Tag) -> Result<Option<UserCommunicator>, Error> {
         let mut newcomm: MPI_Comm = unsafe { mem::uninitialized() };
-        unsafe {
-            ffi::MPI_Comm_create_group(self.as_raw(), group.as_raw(), tag, &mut newcomm);
-        }
-        if newcomm == ffi::RSMPI_COMM_NULL {
-            None
-        } else {
-            Some(UserCommunicator(newcomm))
-        }
+        try!(check(unsafe {
+            ffi::MPI_Comm_create_group(self.as_raw(), group.as_raw(), tag, &mut newcomm)
+        }));
+        Ok(UserCommunicator::from_raw(newcomm))
+    }
+
+    /// Create an inter-communicator between the group of this intra-communicator and another
+    /// disjoint group.
+    ///
+    /// All processes of both groups call this function. The processes at rank `local_leader` in
+    /// this communicator and at rank `remote_leader` in `peer` act as leaders of the two groups
+    /// and have to be able to communicate via `peer`. Only the leaders need to pass a meaningful
+    /// `peer` and `remote_leader`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/intercommunicator.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.6.2
+    fn create_intercommunicator<P: ?Sized + RawCommunicator>(&self, local_leader: Rank, peer: &P, remote_leader: Rank) -> Result<InterCommunicator, Error> {
+        self.create_intercommunicator_with_tag(local_leader, peer, remote_leader, Tag::default())
+    }
+
+    /// Create an inter-communicator between the group of this intra-communicator and another
+    /// disjoint group.
+    ///
+    /// Like `create_intercommunicator()` but can avoid collision of concurrent calls by passing
+    /// in distinct tags for the communication between the leaders on `peer`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.6.2
+    fn create_intercommunicator_with_tag<P: ?Sized + RawCommunicator>(&self, local_leader: Rank, peer: &P, remote_leader: Rank, tag: Tag) -> Result<InterCommunicator, Error> {
+        let mut newcomm: MPI_Comm = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Intercomm_create(self.as_raw(), local_leader, peer.as_raw(), remote_leader,
+                tag, &mut newcomm)
+        }));
+        Ok(InterCommunicator(newcomm))
+    }
+
+    /// Create a communicator with a Cartesian process topology.
+    ///
+    /// `dims` gives the number of processes along each dimension of the grid and `periods`
+    /// whether each dimension is periodic. If `reorder` is `true`, processes may be assigned a
+    /// different rank in the new communicator. Processes beyond the size of the grid do not join
+    /// the new communicator and receive `None`.
+    ///
+    /// This is a collective operation on this communicator.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/cartesian.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.1
+    fn create_cartesian_communicator(&self, dims: &[Count], periods: &[bool], reorder: bool) -> Result<Option<CartesianCommunicator>, Error> {
+        let periods = periods.iter().map(|&p| p as c_int).collect::<Vec<_>>();
+        assert_eq!(dims.count(), periods.count());
+        let mut newcomm: MPI_Comm = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Cart_create(self.as_raw(), dims.count(), dims.as_ptr(), periods.as_ptr(),
+                reorder as c_int, &mut newcomm)
+        }));
+        Ok(CartesianCommunicator::from_raw(newcomm))
+    }
+
+    /// Create a communicator with a graph process topology.
+    ///
+    /// The graph is given in the format used by the MPI standard: the neighbors of node `i` are
+    /// `edges[index[i - 1]..index[i]]` (with `index[-1] = 0`). All processes have to pass the
+    /// same graph, `index` has to be non-decreasing and its last entry has to be the number of
+    /// `edges`. If `reorder` is `true`, processes may be assigned a different rank in the new
+    /// communicator. Processes beyond the number of nodes do not join the new communicator and
+    /// receive `None`.
+    ///
+    /// This is a collective operation on this communicator.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/graph.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.3
+    fn create_graph_communicator(&self, index: &[Count], edges: &[Rank], reorder: bool) -> Result<Option<GraphCommunicator>, Error> {
+        assert!(index.first().map_or(true, |&i| i >= 0) && index.windows(2).all(|w| w[0] <= w[1]),
+            "The index of a graph has to be non-negative and non-decreasing.");
+        assert_eq!(edges.count(), *index.last().unwrap_or(&0));
+        let mut newcomm: MPI_Comm = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Graph_create(self.as_raw(), index.count(), index.as_ptr(), edges.as_ptr(),
+                reorder as c_int, &mut newcomm)
+        }));
+        Ok(GraphCommunicator::from_raw(newcomm))
+    }
+
+    /// Create a communicator with a distributed graph process topology where every process
+    /// specifies its own adjacent processes.
+    ///
+    /// Edges lead from all processes in `sources` to the calling process and from the calling
+    /// process to all processes in `destinations`. The graph is weighted if weights are passed
+    /// in, in which case all processes have to pass weights. If `reorder` is `true`, processes
+    /// may be assigned a different rank in the new communicator.
+    ///
+    /// This is a collective operation on this communicator.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/graph.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.4
+    fn create_dist_graph_communicator_adjacent(&self, sources: &[Rank], source_weights: Option<&[Weight]>, destinations: &[Rank], destination_weights: Option<&[Weight]>, reorder: bool) -> Result<DistGraphCommunicator, Error> {
+        assert!(source_weights.map_or(true, |w| w.count() == sources.count()));
+        assert!(destination_weights.map_or(true, |w| w.count() == destinations.count()));
+        let mut newcomm: MPI_Comm = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Dist_graph_create_adjacent(self.as_raw(),
+                sources.count(), sources.as_ptr(), weights_as_ptr(source_weights),
+                destinations.count(), destinations.as_ptr(), weights_as_ptr(destination_weights),
+                ffi::RSMPI_INFO_NULL, reorder as c_int, &mut newcomm)
+        }));
+        Ok(DistGraphCommunicator(newcomm))
+    }
+
+    /// Create a communicator with a distributed graph process topology where every process may
+    /// specify any edges of the graph.
+    ///
+    /// Edges lead from each process in `sources` to `degrees[i]` processes listed consecutively in
+    /// `destinations`. The graph is weighted if `weights` (one per destination) are passed in, in
+    /// which case all processes have to pass weights. If `reorder` is `true`, processes may be
+    /// assigned a different rank in the new communicator.
+    ///
+    /// This is a collective operation on this communicator.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/graph.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 7.5.4
+    fn create_dist_graph_communicator(&self, sources: &[Rank], degrees: &[Count], destinations: &[Rank], weights: Option<&[Weight]>, reorder: bool) -> Result<DistGraphCommunicator, Error> {
+        assert_eq!(sources.count(), degrees.count());
+        assert_eq!(degrees.iter().fold(0, |acc, &d| acc + d), destinations.count());
+        assert!(weights.map_or(true, |w| w.count() == destinations.count()));
+        let mut newcomm: MPI_Comm = unsafe { mem::uninitialized() };
+        try!(check(unsafe {
+            ffi::MPI_Dist_graph_create(self.as_raw(), sources.count(), sources.as_ptr(),
+                degrees.as_ptr(), destinations.as_ptr(), weights_as_ptr(weights),
+                ffi::RSMPI_INFO_NULL, reorder as c_int, &mut newcomm)
+        }));
+        Ok(DistGraphCommunicator(newcomm))
     }
 
     /// The group associated with this communicator
     ///
+    /// On an inter-communicator, this is the local group.
+    ///
     /// # Standard section(s)
     ///
     /// 6.3.2
-    fn group(&self) -> UserGroup {
+    fn group(&self) -> Result<UserGroup, Error> {
         let mut group: MPI_Group = unsafe { mem::uninitialized() };
-        unsafe { ffi::MPI_Comm_group(self.as_raw(), &mut group); }
-        UserGroup(group)
+        try!(check(unsafe { ffi::MPI_Comm_group(self.as_raw(), &mut group) }));
+        Ok(UserGroup(group))
     }
 }
 
@@ -609,10 +1345,10 @@ pub trait GroupExt: RawGroup {
     /// # Standard section(s)
     ///
     /// 6.3.2
-    fn union<G: RawGroup>(&self, other: &G) -> UserGroup {
+    fn union<G: RawGroup>(&self, other: &G) -> Result<UserGroup, Error> {
         let mut newgroup: MPI_Group = unsafe { mem::uninitialized() };
-        unsafe { ffi::MPI_Group_union(self.as_raw(), other.as_raw(), &mut newgroup); }
-        UserGroup(newgroup)
+        try!(check(unsafe { ffi::MPI_Group_union(self.as_raw(), other.as_raw(), &mut newgroup) }));
+        Ok(UserGroup(newgroup))
     }
 
     /// Group intersection
@@ -623,10 +1359,10 @@ pub trait GroupExt: RawGroup {
     /// # Standard section(s)
     ///
     /// 6.3.2
-    fn intersection<G: RawGroup>(&self, other: &G) -> UserGroup {
+    fn intersection<G: RawGroup>(&self, other: &G) -> Result<UserGroup, Error> {
         let mut newgroup: MPI_Group = unsafe { mem::uninitialized() };
-        unsafe { ffi::MPI_Group_intersection(self.as_raw(), other.as_raw(), &mut newgroup); }
-        UserGroup(newgroup)
+        try!(check(unsafe { ffi::MPI_Group_intersection(self.as_raw(), other.as_raw(), &mut newgroup) }));
+        Ok(UserGroup(newgroup))
     }
 
     /// Group difference
@@ -637,10 +1373,10 @@ pub trait GroupExt: RawGroup {
     /// # Standard section(s)
     ///
     /// 6.3.2
-    fn difference<G: RawGroup>(&self, other: &G) -> UserGroup {
+    fn difference<G: RawGroup>(&self, other: &G) -> Result<UserGroup, Error> {
         let mut newgroup: MPI_Group = unsafe { mem::uninitialized() };
-        unsafe { ffi::MPI_Group_difference(self.as_raw(), other.as_raw(), &mut newgroup); }
-        UserGroup(newgroup)
+        try!(check(unsafe { ffi::MPI_Group_difference(self.as_raw(), other.as_raw(), &mut newgroup) }));
+        Ok(UserGroup(newgroup))
     }
 
     /// Subgroup including specified ranks
@@ -651,10 +1387,12 @@ pub trait GroupExt: RawGroup {
     /// # Standard section(s)
     ///
     /// 6.3.2
-    fn include(&self, ranks: &[Rank]) -> UserGroup {
+    fn include(&self, ranks: &[Rank]) -> Result<UserGroup, Error> {
         let mut newgroup: MPI_Group = unsafe { mem::uninitialized() };
-        unsafe { ffi::MPI_Group_incl(self.as_raw(), ranks.count(), ranks.as_ptr(), &mut newgroup); }
-        UserGroup(newgroup)
+        try!(check(unsafe {
+            ffi::MPI_Group_incl(self.as_raw(), ranks.count(), ranks.as_ptr(), &mut newgroup)
+        }));
+        Ok(UserGroup(newgroup))
     }
 
     /// Subgroup including specified ranks
@@ -665,10 +1403,12 @@ pub trait GroupExt: RawGroup {
     /// # Standard section(s)
     ///
     /// 6.3.2
-    fn exclude(&self, ranks: &[Rank]) -> UserGroup {
+    fn exclude(&self, ranks: &[Rank]) -> Result<UserGroup, Error> {
         let mut newgroup: MPI_Group = unsafe { mem::uninitialized() };
-        unsafe { ffi::MPI_Group_excl(self.as_raw(), ranks.count(), ranks.as_ptr(), &mut newgroup); }
-        UserGroup(newgroup)
+        try!(check(unsafe {
+            ffi::MPI_Group_excl(self.as_raw(), ranks.count(), ranks.as_ptr(), &mut newgroup)
+        }));
+        Ok(UserGroup(newgroup))
     }
 
     /// Number of processes in the group.
@@ -704,13 +1444,15 @@ pub trait GroupExt: RawGroup {
     /// # Standard section(s)
     ///
     /// 6.3.1
-    fn translate_rank<G: RawGroup>(&self, rank: Rank, other: &G) -> Option<Rank> {
+    fn translate_rank<G: RawGroup>(&self, rank: Rank, other: &G) -> Result<Option<Rank>, Error> {
         let mut res: Rank = unsafe { mem::uninitialized() };
-        unsafe { ffi::MPI_Group_translate_ranks(self.as_raw(), 1, &rank, other.as_raw(), &mut res); }
+        try!(check(unsafe {
+            ffi::MPI_Group_translate_ranks(self.as_raw(), 1, &rank, other.as_raw(), &mut res)
+        }));
         if res == ffi::RSMPI_UNDEFINED {
-            None
+            Ok(None)
         } else {
-            Some(res)
+            Ok(Some(res))
         }
     }
 
@@ -721,7 +1463,7 @@ pub trait GroupExt: RawGroup {
     /// # Standard section(s)
     ///
     /// 6.3.1
-    fn translate_ranks<G: RawGroup>(&self, ranks: &[Rank], other: &G) -> Vec<Option<Rank>> {
+    fn translate_ranks<G: RawGroup>(&self, ranks: &[Rank], other: &G) -> Result<Vec<Option<Rank>>, Error> {
         ranks.iter().map(|&r| self.translate_rank(r, other)).collect()
     }
 
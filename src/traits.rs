@@ -1,6 +1,7 @@
 //! Re-exports all traits.
 pub use collective::traits::*;
 pub use datatype::traits::*;
+pub use error::traits::*;
 pub use point_to_point::traits::*;
 pub use raw::traits::*;
 pub use request::traits::*;